@@ -0,0 +1,652 @@
+use std::fmt;
+
+use crate::encoder::encode_with_config;
+use crate::{bits, sign_extend, DecodeError, DecoderConfig, Instruction, Xlen};
+
+/*
+ * RVC Instruction breakdown
+ *
+ * The two lowest bits select the quadrant (00, 01, 10), 11 marks a 32-bit
+ * instruction. funct3 sits in bits 15:13 for every format. Registers written
+ * rd'/rs1'/rs2' are 3-bit fields naming x8-x15 (or f8-f15).
+ *
+ * CR   15------12 11-------7 6-------2 1---0
+ *       /funct4/   /rd/rs1/    /rs2/    /op/
+ *
+ * CI   15----13 12---- 11-------7 6-------2 1---0
+ *      /funct3/ /imm/   /rd/rs1/    /imm/    /op/
+ *
+ * CSS  15----13 12-------7 6-------2 1---0
+ *      /funct3/   /imm/      /rs2/    /op/
+ *
+ * CIW  15----13 12---------5 4-----2 1---0
+ *      /funct3/    /imm/      /rd'/   /op/
+ *
+ * CL/CS 15----13 12---10 9-----7 6---5 4-----2 1---0
+ *       /funct3/  /imm/  /rs1'/  /imm/ /rd'rs2'/ /op/
+ *
+ * CB   15----13 12---10 9-----7 6-------2 1---0
+ *      /funct3/ /offset/ /rs1'/  /offset/  /op/
+ *
+ * CJ   15----13 12--------------------2 1---0
+ *      /funct3/      /jump target/       /op/
+ */
+
+/// A decoded 16-bit compressed instruction together with its 32-bit expansion
+///
+/// `Display` shows the compressed form followed by the expansion, e.g.
+/// "C.ADDI x1, 4 (ADDI x1, x1, 4)". HINT encodings decode to their
+/// expansion and are marked in the output.
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{decode_compressed, Instruction};
+///
+/// let decoded = decode_compressed(0x0091).unwrap();
+/// assert_eq!(decoded.mnemonic(), "C.ADDI");
+/// assert_eq!(decoded.expansion(), Instruction::Addi { rd: 1, rs1: 1, imm: 4 });
+/// assert_eq!(decoded.to_string(), "C.ADDI x1, 4 (ADDI x1, x1, 4)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompressedInstruction {
+    parcel: u16,
+    mnemonic: &'static str,
+    expansion: Instruction,
+    hint: bool,
+    // Which operands of the expansion the compressed form writes out
+    operands: &'static [usize],
+    // Base ISA the parcel was decoded for, which the expansion is encoded for
+    xlen: Xlen,
+}
+
+impl CompressedInstruction {
+    /// Returns the 16-bit parcel the instruction was decoded from
+    pub fn parcel(&self) -> u16 {
+        self.parcel
+    }
+
+    /// Returns the compressed mnemonic, e.g. "C.LWSP"
+    pub fn mnemonic(&self) -> &'static str {
+        self.mnemonic
+    }
+
+    /// Returns the 32-bit instruction the compressed instruction expands to
+    pub fn expansion(&self) -> Instruction {
+        self.expansion
+    }
+
+    /// Returns the 32-bit instruction word of the expansion
+    pub fn expanded_word(&self) -> u32 {
+        // Every expansion uses registers and immediates its 32-bit format can hold
+        let config = DecoderConfig { xlen: self.xlen };
+        encode_with_config(&self.expansion, &config).expect("compressed expansion fits its 32-bit encoding")
+    }
+
+    /// Returns true for HINT encodings, which execute as no-ops
+    pub fn is_hint(&self) -> bool {
+        self.hint
+    }
+}
+
+impl fmt::Display for CompressedInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expansion = self.expansion.to_string();
+        let all: Vec<&str> = match expansion.split_once(' ') {
+            Some((_, operands)) => operands.split(", ").collect(),
+            None => Vec::new(),
+        };
+        let operands: Vec<&str> = self.operands.iter().map(|&i| all[i]).collect();
+        f.write_str(self.mnemonic)?;
+        if !operands.is_empty() {
+            write!(f, " {}", operands.join(", "))?;
+        }
+        if self.hint {
+            write!(f, " (hint: {})", expansion)
+        } else {
+            write!(f, " ({})", expansion)
+        }
+    }
+}
+
+/// Extensions beyond C whose instructions have compressed forms
+///
+/// C.FLW/C.FSW and their stack pointer forms need F, C.FLD/C.FSD and theirs
+/// need D. The default is the integer compressed instructions only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Extensions {
+    pub f: bool,
+    pub d: bool,
+}
+
+impl Extensions {
+    /// The integer compressed instructions plus the F and D loads and stores
+    pub fn all() -> Self {
+        Extensions { f: true, d: true }
+    }
+
+    fn allows(&self, instr: &Instruction) -> bool {
+        match instr {
+            Instruction::Flw { .. } | Instruction::Fsw { .. } => self.f,
+            Instruction::Fld { .. } | Instruction::Fsd { .. } => self.d,
+            _ => true,
+        }
+    }
+}
+
+/// Instruction counts of a code buffer scanned for compressible instructions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CompressionSummary {
+    /// 32-bit instructions in the buffer
+    pub instructions: usize,
+    /// 16-bit instructions already in the buffer
+    pub compressed: usize,
+    /// 32-bit instructions that have a compressed encoding
+    pub compressible: usize,
+}
+
+impl CompressionSummary {
+    /// Returns the number of bytes saved by compressing every compressible instruction
+    pub fn bytes_saved(&self) -> usize {
+        self.compressible * 2
+    }
+}
+
+fn compressed(parcel: u16, mnemonic: &'static str, expansion: Instruction, operands: &'static [usize]) -> CompressedInstruction {
+    CompressedInstruction { parcel, mnemonic, expansion, hint: false, operands, xlen: Xlen::Rv32 }
+}
+
+fn hint(parcel: u16, mnemonic: &'static str, expansion: Instruction, operands: &'static [usize]) -> CompressedInstruction {
+    CompressedInstruction { parcel, mnemonic, expansion, hint: true, operands, xlen: Xlen::Rv32 }
+}
+
+/// Maps a 3-bit rd'/rs1'/rs2' field to its register number (x8-x15)
+fn creg(field: u32) -> u8 {
+    (field + 8) as u8
+}
+
+/// Extracts shamt[5|4:0] of C.SLLI/C.SRLI/C.SRAI, shamt[5] must be zero on RV32
+fn shift_amount(instr: u32, config: &DecoderConfig) -> Result<u8, DecodeError> {
+    let shamt = bits(instr, 12, 12) << 5 | bits(instr, 6, 2);
+    if shamt > 31 {
+        config.require_rv64(instr)?;
+    }
+    return Ok(shamt as u8);
+}
+
+/// Decodes a 16-bit compressed instruction for RV32
+///
+/// Use `DecoderConfig` to decode the RV64 forms.
+pub fn decode_compressed(parcel: u16) -> Result<CompressedInstruction, DecodeError> {
+    return decode_compressed_with_config(parcel, &DecoderConfig::default());
+}
+
+/// Expands a 16-bit compressed instruction to its equivalent RV32 instruction word
+///
+/// Reserved encodings fail with the same `DecodeError` as `decode_compressed`,
+/// HINTs expand to the 32-bit HINT they stand for. Use `DecoderConfig` to
+/// expand the RV64 forms.
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{decode, expand_compressed, Instruction};
+///
+/// let word = expand_compressed(0x40b2).unwrap();      // C.LWSP x1, 12(x2)
+/// assert_eq!(word, 0x00c12083);
+/// assert_eq!(decode(word).unwrap(), Instruction::Lw { rd: 1, rs1: 2, imm: 12 });
+///
+/// assert!(expand_compressed(0x0000).is_err());
+/// ```
+pub fn expand_compressed(parcel: u16) -> Result<u32, DecodeError> {
+    return DecoderConfig::default().expand_compressed(parcel);
+}
+
+/// Finds the RV32 compressed encoding of a 32-bit instruction
+///
+/// Returns the compressed instruction whose expansion is exactly `instr`, or
+/// `None` when no such encoding exists. HINT encodings are never returned. Use
+/// `DecoderConfig` to compress for RV64.
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{compress, Extensions, Instruction};
+///
+/// let compressed = compress(&Instruction::Addi { rd: 2, rs1: 2, imm: -16 }, &Extensions::default()).unwrap();
+/// assert_eq!(compressed.parcel(), 0x1141);
+/// assert_eq!(compressed.mnemonic(), "C.ADDI");
+///
+/// // x20 is outside the x8-x15 window of C.LW
+/// assert!(compress(&Instruction::Lw { rd: 20, rs1: 9, imm: 4 }, &Extensions::default()).is_none());
+/// ```
+pub fn compress(instr: &Instruction, extensions: &Extensions) -> Option<CompressedInstruction> {
+    return DecoderConfig::default().compress(instr, extensions);
+}
+
+/// Counts how many bytes compressing a little-endian RV32 code buffer would save
+///
+/// Use `DecoderConfig` to scan RV64 code.
+pub fn compression_summary(bytes: &[u8], extensions: &Extensions) -> Result<CompressionSummary, DecodeError> {
+    return DecoderConfig::default().compression_summary(bytes, extensions);
+}
+
+pub(crate) fn decode_compressed_with_config(parcel: u16, config: &DecoderConfig) -> Result<CompressedInstruction, DecodeError> {
+    let decoded = decode_parcel(parcel, config)?;
+    return Ok(CompressedInstruction { xlen: config.xlen, ..decoded });
+}
+
+fn decode_parcel(parcel: u16, config: &DecoderConfig) -> Result<CompressedInstruction, DecodeError> {
+    let instr = parcel as u32;
+    let op = bits(instr, 1, 0);
+    let funct3 = bits(instr, 15, 13);
+    let rv64 = config.xlen == Xlen::Rv64;
+
+    match op {
+        0b00 => {      // Quadrant 0, stack pointer based and register based loads/stores
+            let rd_bits = creg(bits(instr, 4, 2));     // rd' or rs2'
+            let rs1_bits = creg(bits(instr, 9, 7));
+            // uimm[5:3|7:6] for double-words, uimm[5:3|2|6] for words
+            let d_imm = (bits(instr, 12, 10) << 3 | bits(instr, 6, 5) << 6) as i32;
+            let w_imm = (bits(instr, 12, 10) << 3 | bits(instr, 6, 6) << 2 | bits(instr, 5, 5) << 6) as i32;
+
+            match funct3 {
+                0b000 => {      // Add scaled immediate to stack pointer
+                    let imm = (bits(instr, 12, 11) << 4 | bits(instr, 10, 7) << 6 | bits(instr, 6, 6) << 2 | bits(instr, 5, 5) << 3) as i32;
+                    if imm == 0 {
+                        return Err(DecodeError::ReservedFunct { word: instr, field: "nzuimm", value: 0 });
+                    }
+                    return Ok(compressed(parcel, "C.ADDI4SPN", Instruction::Addi { rd: rd_bits, rs1: 2, imm }, &[0, 1, 2]));
+                }
+                0b001 => {
+                    return Ok(compressed(parcel, "C.FLD", Instruction::Fld { rd: rd_bits, rs1: rs1_bits, imm: d_imm }, &[0, 1]));
+                }
+                0b010 => {
+                    return Ok(compressed(parcel, "C.LW", Instruction::Lw { rd: rd_bits, rs1: rs1_bits, imm: w_imm }, &[0, 1]));
+                }
+                0b011 => {      // C.FLW on RV32, C.LD on RV64
+                    if rv64 {
+                        return Ok(compressed(parcel, "C.LD", Instruction::Ld { rd: rd_bits, rs1: rs1_bits, imm: d_imm }, &[0, 1]));
+                    }
+                    return Ok(compressed(parcel, "C.FLW", Instruction::Flw { rd: rd_bits, rs1: rs1_bits, imm: w_imm }, &[0, 1]));
+                }
+                0b101 => {
+                    return Ok(compressed(parcel, "C.FSD", Instruction::Fsd { rs1: rs1_bits, rs2: rd_bits, imm: d_imm }, &[0, 1]));
+                }
+                0b110 => {
+                    return Ok(compressed(parcel, "C.SW", Instruction::Sw { rs1: rs1_bits, rs2: rd_bits, imm: w_imm }, &[0, 1]));
+                }
+                0b111 => {      // C.FSW on RV32, C.SD on RV64
+                    if rv64 {
+                        return Ok(compressed(parcel, "C.SD", Instruction::Sd { rs1: rs1_bits, rs2: rd_bits, imm: d_imm }, &[0, 1]));
+                    }
+                    return Ok(compressed(parcel, "C.FSW", Instruction::Fsw { rs1: rs1_bits, rs2: rd_bits, imm: w_imm }, &[0, 1]));
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 }),
+            }
+        }
+
+        0b01 => {      // Quadrant 1, control transfers and integer computation
+            let rd_bits = bits(instr, 11, 7) as u8;
+            let rd_prime = creg(bits(instr, 9, 7));     // rd' / rs1'
+            let rs2_prime = creg(bits(instr, 4, 2));
+            let imm = sign_extend(bits(instr, 12, 12) << 5 | bits(instr, 6, 2), 6);
+            // offset[11|4|9:8|10|6|7|3:1|5]
+            let j_imm = sign_extend(bits(instr, 12, 12) << 11 | bits(instr, 11, 11) << 4 | bits(instr, 10, 9) << 8
+                | bits(instr, 8, 8) << 10 | bits(instr, 7, 7) << 6 | bits(instr, 6, 6) << 7
+                | bits(instr, 5, 3) << 1 | bits(instr, 2, 2) << 5, 12);
+            // offset[8|4:3] and offset[7:6|2:1|5]
+            let b_imm = sign_extend(bits(instr, 12, 12) << 8 | bits(instr, 11, 10) << 3 | bits(instr, 6, 5) << 6
+                | bits(instr, 4, 3) << 1 | bits(instr, 2, 2) << 5, 9);
+
+            match funct3 {
+                0b000 => {
+                    let expansion = Instruction::Addi { rd: rd_bits, rs1: rd_bits, imm };
+                    if rd_bits == 0 {      // No operation, a nonzero immediate is a HINT
+                        if imm != 0 {
+                            return Ok(hint(parcel, "C.NOP", expansion, &[2]));
+                        }
+                        return Ok(compressed(parcel, "C.NOP", expansion, &[]));
+                    }
+                    if imm == 0 {
+                        return Ok(hint(parcel, "C.ADDI", expansion, &[0, 2]));
+                    }
+                    return Ok(compressed(parcel, "C.ADDI", expansion, &[0, 2]));
+                }
+                0b001 => {      // C.JAL on RV32, C.ADDIW on RV64
+                    if rv64 {
+                        if rd_bits == 0 {
+                            return Err(DecodeError::ReservedFunct { word: instr, field: "rd", value: 0 });
+                        }
+                        return Ok(compressed(parcel, "C.ADDIW", Instruction::Addiw { rd: rd_bits, rs1: rd_bits, imm }, &[0, 2]));
+                    }
+                    return Ok(compressed(parcel, "C.JAL", Instruction::Jal { rd: 1, imm: j_imm }, &[1]));
+                }
+                0b010 => {      // Load immediate
+                    let expansion = Instruction::Addi { rd: rd_bits, rs1: 0, imm };
+                    if rd_bits == 0 {
+                        return Ok(hint(parcel, "C.LI", expansion, &[0, 2]));
+                    }
+                    return Ok(compressed(parcel, "C.LI", expansion, &[0, 2]));
+                }
+                0b011 => {
+                    if rd_bits == 2 {      // Add scaled immediate to stack pointer, nzimm[9|4|6|8:7|5]
+                        let imm = sign_extend(bits(instr, 12, 12) << 9 | bits(instr, 6, 6) << 4 | bits(instr, 5, 5) << 6
+                            | bits(instr, 4, 3) << 7 | bits(instr, 2, 2) << 5, 10);
+                        if imm == 0 {
+                            return Err(DecodeError::ReservedFunct { word: instr, field: "nzimm", value: 0 });
+                        }
+                        return Ok(compressed(parcel, "C.ADDI16SP", Instruction::Addi { rd: 2, rs1: 2, imm }, &[0, 2]));
+                    }
+                    // Load upper immediate, nzimm[17|16:12]
+                    if imm == 0 {
+                        return Err(DecodeError::ReservedFunct { word: instr, field: "nzimm", value: 0 });
+                    }
+                    let expansion = Instruction::Lui { rd: rd_bits, imm };
+                    if rd_bits == 0 {
+                        return Ok(hint(parcel, "C.LUI", expansion, &[0, 1]));
+                    }
+                    return Ok(compressed(parcel, "C.LUI", expansion, &[0, 1]));
+                }
+                0b100 => {      // Register-immediate and register-register arithmetic on x8-x15
+                    let funct2 = bits(instr, 11, 10);
+                    match funct2 {
+                        0b00 | 0b01 => {
+                            let shamt = shift_amount(instr, config)?;
+                            let (mnemonic, expansion) = if funct2 == 0b00 {
+                                ("C.SRLI", Instruction::Srli { rd: rd_prime, rs1: rd_prime, shamt })
+                            } else {
+                                ("C.SRAI", Instruction::Srai { rd: rd_prime, rs1: rd_prime, shamt })
+                            };
+                            if shamt == 0 {
+                                return Ok(hint(parcel, mnemonic, expansion, &[0, 2]));
+                            }
+                            return Ok(compressed(parcel, mnemonic, expansion, &[0, 2]));
+                        }
+                        0b10 => {
+                            return Ok(compressed(parcel, "C.ANDI", Instruction::Andi { rd: rd_prime, rs1: rd_prime, imm }, &[0, 2]));
+                        }
+                        _ => {
+                            let funct = bits(instr, 12, 12) << 2 | bits(instr, 6, 5);
+                            let (rd, rs1, rs2) = (rd_prime, rd_prime, rs2_prime);
+                            match funct {
+                                0b000 => return Ok(compressed(parcel, "C.SUB", Instruction::Sub { rd, rs1, rs2 }, &[0, 2])),
+                                0b001 => return Ok(compressed(parcel, "C.XOR", Instruction::Xor { rd, rs1, rs2 }, &[0, 2])),
+                                0b010 => return Ok(compressed(parcel, "C.OR", Instruction::Or { rd, rs1, rs2 }, &[0, 2])),
+                                0b011 => return Ok(compressed(parcel, "C.AND", Instruction::And { rd, rs1, rs2 }, &[0, 2])),
+                                0b100 => {
+                                    config.require_rv64(instr)?;
+                                    return Ok(compressed(parcel, "C.SUBW", Instruction::Subw { rd, rs1, rs2 }, &[0, 2]));
+                                }
+                                0b101 => {
+                                    config.require_rv64(instr)?;
+                                    return Ok(compressed(parcel, "C.ADDW", Instruction::Addw { rd, rs1, rs2 }, &[0, 2]));
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct6", value: bits(instr, 15, 10) }),
+                            }
+                        }
+                    }
+                }
+                0b101 => {
+                    return Ok(compressed(parcel, "C.J", Instruction::Jal { rd: 0, imm: j_imm }, &[1]));
+                }
+                0b110 => {
+                    return Ok(compressed(parcel, "C.BEQZ", Instruction::Beq { rs1: rd_prime, rs2: 0, imm: b_imm }, &[0, 2]));
+                }
+                _ => {
+                    return Ok(compressed(parcel, "C.BNEZ", Instruction::Bne { rs1: rd_prime, rs2: 0, imm: b_imm }, &[0, 2]));
+                }
+            }
+        }
+
+        0b10 => {      // Quadrant 2, stack pointer based loads/stores and full register operations
+            let rd_bits = bits(instr, 11, 7) as u8;     // rd / rs1
+            let rs2_bits = bits(instr, 6, 2) as u8;
+            // uimm[5|4:3|8:6] for double-word loads, uimm[5|4:2|7:6] for word loads
+            let d_load_imm = (bits(instr, 12, 12) << 5 | bits(instr, 6, 5) << 3 | bits(instr, 4, 2) << 6) as i32;
+            let w_load_imm = (bits(instr, 12, 12) << 5 | bits(instr, 6, 4) << 2 | bits(instr, 3, 2) << 6) as i32;
+            // uimm[5:3|8:6] for double-word stores, uimm[5:2|7:6] for word stores
+            let d_store_imm = (bits(instr, 12, 10) << 3 | bits(instr, 9, 7) << 6) as i32;
+            let w_store_imm = (bits(instr, 12, 9) << 2 | bits(instr, 8, 7) << 6) as i32;
+
+            match funct3 {
+                0b000 => {      // Shift left logical immediate
+                    let shamt = shift_amount(instr, config)?;
+                    let expansion = Instruction::Slli { rd: rd_bits, rs1: rd_bits, shamt };
+                    if rd_bits == 0 || shamt == 0 {
+                        return Ok(hint(parcel, "C.SLLI", expansion, &[0, 2]));
+                    }
+                    return Ok(compressed(parcel, "C.SLLI", expansion, &[0, 2]));
+                }
+                0b001 => {
+                    return Ok(compressed(parcel, "C.FLDSP", Instruction::Fld { rd: rd_bits, rs1: 2, imm: d_load_imm }, &[0, 1]));
+                }
+                0b010 => {
+                    if rd_bits == 0 {
+                        return Err(DecodeError::ReservedFunct { word: instr, field: "rd", value: 0 });
+                    }
+                    return Ok(compressed(parcel, "C.LWSP", Instruction::Lw { rd: rd_bits, rs1: 2, imm: w_load_imm }, &[0, 1]));
+                }
+                0b011 => {      // C.FLWSP on RV32, C.LDSP on RV64
+                    if rv64 {
+                        if rd_bits == 0 {
+                            return Err(DecodeError::ReservedFunct { word: instr, field: "rd", value: 0 });
+                        }
+                        return Ok(compressed(parcel, "C.LDSP", Instruction::Ld { rd: rd_bits, rs1: 2, imm: d_load_imm }, &[0, 1]));
+                    }
+                    return Ok(compressed(parcel, "C.FLWSP", Instruction::Flw { rd: rd_bits, rs1: 2, imm: w_load_imm }, &[0, 1]));
+                }
+                0b100 => {
+                    let rs1_bits = rd_bits;
+                    match (bits(instr, 12, 12), rs1_bits, rs2_bits) {
+                        (0, 0, 0) => return Err(DecodeError::ReservedFunct { word: instr, field: "rs1", value: 0 }),
+                        (0, _, 0) => {      // Jump register
+                            return Ok(compressed(parcel, "C.JR", Instruction::Jalr { rd: 0, rs1: rs1_bits, imm: 0 }, &[1]));
+                        }
+                        (0, _, _) => {      // Move
+                            let expansion = Instruction::Add { rd: rd_bits, rs1: 0, rs2: rs2_bits };
+                            if rd_bits == 0 {
+                                return Ok(hint(parcel, "C.MV", expansion, &[0, 2]));
+                            }
+                            return Ok(compressed(parcel, "C.MV", expansion, &[0, 2]));
+                        }
+                        (_, 0, 0) => return Ok(compressed(parcel, "C.EBREAK", Instruction::Ebreak, &[])),
+                        (_, _, 0) => {      // Jump and link register
+                            return Ok(compressed(parcel, "C.JALR", Instruction::Jalr { rd: 1, rs1: rs1_bits, imm: 0 }, &[1]));
+                        }
+                        (_, _, _) => {      // Add
+                            let expansion = Instruction::Add { rd: rd_bits, rs1: rd_bits, rs2: rs2_bits };
+                            if rd_bits == 0 {
+                                return Ok(hint(parcel, "C.ADD", expansion, &[0, 2]));
+                            }
+                            return Ok(compressed(parcel, "C.ADD", expansion, &[0, 2]));
+                        }
+                    }
+                }
+                0b101 => {
+                    return Ok(compressed(parcel, "C.FSDSP", Instruction::Fsd { rs1: 2, rs2: rs2_bits, imm: d_store_imm }, &[0, 1]));
+                }
+                0b110 => {
+                    return Ok(compressed(parcel, "C.SWSP", Instruction::Sw { rs1: 2, rs2: rs2_bits, imm: w_store_imm }, &[0, 1]));
+                }
+                _ => {      // C.FSWSP on RV32, C.SDSP on RV64
+                    if rv64 {
+                        return Ok(compressed(parcel, "C.SDSP", Instruction::Sd { rs1: 2, rs2: rs2_bits, imm: d_store_imm }, &[0, 1]));
+                    }
+                    return Ok(compressed(parcel, "C.FSWSP", Instruction::Fsw { rs1: 2, rs2: rs2_bits, imm: w_store_imm }, &[0, 1]));
+                }
+            }
+        }
+
+        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "op", value: op }),
+    }
+}
+
+pub(crate) fn compress_with_config(instr: &Instruction, config: &DecoderConfig, extensions: &Extensions) -> Option<CompressedInstruction> {
+    if !extensions.allows(instr) {
+        return None;
+    }
+    // Decoding each candidate back rejects registers and immediates the
+    // compressed fields cannot hold, as well as forms of the other XLEN
+    return candidates(instr).into_iter().find_map(|parcel| {
+        let decoded = decode_compressed_with_config(parcel as u16, config).ok()?;
+        if decoded.hint || decoded.expansion != *instr {
+            return None;
+        }
+        return Some(decoded);
+    });
+}
+
+pub(crate) fn compression_summary_with_config(bytes: &[u8], config: &DecoderConfig, extensions: &Extensions) -> Result<CompressionSummary, DecodeError> {
+    let mut summary = CompressionSummary::default();
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let rest = &bytes[offset..];
+        if rest.len() < 2 {
+            return Err(DecodeError::TooShort { len: rest.len(), needed: 2 });
+        }
+        let parcel = u16::from_le_bytes([rest[0], rest[1]]);
+        if parcel & 0b11 != 0b11 {
+            decode_compressed_with_config(parcel, config)?;
+            summary.compressed += 1;
+            offset += 2;
+            continue;
+        }
+        let decoded = config.decode_bytes(rest)?;
+        summary.instructions += 1;
+        if compress_with_config(&decoded, config, extensions).is_some() {
+            summary.compressible += 1;
+        }
+        offset += 4;
+    }
+    return Ok(summary);
+}
+
+/*
+ * Packing the compressed formats
+ *
+ * Registers and immediates are masked to their field widths here, whether
+ * they fit is settled by decoding the parcel again.
+ */
+
+/// Maps x8-x15 to a 3-bit rd'/rs1'/rs2' field
+fn cfield(reg: u8) -> u32 {
+    (reg as u32).wrapping_sub(8) & 0b111
+}
+
+fn reg(reg: u8) -> u32 {
+    reg as u32 & 0x1f
+}
+
+fn ci_type(funct3: u32, rd: u8, imm: i32, op: u32) -> u32 {
+    let imm = imm as u32;
+    funct3 << 13 | bits(imm, 5, 5) << 12 | reg(rd) << 7 | bits(imm, 4, 0) << 2 | op
+}
+
+fn cr_type(funct4: u32, rd: u8, rs2: u8) -> u32 {
+    funct4 << 12 | reg(rd) << 7 | reg(rs2) << 2 | 0b10
+}
+
+/// CL/CS with uimm[5:3|2|6], the word loads and stores
+fn cl_word(funct3: u32, rd: u8, rs1: u8, imm: i32) -> u32 {
+    let imm = imm as u32;
+    funct3 << 13 | bits(imm, 5, 3) << 10 | cfield(rs1) << 7 | bits(imm, 2, 2) << 6 | bits(imm, 6, 6) << 5 | cfield(rd) << 2
+}
+
+/// CL/CS with uimm[5:3|7:6], the double-word loads and stores
+fn cl_double(funct3: u32, rd: u8, rs1: u8, imm: i32) -> u32 {
+    let imm = imm as u32;
+    funct3 << 13 | bits(imm, 5, 3) << 10 | cfield(rs1) << 7 | bits(imm, 7, 6) << 5 | cfield(rd) << 2
+}
+
+/// C.LWSP/C.FLWSP, uimm[5|4:2|7:6]
+fn lwsp_type(funct3: u32, rd: u8, imm: i32) -> u32 {
+    let imm = imm as u32;
+    funct3 << 13 | bits(imm, 5, 5) << 12 | reg(rd) << 7 | bits(imm, 4, 2) << 4 | bits(imm, 7, 6) << 2 | 0b10
+}
+
+/// C.LDSP/C.FLDSP, uimm[5|4:3|8:6]
+fn ldsp_type(funct3: u32, rd: u8, imm: i32) -> u32 {
+    let imm = imm as u32;
+    funct3 << 13 | bits(imm, 5, 5) << 12 | reg(rd) << 7 | bits(imm, 4, 3) << 5 | bits(imm, 8, 6) << 2 | 0b10
+}
+
+/// C.SWSP/C.FSWSP, uimm[5:2|7:6]
+fn swsp_type(funct3: u32, rs2: u8, imm: i32) -> u32 {
+    let imm = imm as u32;
+    funct3 << 13 | bits(imm, 5, 2) << 9 | bits(imm, 7, 6) << 7 | reg(rs2) << 2 | 0b10
+}
+
+/// C.SDSP/C.FSDSP, uimm[5:3|8:6]
+fn sdsp_type(funct3: u32, rs2: u8, imm: i32) -> u32 {
+    let imm = imm as u32;
+    funct3 << 13 | bits(imm, 5, 3) << 10 | bits(imm, 8, 6) << 7 | reg(rs2) << 2 | 0b10
+}
+
+/// C.SRLI/C.SRAI/C.ANDI on rd'
+fn cb_arith(funct2: u32, rd: u8, imm: i32) -> u32 {
+    let imm = imm as u32;
+    0b100 << 13 | bits(imm, 5, 5) << 12 | funct2 << 10 | cfield(rd) << 7 | bits(imm, 4, 0) << 2 | 0b01
+}
+
+/// C.SUB/C.XOR/C.OR/C.AND/C.SUBW/C.ADDW, funct is bit 12 followed by bits 6:5
+fn ca_type(funct: u32, rd: u8, rs2: u8) -> u32 {
+    0b100 << 13 | bits(funct, 2, 2) << 12 | 0b11 << 10 | cfield(rd) << 7 | bits(funct, 1, 0) << 5 | cfield(rs2) << 2 | 0b01
+}
+
+/// C.J/C.JAL, offset[11|4|9:8|10|6|7|3:1|5]
+fn cj_type(funct3: u32, imm: i32) -> u32 {
+    let imm = imm as u32;
+    funct3 << 13 | bits(imm, 11, 11) << 12 | bits(imm, 4, 4) << 11 | bits(imm, 9, 8) << 9 | bits(imm, 10, 10) << 8
+        | bits(imm, 6, 6) << 7 | bits(imm, 7, 7) << 6 | bits(imm, 3, 1) << 3 | bits(imm, 5, 5) << 2 | 0b01
+}
+
+/// C.BEQZ/C.BNEZ, offset[8|4:3] and offset[7:6|2:1|5]
+fn cb_branch(funct3: u32, rs1: u8, imm: i32) -> u32 {
+    let imm = imm as u32;
+    funct3 << 13 | bits(imm, 8, 8) << 12 | bits(imm, 4, 3) << 10 | cfield(rs1) << 7
+        | bits(imm, 7, 6) << 5 | bits(imm, 2, 1) << 3 | bits(imm, 5, 5) << 2 | 0b01
+}
+
+/// Packs every compressed encoding that could expand to `instr`
+fn candidates(instr: &Instruction) -> Vec<u32> {
+    use Instruction::*;
+
+    match *instr {
+        Addi { rd, imm, .. } => {
+            let uimm = imm as u32;
+            // nzuimm[5:4|9:6|2|3] and nzimm[9|4|6|8:7|5]
+            let addi4spn = bits(uimm, 5, 4) << 11 | bits(uimm, 9, 6) << 7 | bits(uimm, 2, 2) << 6 | bits(uimm, 3, 3) << 5 | cfield(rd) << 2;
+            let addi16sp = 0b011 << 13 | bits(uimm, 9, 9) << 12 | 2 << 7 | bits(uimm, 4, 4) << 6 | bits(uimm, 6, 6) << 5
+                | bits(uimm, 8, 7) << 3 | bits(uimm, 5, 5) << 2 | 0b01;
+            vec![ci_type(0b000, rd, imm, 0b01), addi16sp, addi4spn, ci_type(0b010, rd, imm, 0b01)]
+        }
+        Addiw { rd, imm, .. } => vec![ci_type(0b001, rd, imm, 0b01)],
+        Lui { rd, imm } => vec![ci_type(0b011, rd, imm, 0b01)],
+        Slli { rd, shamt, .. } => vec![ci_type(0b000, rd, shamt as i32, 0b10)],
+        Srli { rd, shamt, .. } => vec![cb_arith(0b00, rd, shamt as i32)],
+        Srai { rd, shamt, .. } => vec![cb_arith(0b01, rd, shamt as i32)],
+        Andi { rd, imm, .. } => vec![cb_arith(0b10, rd, imm)],
+        Sub { rd, rs2, .. } => vec![ca_type(0b000, rd, rs2)],
+        Xor { rd, rs2, .. } => vec![ca_type(0b001, rd, rs2)],
+        Or { rd, rs2, .. } => vec![ca_type(0b010, rd, rs2)],
+        And { rd, rs2, .. } => vec![ca_type(0b011, rd, rs2)],
+        Subw { rd, rs2, .. } => vec![ca_type(0b100, rd, rs2)],
+        Addw { rd, rs2, .. } => vec![ca_type(0b101, rd, rs2)],
+        Add { rd, rs2, .. } => vec![cr_type(0b1000, rd, rs2), cr_type(0b1001, rd, rs2)],
+        Jal { imm, .. } => vec![cj_type(0b101, imm), cj_type(0b001, imm)],
+        Jalr { rs1, .. } => vec![cr_type(0b1000, rs1, 0), cr_type(0b1001, rs1, 0)],
+        Beq { rs1, imm, .. } => vec![cb_branch(0b110, rs1, imm)],
+        Bne { rs1, imm, .. } => vec![cb_branch(0b111, rs1, imm)],
+        Ebreak => vec![cr_type(0b1001, 0, 0)],
+        Lw { rd, rs1, imm } => vec![lwsp_type(0b010, rd, imm), cl_word(0b010, rd, rs1, imm)],
+        Flw { rd, rs1, imm } => vec![lwsp_type(0b011, rd, imm), cl_word(0b011, rd, rs1, imm)],
+        Ld { rd, rs1, imm } => vec![ldsp_type(0b011, rd, imm), cl_double(0b011, rd, rs1, imm)],
+        Fld { rd, rs1, imm } => vec![ldsp_type(0b001, rd, imm), cl_double(0b001, rd, rs1, imm)],
+        Sw { rs1, rs2, imm } => vec![swsp_type(0b110, rs2, imm), cl_word(0b110, rs2, rs1, imm)],
+        Fsw { rs1, rs2, imm } => vec![swsp_type(0b111, rs2, imm), cl_word(0b111, rs2, rs1, imm)],
+        Sd { rs1, rs2, imm } => vec![sdsp_type(0b111, rs2, imm), cl_double(0b111, rs2, rs1, imm)],
+        Fsd { rs1, rs2, imm } => vec![sdsp_type(0b101, rs2, imm), cl_double(0b101, rs2, rs1, imm)],
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+
+use crate::{CompressedInstruction, CompressionSummary, DecodeError, DecodeObserver, EncodeError, Extensions, Instruction, ParseError};
+
+/// Base integer register width the decoder targets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Xlen {
+    #[default]
+    Rv32,
+    Rv64,
+}
+
+impl fmt::Display for Xlen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Xlen::Rv32 => f.write_str("RV32"),
+            Xlen::Rv64 => f.write_str("RV64"),
+        }
+    }
+}
+
+/// Selects the base ISA the decoder accepts
+///
+/// The default configuration is RV32, which is what `decode` uses. Under RV32
+/// the RV64-only encodings (LD, ADDIW, 6-bit shift amounts, ...) are rejected
+/// with `DecodeError::UnsupportedXlen`.
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{DecodeError, DecoderConfig, Instruction, Xlen};
+///
+/// let decoded = DecoderConfig::rv64().decode(0x00813183).unwrap();
+/// assert_eq!(decoded, Instruction::Ld { rd: 3, rs1: 2, imm: 8 });
+///
+/// let err = DecoderConfig::rv32().decode(0x00813183).unwrap_err();
+/// assert_eq!(err, DecodeError::UnsupportedXlen { word: 0x00813183, xlen: Xlen::Rv32 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DecoderConfig {
+    pub xlen: Xlen,
+}
+
+impl DecoderConfig {
+    /// Configuration for the RV32 base ISA
+    pub fn rv32() -> Self {
+        DecoderConfig { xlen: Xlen::Rv32 }
+    }
+
+    /// Configuration for the RV64 base ISA
+    pub fn rv64() -> Self {
+        DecoderConfig { xlen: Xlen::Rv64 }
+    }
+
+    /// Decodes a RISC-V 32-bit instruction word under this configuration
+    pub fn decode(&self, instr: u32) -> Result<Instruction, DecodeError> {
+        return crate::decode_with_config(instr, self);
+    }
+
+    /// Decodes a RISC-V 32-bit instruction word under this configuration, reporting the breakdown to `observer`
+    pub fn decode_with_observer<O: DecodeObserver>(&self, instr: u32, observer: &mut O) -> Result<Instruction, DecodeError> {
+        return crate::observer::decode_with_observer_and_config(instr, observer, self);
+    }
+
+    /// Encodes an instruction into its 32-bit instruction word under this configuration
+    pub fn encode(&self, instr: &Instruction) -> Result<u32, EncodeError> {
+        return crate::encoder::encode_with_config(instr, self);
+    }
+
+    /// Parses one line of assembly text into an `Instruction` under this configuration
+    pub fn parse(&self, line: &str) -> Result<Instruction, ParseError> {
+        return crate::parser::parse_with_config(line, self);
+    }
+
+    /// Decodes a 16-bit compressed instruction under this configuration
+    pub fn decode_compressed(&self, parcel: u16) -> Result<CompressedInstruction, DecodeError> {
+        return crate::compressed::decode_compressed_with_config(parcel, self);
+    }
+
+    /// Expands a 16-bit compressed instruction to its 32-bit instruction word under this configuration
+    pub fn expand_compressed(&self, parcel: u16) -> Result<u32, DecodeError> {
+        return Ok(self.decode_compressed(parcel)?.expanded_word());
+    }
+
+    /// Finds the compressed encoding of a 32-bit instruction under this configuration
+    pub fn compress(&self, instr: &Instruction, extensions: &Extensions) -> Option<CompressedInstruction> {
+        return crate::compressed::compress_with_config(instr, self, extensions);
+    }
+
+    /// Counts how many bytes compressing a little-endian code buffer would save under this configuration
+    ///
+    /// The buffer may mix 16-bit and 32-bit instructions.
+    pub fn compression_summary(&self, bytes: &[u8], extensions: &Extensions) -> Result<CompressionSummary, DecodeError> {
+        return crate::compressed::compression_summary_with_config(bytes, self, extensions);
+    }
+
+    /// Decodes an instruction stored as little-endian bytes under this configuration
+    ///
+    /// Only the first four bytes are read.
+    pub fn decode_bytes(&self, bytes: &[u8]) -> Result<Instruction, DecodeError> {
+        if bytes.len() < 4 {
+            return Err(DecodeError::TooShort { len: bytes.len(), needed: 4 });
+        }
+        let word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        return self.decode(word);
+    }
+
+    /// Fails with `UnsupportedXlen` unless this configuration is RV64
+    pub(crate) fn require_rv64(&self, instr: u32) -> Result<(), DecodeError> {
+        if self.xlen != Xlen::Rv64 {
+            return Err(DecodeError::UnsupportedXlen { word: instr, xlen: self.xlen });
+        }
+        return Ok(());
+    }
+
+    /// Fails with `UnsupportedXlen` unless this configuration is RV32
+    pub(crate) fn require_rv32(&self, instr: u32) -> Result<(), DecodeError> {
+        if self.xlen != Xlen::Rv32 {
+            return Err(DecodeError::UnsupportedXlen { word: instr, xlen: self.xlen });
+        }
+        return Ok(());
+    }
+
+    /// Width of the shift amount of a register-immediate shift, 6 bits under
+    /// RV64 and 5 bits under RV32
+    pub(crate) fn shamt_width(&self) -> u32 {
+        match self.xlen {
+            Xlen::Rv32 => 5,
+            Xlen::Rv64 => 6,
+        }
+    }
+
+    /// Extracts the shift amount of a register-immediate shift, 6 bits wide
+    /// under RV64 and 5 bits wide under RV32
+    pub(crate) fn shamt(&self, instr: u32) -> Result<u8, DecodeError> {
+        let shamt = (instr >> 20) & 0x3f;
+        if shamt > 31 {
+            self.require_rv64(instr)?;
+        }
+        return Ok(shamt as u8);
+    }
+}
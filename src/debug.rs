@@ -0,0 +1,168 @@
+use std::fmt;
+
+use crate::csr::csr_text;
+use crate::{bits, word_from_bit_strings, DecodeError};
+
+/*
+ * Debug Module abstract commands
+ *
+ * A debugger starts an abstract command by writing a 32-bit word to the
+ * `command` register of the Debug Module (RISC-V Debug Specification 1.0,
+ * section 3.7). cmdtype selects the layout of the 24-bit control field:
+ *
+ * Access Register (cmdtype 0)
+ *   31-----24 23 22-----20 19                18         17         16      15-----0
+ *   /cmdtype/ /0/ /aarsize/ /aarpostincrement/ /postexec/ /transfer/ /write/ /regno/
+ *
+ * Quick Access (cmdtype 1)
+ *   31-----24 23-----------------------------------------------------------------0
+ *   /cmdtype/                                /0/
+ *
+ * Access Memory (cmdtype 2)
+ *   31-----24 23           22-----20 19                18--17 16      15-------14 13--0
+ *   /cmdtype/ /aamvirtual/ /aamsize/ /aampostincrement/  /0/  /write/ /target-specific/ /0/
+ *
+ * aarsize/aamsize give the access width as 8 << size bits. Registers are
+ * accessed 32, 64 or 128 bits wide, memory 8 to 128 bits wide.
+ */
+
+/// A decoded Debug Module abstract command
+///
+/// `Display` breaks the command out field by field, e.g.
+/// "Access Register: cmdtype=0, aarsize=2 (32-bit), aarpostincrement=0, postexec=0, transfer=1, write=0, regno=0x1005 (x5)".
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{decode_abstract_command, AbstractCommand};
+///
+/// let command = decode_abstract_command(0x00221005).unwrap();
+/// assert_eq!(command, AbstractCommand::AccessRegister {
+///     aarsize: 2,
+///     aarpostincrement: false,
+///     postexec: false,
+///     transfer: true,
+///     write: false,
+///     regno: 0x1005,
+/// });
+/// assert_eq!(command.cmdtype(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbstractCommand {
+    AccessRegister { aarsize: u8, aarpostincrement: bool, postexec: bool, transfer: bool, write: bool, regno: u16 },
+    QuickAccess,
+    AccessMemory { aamvirtual: bool, aamsize: u8, aampostincrement: bool, write: bool, target_specific: u8 },
+}
+
+impl AbstractCommand {
+    /// Returns the cmdtype field selecting the kind of command
+    pub fn cmdtype(&self) -> u8 {
+        match self {
+            AbstractCommand::AccessRegister { .. } => 0,
+            AbstractCommand::QuickAccess => 1,
+            AbstractCommand::AccessMemory { .. } => 2,
+        }
+    }
+
+    /// Returns the name of the command, e.g. "Access Register"
+    pub fn name(&self) -> &'static str {
+        match self {
+            AbstractCommand::AccessRegister { .. } => "Access Register",
+            AbstractCommand::QuickAccess => "Quick Access",
+            AbstractCommand::AccessMemory { .. } => "Access Memory",
+        }
+    }
+}
+
+/// Formats an abstract command register number as the register it selects
+fn regno_text(regno: u16) -> String {
+    match regno {
+        0x0000..=0x0fff => csr_text(regno),
+        0x1000..=0x101f => format!("x{}", regno - 0x1000),
+        0x1020..=0x103f => format!("f{}", regno - 0x1020),
+        _ => format!("{:#x}", regno),
+    }
+}
+
+impl fmt::Display for AbstractCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: cmdtype={}", self.name(), self.cmdtype())?;
+        match *self {
+            AbstractCommand::AccessRegister { aarsize, aarpostincrement, postexec, transfer, write, regno } => {
+                write!(f, ", aarsize={} ({}-bit), aarpostincrement={}, postexec={}, transfer={}, write={}, regno={:#x} ({})",
+                    aarsize, 8 << aarsize, aarpostincrement as u8, postexec as u8, transfer as u8, write as u8, regno, regno_text(regno))
+            }
+            AbstractCommand::QuickAccess => Ok(()),
+            AbstractCommand::AccessMemory { aamvirtual, aamsize, aampostincrement, write, target_specific } => {
+                write!(f, ", aamvirtual={}, aamsize={} ({}-bit), aampostincrement={}, write={}, target-specific={}",
+                    aamvirtual as u8, aamsize, 8 << aamsize, aampostincrement as u8, write as u8, target_specific)
+            }
+        }
+    }
+}
+
+/// Decodes a 32-bit Debug Module abstract command word
+///
+/// Unknown command types, reserved sizes and nonzero reserved control bits
+/// fail with `DecodeError::ReservedFunct`.
+pub fn decode_abstract_command(command: u32) -> Result<AbstractCommand, DecodeError> {
+    let cmdtype = bits(command, 31, 24);
+    let control = bits(command, 23, 0);
+
+    match cmdtype {
+        0 => {      // Access Register
+            let aarsize = bits(command, 22, 20);
+            if bits(command, 23, 23) != 0 {
+                return Err(DecodeError::ReservedFunct { word: command, field: "control", value: control });
+            }
+            if !(2..=4).contains(&aarsize) {
+                return Err(DecodeError::ReservedFunct { word: command, field: "aarsize", value: aarsize });
+            }
+            return Ok(AbstractCommand::AccessRegister {
+                aarsize: aarsize as u8,
+                aarpostincrement: bits(command, 19, 19) == 1,
+                postexec: bits(command, 18, 18) == 1,
+                transfer: bits(command, 17, 17) == 1,
+                write: bits(command, 16, 16) == 1,
+                regno: bits(command, 15, 0) as u16,
+            });
+        }
+        1 => {      // Quick Access, the control field is reserved
+            if control != 0 {
+                return Err(DecodeError::ReservedFunct { word: command, field: "control", value: control });
+            }
+            return Ok(AbstractCommand::QuickAccess);
+        }
+        2 => {      // Access Memory
+            let aamsize = bits(command, 22, 20);
+            if bits(command, 18, 17) != 0 || bits(command, 13, 0) != 0 {
+                return Err(DecodeError::ReservedFunct { word: command, field: "control", value: control });
+            }
+            if aamsize > 4 {
+                return Err(DecodeError::ReservedFunct { word: command, field: "aamsize", value: aamsize });
+            }
+            return Ok(AbstractCommand::AccessMemory {
+                aamvirtual: bits(command, 23, 23) == 1,
+                aamsize: aamsize as u8,
+                aampostincrement: bits(command, 19, 19) == 1,
+                write: bits(command, 16, 16) == 1,
+                target_specific: bits(command, 15, 14) as u8,
+            });
+        }
+        _ => return Err(DecodeError::ReservedFunct { word: command, field: "cmdtype", value: cmdtype }),
+    }
+}
+
+/// Takes a 32-bit binary Debug Module abstract command and returns its field breakdown
+///
+/// The command is a vector of "0"/"1" strings, most significant bit first,
+/// like the instructions taken by `decode_instruction`. The returned text is
+/// the `Display` form of the `AbstractCommand` produced by
+/// `decode_abstract_command`. Only the first 32 entries are read.
+pub fn abstract_command_decoder(command: Vec<String>) -> Result<String, DecodeError> {
+    if command.len() < 32 {
+        return Err(DecodeError::TooShort { len: command.len(), needed: 32 });
+    }
+    let word = word_from_bit_strings(&command[..32])?;
+    return Ok(decode_abstract_command(word)?.to_string());
+}
@@ -0,0 +1,666 @@
+use crate::vector::{self, Sew, VectorAddressing, VectorOperand};
+use crate::{DecoderConfig, EncodeError, Instruction, RoundingMode, Xlen};
+
+/*
+ * Encoding mirrors the decoder: every instruction is assembled from its
+ * opcode, function fields and operands using one helper per format. The
+ * helpers validate register numbers and immediate ranges/alignment so an
+ * out of range operand is reported instead of being silently truncated.
+ */
+
+/// Checks that a register number fits the 5-bit register field
+fn reg(register: u8) -> Result<u32, EncodeError> {
+    if register > 31 {
+        return Err(EncodeError::InvalidRegister { register });
+    }
+    return Ok(register as u32);
+}
+
+/// Checks that `imm` is a multiple of `alignment` and fits a signed `width`-bit field
+fn signed_imm(imm: i32, width: u32, alignment: i64) -> Result<u32, EncodeError> {
+    let imm = imm as i64;
+    let min = -(1i64 << (width - 1));
+    let max = (1i64 << (width - 1)) - alignment;
+    if imm < min || imm > max {
+        return Err(EncodeError::ImmediateOutOfRange { imm, min, max });
+    }
+    if imm % alignment != 0 {
+        return Err(EncodeError::MisalignedImmediate { imm, alignment });
+    }
+    return Ok(imm as u32);
+}
+
+/// Checks that `value` fits an unsigned `width`-bit field
+fn unsigned_imm(value: u8, width: u32) -> Result<u32, EncodeError> {
+    let max = (1i64 << width) - 1;
+    if value as i64 > max {
+        return Err(EncodeError::ImmediateOutOfRange { imm: value as i64, min: 0, max });
+    }
+    return Ok(value as u32);
+}
+
+fn rm_encoder(rm: RoundingMode) -> u32 {
+    match rm {
+        RoundingMode::Rne => 0b000,
+        RoundingMode::Rtz => 0b001,
+        RoundingMode::Rdn => 0b010,
+        RoundingMode::Rup => 0b011,
+        RoundingMode::Rmm => 0b100,
+        RoundingMode::Dyn => 0b111,
+    }
+}
+
+fn r_type(opcode: u32, funct3: u32, funct7: u32, rd: u8, rs1: u8, rs2: u8) -> Result<u32, EncodeError> {
+    return Ok(funct7 << 25 | reg(rs2)? << 20 | reg(rs1)? << 15 | funct3 << 12 | reg(rd)? << 7 | opcode);
+}
+
+fn r4_type(opcode: u32, fmt: u32, rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode) -> Result<u32, EncodeError> {
+    let funct7 = reg(rs3)? << 2 | fmt;
+    return r_type(opcode, rm_encoder(rm), funct7, rd, rs1, rs2);
+}
+
+fn i_type(opcode: u32, funct3: u32, rd: u8, rs1: u8, imm: i32) -> Result<u32, EncodeError> {
+    let imm = signed_imm(imm, 12, 1)?;
+    return Ok((imm & 0xfff) << 20 | reg(rs1)? << 15 | funct3 << 12 | reg(rd)? << 7 | opcode);
+}
+
+/// Register-immediate shift with a `width`-bit shift amount (6 bits under
+/// RV64 and for SLLI.UW, 5 bits under RV32 and for the word forms)
+fn shift_type(opcode: u32, funct3: u32, funct7: u32, rd: u8, rs1: u8, shamt: u8, width: u32) -> Result<u32, EncodeError> {
+    let shamt = unsigned_imm(shamt, width)?;
+    return Ok(funct7 << 25 | shamt << 20 | reg(rs1)? << 15 | funct3 << 12 | reg(rd)? << 7 | opcode);
+}
+
+/// AES32 and SM4 rounds carry the byte select in the top two bits of funct7
+fn bs_type(funct5: u32, rd: u8, rs1: u8, rs2: u8, bs: u8) -> Result<u32, EncodeError> {
+    return r_type(0b0110011, 0b000, unsigned_imm(bs, 2)? << 5 | funct5, rd, rs1, rs2);
+}
+
+fn fence_type(fm: u32, pred: u8, succ: u8) -> Result<u32, EncodeError> {
+    let pred = unsigned_imm(pred, 4)?;
+    let succ = unsigned_imm(succ, 4)?;
+    return Ok(fm << 28 | pred << 24 | succ << 20 | 0b0001111);
+}
+
+/// CSR access, `source` is the rs1 field holding a register or a 5-bit immediate
+fn csr_type(funct3: u32, rd: u8, source: u32, csr: u16) -> Result<u32, EncodeError> {
+    if csr > 0xfff {
+        return Err(EncodeError::ImmediateOutOfRange { imm: csr as i64, min: 0, max: 0xfff });
+    }
+    return Ok((csr as u32) << 20 | source << 15 | funct3 << 12 | reg(rd)? << 7 | 0b1110011);
+}
+
+fn s_type(opcode: u32, funct3: u32, rs1: u8, rs2: u8, imm: i32) -> Result<u32, EncodeError> {
+    let imm = signed_imm(imm, 12, 1)?;
+    return Ok((imm >> 5 & 0x7f) << 25 | reg(rs2)? << 20 | reg(rs1)? << 15 | funct3 << 12 | (imm & 0x1f) << 7 | opcode);
+}
+
+fn b_type(opcode: u32, funct3: u32, rs1: u8, rs2: u8, imm: i32) -> Result<u32, EncodeError> {
+    let imm = signed_imm(imm, 13, 2)?;
+    return Ok((imm >> 12 & 0x1) << 31        // imm [12]
+        | (imm >> 5 & 0x3f) << 25             // imm [10:5]
+        | reg(rs2)? << 20
+        | reg(rs1)? << 15
+        | funct3 << 12
+        | (imm >> 1 & 0xf) << 8               // imm [4:1]
+        | (imm >> 11 & 0x1) << 7              // imm [11]
+        | opcode);
+}
+
+fn u_type(opcode: u32, rd: u8, imm: i32) -> Result<u32, EncodeError> {
+    let imm = signed_imm(imm, 20, 1)?;
+    return Ok((imm & 0xfffff) << 12 | reg(rd)? << 7 | opcode);
+}
+
+fn j_type(opcode: u32, rd: u8, imm: i32) -> Result<u32, EncodeError> {
+    let imm = signed_imm(imm, 21, 2)?;
+    return Ok((imm >> 20 & 0x1) << 31        // imm [20]
+        | (imm >> 1 & 0x3ff) << 21            // imm [10:1]
+        | (imm >> 11 & 0x1) << 20             // imm [11]
+        | (imm >> 12 & 0xff) << 12            // imm [19:12]
+        | reg(rd)? << 7
+        | opcode);
+}
+
+/// Atomic memory operation, `funct3` selects word (010) or double-word (011) access
+fn amo_type(funct5: u32, funct3: u32, rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool) -> Result<u32, EncodeError> {
+    let funct7 = funct5 << 2 | (aq as u32) << 1 | rl as u32;
+    return r_type(0b0101111, funct3, funct7, rd, rs1, rs2);
+}
+
+fn fp_type(funct5: u32, fmt: u32, funct3: u32, rd: u8, rs1: u8, rs2: u8) -> Result<u32, EncodeError> {
+    return r_type(0b1010011, funct3, funct5 << 2 | fmt, rd, rs1, rs2);
+}
+
+/// OP-V arithmetic, `source` is the vs1/rs1/imm field
+fn op_v_type(opcode: u32, funct6: u32, funct3: u32, vd: u8, source: u32, vs2: u8, vm: bool) -> Result<u32, EncodeError> {
+    return Ok(funct6 << 26 | (vm as u32) << 25 | reg(vs2)? << 20 | source << 15 | funct3 << 12 | reg(vd)? << 7 | opcode);
+}
+
+/// The vs1/rs1/imm field of a vector arithmetic instruction, immediates are
+/// 5 bits wide except the 6-bit rotate amount of VROR.VI
+fn vector_source(src: VectorOperand, (min, max): (i64, i64)) -> Result<u32, EncodeError> {
+    match src {
+        VectorOperand::Vector(n) | VectorOperand::Scalar(n) | VectorOperand::Float(n) => reg(n),
+        VectorOperand::Imm(imm) => {
+            if (imm as i64) < min || imm as i64 > max {
+                return Err(EncodeError::ImmediateOutOfRange { imm: imm as i64, min, max });
+            }
+            let width = if max > 31 { 0x3f } else { 0x1f };
+            return Ok(imm as u32 & width);
+        }
+    }
+}
+
+/// Vector load or store, `data` is vd or vs3 and `nf` the field count (1..=8)
+fn vector_memory_type(opcode: u32, data: u8, rs1: u8, addressing: VectorAddressing, eew: Sew, nf: u8, vm: bool) -> Result<u32, EncodeError> {
+    let load = opcode == 0b0000111;
+    if !(1..=8).contains(&nf) {
+        return Err(EncodeError::ImmediateOutOfRange { imm: nf as i64, min: 1, max: 8 });
+    }
+    let (mop, field) = match addressing {
+        VectorAddressing::UnitStride => (0b00, 0b00000),
+        VectorAddressing::FaultOnlyFirst if load => (0b00, 0b10000),
+        VectorAddressing::WholeRegister if vm && matches!(nf, 1 | 2 | 4 | 8) && data % nf == 0 && (load || eew == Sew::E8) => (0b00, 0b01000),
+        VectorAddressing::Mask if vm && nf == 1 && eew == Sew::E8 => (0b00, 0b01011),
+        VectorAddressing::IndexedUnordered { vs2 } => (0b01, reg(vs2)?),
+        VectorAddressing::Strided { rs2 } => (0b10, reg(rs2)?),
+        VectorAddressing::IndexedOrdered { vs2 } => (0b11, reg(vs2)?),
+        _ => return Err(EncodeError::ReservedEncoding),
+    };
+    return Ok((nf as u32 - 1) << 29 | mop << 26 | (vm as u32) << 25 | field << 20 | reg(rs1)? << 15
+        | eew.width() << 12 | reg(data)? << 7 | opcode);
+}
+
+/// Base ISA an instruction is restricted to, `None` when it exists for both
+fn required_xlen(instruction: &Instruction) -> Option<Xlen> {
+    use Instruction::*;
+    match *instruction {
+        Lwu { .. } | Ld { .. } | Sd { .. }
+        | Addiw { .. } | Slliw { .. } | Srliw { .. } | Sraiw { .. }
+        | Addw { .. } | Subw { .. } | Sllw { .. } | Srlw { .. } | Sraw { .. }
+        | Mulw { .. } | Divw { .. } | Divuw { .. } | Remw { .. } | Remuw { .. }
+        | LrD { .. } | ScD { .. } | AmoswapD { .. } | AmoaddD { .. } | AmoxorD { .. }
+        | AmoandD { .. } | AmoorD { .. } | AmominD { .. } | AmomaxD { .. } | AmominuD { .. } | AmomaxuD { .. }
+        | FcvtLS { .. } | FcvtLuS { .. } | FcvtSL { .. } | FcvtSLu { .. }
+        | FcvtLD { .. } | FcvtLuD { .. } | FcvtDL { .. } | FcvtDLu { .. }
+        | FcvtLH { .. } | FcvtLuH { .. } | FcvtHL { .. } | FcvtHLu { .. }
+        | FcvtLQ { .. } | FcvtLuQ { .. } | FcvtQL { .. } | FcvtQLu { .. }
+        | FmvXD { .. } | FmvDX { .. } | FmvhXQ { .. } | FmvpQX { .. }
+        | AddUw { .. } | Sh1addUw { .. } | Sh2addUw { .. } | Sh3addUw { .. }
+        | Rolw { .. } | Rorw { .. } | Clzw { .. } | Ctzw { .. } | Cpopw { .. }
+        | SlliUw { .. } | Roriw { .. } | Packw { .. }
+        | Aes64es { .. } | Aes64esm { .. } | Aes64ds { .. } | Aes64dsm { .. }
+        | Aes64ks1i { .. } | Aes64ks2 { .. } | Aes64im { .. }
+        | Sha512sum0 { .. } | Sha512sum1 { .. } | Sha512sig0 { .. } | Sha512sig1 { .. } => Some(Xlen::Rv64),
+        Zip { .. } | Unzip { .. }
+        | Aes32esi { .. } | Aes32esmi { .. } | Aes32dsi { .. } | Aes32dsmi { .. }
+        | Sha512sum0r { .. } | Sha512sum1r { .. } | Sha512sig0l { .. } | Sha512sig1l { .. } | Sha512sig0h { .. } | Sha512sig1h { .. }
+        | FmvhXD { .. } | FmvpDX { .. } => Some(Xlen::Rv32),
+        _ => None,
+    }
+}
+
+/// Encodes an instruction back into its RISC-V 32-bit instruction word
+///
+/// Encodes for RV32, use `DecoderConfig` to select RV64. Instructions that only
+/// exist for RV64, such as LD or ADDIW, fail with `EncodeError::UnsupportedXlen`
+/// and shift amounts are limited to 5 bits.
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{decode, encode, Instruction};
+///
+/// let word = encode(&Instruction::Lb { rd: 4, rs1: 0, imm: 4 }).unwrap();
+/// assert_eq!(word, 0x00400203);
+/// assert_eq!(decode(word).unwrap(), Instruction::Lb { rd: 4, rs1: 0, imm: 4 });
+///
+/// // Branch offsets must be even
+/// assert!(encode(&Instruction::Beq { rs1: 1, rs2: 2, imm: 3 }).is_err());
+/// ```
+pub fn encode(instruction: &Instruction) -> Result<u32, EncodeError> {
+    return encode_with_config(instruction, &DecoderConfig::default());
+}
+
+pub(crate) fn encode_with_config(instruction: &Instruction, config: &DecoderConfig) -> Result<u32, EncodeError> {
+    use Instruction::*;
+    if let Some(xlen) = required_xlen(instruction) {
+        if xlen != config.xlen {
+            return Err(EncodeError::UnsupportedXlen { xlen: config.xlen });
+        }
+    }
+    let shamt_width = config.shamt_width();
+    match *instruction {
+        // Loads
+        Lb { rd, rs1, imm } => i_type(0b0000011, 0b000, rd, rs1, imm),
+        Lh { rd, rs1, imm } => i_type(0b0000011, 0b001, rd, rs1, imm),
+        Lw { rd, rs1, imm } => i_type(0b0000011, 0b010, rd, rs1, imm),
+        Lbu { rd, rs1, imm } => i_type(0b0000011, 0b100, rd, rs1, imm),
+        Lhu { rd, rs1, imm } => i_type(0b0000011, 0b101, rd, rs1, imm),
+        Lwu { rd, rs1, imm } => i_type(0b0000011, 0b110, rd, rs1, imm),
+        Ld { rd, rs1, imm } => i_type(0b0000011, 0b011, rd, rs1, imm),
+
+        // Stores
+        Sb { rs1, rs2, imm } => s_type(0b0100011, 0b000, rs1, rs2, imm),
+        Sh { rs1, rs2, imm } => s_type(0b0100011, 0b001, rs1, rs2, imm),
+        Sw { rs1, rs2, imm } => s_type(0b0100011, 0b010, rs1, rs2, imm),
+        Sd { rs1, rs2, imm } => s_type(0b0100011, 0b011, rs1, rs2, imm),
+
+        // Branches
+        Beq { rs1, rs2, imm } => b_type(0b1100011, 0b000, rs1, rs2, imm),
+        Bne { rs1, rs2, imm } => b_type(0b1100011, 0b001, rs1, rs2, imm),
+        Blt { rs1, rs2, imm } => b_type(0b1100011, 0b100, rs1, rs2, imm),
+        Bge { rs1, rs2, imm } => b_type(0b1100011, 0b101, rs1, rs2, imm),
+        Bltu { rs1, rs2, imm } => b_type(0b1100011, 0b110, rs1, rs2, imm),
+        Bgeu { rs1, rs2, imm } => b_type(0b1100011, 0b111, rs1, rs2, imm),
+
+        // Register-immediate
+        Addi { rd, rs1, imm } => i_type(0b0010011, 0b000, rd, rs1, imm),
+        Slti { rd, rs1, imm } => i_type(0b0010011, 0b010, rd, rs1, imm),
+        Sltiu { rd, rs1, imm } => i_type(0b0010011, 0b011, rd, rs1, imm),
+        Xori { rd, rs1, imm } => i_type(0b0010011, 0b100, rd, rs1, imm),
+        Ori { rd, rs1, imm } => i_type(0b0010011, 0b110, rd, rs1, imm),
+        Andi { rd, rs1, imm } => i_type(0b0010011, 0b111, rd, rs1, imm),
+        Slli { rd, rs1, shamt } => shift_type(0b0010011, 0b001, 0b0000000, rd, rs1, shamt, shamt_width),
+        Srli { rd, rs1, shamt } => shift_type(0b0010011, 0b101, 0b0000000, rd, rs1, shamt, shamt_width),
+        Srai { rd, rs1, shamt } => shift_type(0b0010011, 0b101, 0b0100000, rd, rs1, shamt, shamt_width),
+
+        // Upper immediates and jumps
+        Lui { rd, imm } => u_type(0b0110111, rd, imm),
+        Auipc { rd, imm } => u_type(0b0010111, rd, imm),
+        Jal { rd, imm } => j_type(0b1101111, rd, imm),
+        Jalr { rd, rs1, imm } => i_type(0b1100111, 0b000, rd, rs1, imm),
+
+        // Register-register
+        Add { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0000000, rd, rs1, rs2),
+        Sub { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0100000, rd, rs1, rs2),
+        Sll { rd, rs1, rs2 } => r_type(0b0110011, 0b001, 0b0000000, rd, rs1, rs2),
+        Slt { rd, rs1, rs2 } => r_type(0b0110011, 0b010, 0b0000000, rd, rs1, rs2),
+        Sltu { rd, rs1, rs2 } => r_type(0b0110011, 0b011, 0b0000000, rd, rs1, rs2),
+        Xor { rd, rs1, rs2 } => r_type(0b0110011, 0b100, 0b0000000, rd, rs1, rs2),
+        Srl { rd, rs1, rs2 } => r_type(0b0110011, 0b101, 0b0000000, rd, rs1, rs2),
+        Sra { rd, rs1, rs2 } => r_type(0b0110011, 0b101, 0b0100000, rd, rs1, rs2),
+        Or { rd, rs1, rs2 } => r_type(0b0110011, 0b110, 0b0000000, rd, rs1, rs2),
+        And { rd, rs1, rs2 } => r_type(0b0110011, 0b111, 0b0000000, rd, rs1, rs2),
+
+        // Memory ordering and environment calls
+        Fence { pred, succ } => fence_type(0b0000, pred, succ),
+        FenceTso => fence_type(0b1000, 0b0011, 0b0011),
+        Pause => fence_type(0b0000, 0b0001, 0b0000),
+        FenceI => Ok(0x0000100f),
+        Ecall => Ok(0x00000073),
+        Ebreak => Ok(0x00100073),
+
+        // Privileged
+        Sret => Ok(0x10200073),
+        Mret => Ok(0x30200073),
+        Mnret => Ok(0x70200073),
+        Dret => Ok(0x7b200073),
+        Wfi => Ok(0x10500073),
+        SfenceVma { rs1, rs2 } => r_type(0b1110011, 0b000, 0b0001001, 0, rs1, rs2),
+        SinvalVma { rs1, rs2 } => r_type(0b1110011, 0b000, 0b0001011, 0, rs1, rs2),
+        SfenceWInval => Ok(0x18000073),
+        SfenceInvalIr => Ok(0x18100073),
+
+        // Zicsr
+        Csrrw { rd, rs1, csr } => csr_type(0b001, rd, reg(rs1)?, csr),
+        Csrrs { rd, rs1, csr } => csr_type(0b010, rd, reg(rs1)?, csr),
+        Csrrc { rd, rs1, csr } => csr_type(0b011, rd, reg(rs1)?, csr),
+        Csrrwi { rd, uimm, csr } => csr_type(0b101, rd, unsigned_imm(uimm, 5)?, csr),
+        Csrrsi { rd, uimm, csr } => csr_type(0b110, rd, unsigned_imm(uimm, 5)?, csr),
+        Csrrci { rd, uimm, csr } => csr_type(0b111, rd, unsigned_imm(uimm, 5)?, csr),
+
+        // Zba/Zbb/Zbc/Zbs
+        Sh1add { rd, rs1, rs2 } => r_type(0b0110011, 0b010, 0b0010000, rd, rs1, rs2),
+        Sh2add { rd, rs1, rs2 } => r_type(0b0110011, 0b100, 0b0010000, rd, rs1, rs2),
+        Sh3add { rd, rs1, rs2 } => r_type(0b0110011, 0b110, 0b0010000, rd, rs1, rs2),
+        Andn { rd, rs1, rs2 } => r_type(0b0110011, 0b111, 0b0100000, rd, rs1, rs2),
+        Orn { rd, rs1, rs2 } => r_type(0b0110011, 0b110, 0b0100000, rd, rs1, rs2),
+        Xnor { rd, rs1, rs2 } => r_type(0b0110011, 0b100, 0b0100000, rd, rs1, rs2),
+        Max { rd, rs1, rs2 } => r_type(0b0110011, 0b110, 0b0000101, rd, rs1, rs2),
+        Maxu { rd, rs1, rs2 } => r_type(0b0110011, 0b111, 0b0000101, rd, rs1, rs2),
+        Min { rd, rs1, rs2 } => r_type(0b0110011, 0b100, 0b0000101, rd, rs1, rs2),
+        Minu { rd, rs1, rs2 } => r_type(0b0110011, 0b101, 0b0000101, rd, rs1, rs2),
+        Rol { rd, rs1, rs2 } => r_type(0b0110011, 0b001, 0b0110000, rd, rs1, rs2),
+        Ror { rd, rs1, rs2 } => r_type(0b0110011, 0b101, 0b0110000, rd, rs1, rs2),
+        Clmul { rd, rs1, rs2 } => r_type(0b0110011, 0b001, 0b0000101, rd, rs1, rs2),
+        Clmulh { rd, rs1, rs2 } => r_type(0b0110011, 0b011, 0b0000101, rd, rs1, rs2),
+        Clmulr { rd, rs1, rs2 } => r_type(0b0110011, 0b010, 0b0000101, rd, rs1, rs2),
+        Bclr { rd, rs1, rs2 } => r_type(0b0110011, 0b001, 0b0100100, rd, rs1, rs2),
+        Bext { rd, rs1, rs2 } => r_type(0b0110011, 0b101, 0b0100100, rd, rs1, rs2),
+        Binv { rd, rs1, rs2 } => r_type(0b0110011, 0b001, 0b0110100, rd, rs1, rs2),
+        Bset { rd, rs1, rs2 } => r_type(0b0110011, 0b001, 0b0010100, rd, rs1, rs2),
+        Clz { rd, rs1 } => r_type(0b0010011, 0b001, 0b0110000, rd, rs1, 0b00000),
+        Ctz { rd, rs1 } => r_type(0b0010011, 0b001, 0b0110000, rd, rs1, 0b00001),
+        Cpop { rd, rs1 } => r_type(0b0010011, 0b001, 0b0110000, rd, rs1, 0b00010),
+        SextB { rd, rs1 } => r_type(0b0010011, 0b001, 0b0110000, rd, rs1, 0b00100),
+        SextH { rd, rs1 } => r_type(0b0010011, 0b001, 0b0110000, rd, rs1, 0b00101),
+        OrcB { rd, rs1 } => r_type(0b0010011, 0b101, 0b0010100, rd, rs1, 0b00111),
+        // ZEXT.H and REV8 exist for both XLENs with different encodings
+        ZextH { rd, rs1 } if config.xlen == Xlen::Rv64 => r_type(0b0111011, 0b100, 0b0000100, rd, rs1, 0),
+        ZextH { rd, rs1 } => r_type(0b0110011, 0b100, 0b0000100, rd, rs1, 0),
+        Rev8 { rd, rs1 } if config.xlen == Xlen::Rv64 => r_type(0b0010011, 0b101, 0b0110101, rd, rs1, 0b11000),
+        Rev8 { rd, rs1 } => r_type(0b0010011, 0b101, 0b0110100, rd, rs1, 0b11000),
+        Rori { rd, rs1, shamt } => shift_type(0b0010011, 0b101, 0b0110000, rd, rs1, shamt, shamt_width),
+        Bclri { rd, rs1, shamt } => shift_type(0b0010011, 0b001, 0b0100100, rd, rs1, shamt, shamt_width),
+        Bexti { rd, rs1, shamt } => shift_type(0b0010011, 0b101, 0b0100100, rd, rs1, shamt, shamt_width),
+        Binvi { rd, rs1, shamt } => shift_type(0b0010011, 0b001, 0b0110100, rd, rs1, shamt, shamt_width),
+        Bseti { rd, rs1, shamt } => shift_type(0b0010011, 0b001, 0b0010100, rd, rs1, shamt, shamt_width),
+
+        // RV64 Zba/Zbb word operations
+        AddUw { rd, rs1, rs2 } => r_type(0b0111011, 0b000, 0b0000100, rd, rs1, rs2),
+        Sh1addUw { rd, rs1, rs2 } => r_type(0b0111011, 0b010, 0b0010000, rd, rs1, rs2),
+        Sh2addUw { rd, rs1, rs2 } => r_type(0b0111011, 0b100, 0b0010000, rd, rs1, rs2),
+        Sh3addUw { rd, rs1, rs2 } => r_type(0b0111011, 0b110, 0b0010000, rd, rs1, rs2),
+        Rolw { rd, rs1, rs2 } => r_type(0b0111011, 0b001, 0b0110000, rd, rs1, rs2),
+        Rorw { rd, rs1, rs2 } => r_type(0b0111011, 0b101, 0b0110000, rd, rs1, rs2),
+        Clzw { rd, rs1 } => r_type(0b0011011, 0b001, 0b0110000, rd, rs1, 0b00000),
+        Ctzw { rd, rs1 } => r_type(0b0011011, 0b001, 0b0110000, rd, rs1, 0b00001),
+        Cpopw { rd, rs1 } => r_type(0b0011011, 0b001, 0b0110000, rd, rs1, 0b00010),
+        SlliUw { rd, rs1, shamt } => shift_type(0b0011011, 0b001, 0b0000100, rd, rs1, shamt, 6),
+        Roriw { rd, rs1, shamt } => shift_type(0b0011011, 0b101, 0b0110000, rd, rs1, shamt, 5),
+
+        // Zbkb/Zbkx
+        Pack { rd, rs1, rs2 } => r_type(0b0110011, 0b100, 0b0000100, rd, rs1, rs2),
+        Packh { rd, rs1, rs2 } => r_type(0b0110011, 0b111, 0b0000100, rd, rs1, rs2),
+        Packw { rd, rs1, rs2 } => r_type(0b0111011, 0b100, 0b0000100, rd, rs1, rs2),
+        Xperm4 { rd, rs1, rs2 } => r_type(0b0110011, 0b010, 0b0010100, rd, rs1, rs2),
+        Xperm8 { rd, rs1, rs2 } => r_type(0b0110011, 0b100, 0b0010100, rd, rs1, rs2),
+        Brev8 { rd, rs1 } => r_type(0b0010011, 0b101, 0b0110100, rd, rs1, 0b00111),
+        Zip { rd, rs1 } => r_type(0b0010011, 0b001, 0b0000100, rd, rs1, 0b01111),
+        Unzip { rd, rs1 } => r_type(0b0010011, 0b101, 0b0000100, rd, rs1, 0b01111),
+
+        // Zknd/Zkne/Zknh/Zksed/Zksh
+        Aes32esi { rd, rs1, rs2, bs } => bs_type(0b10001, rd, rs1, rs2, bs),
+        Aes32esmi { rd, rs1, rs2, bs } => bs_type(0b10011, rd, rs1, rs2, bs),
+        Aes32dsi { rd, rs1, rs2, bs } => bs_type(0b10101, rd, rs1, rs2, bs),
+        Aes32dsmi { rd, rs1, rs2, bs } => bs_type(0b10111, rd, rs1, rs2, bs),
+        Aes64es { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0011001, rd, rs1, rs2),
+        Aes64esm { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0011011, rd, rs1, rs2),
+        Aes64ds { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0011101, rd, rs1, rs2),
+        Aes64dsm { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0011111, rd, rs1, rs2),
+        Aes64ks1i { rd, rs1, rnum } => {
+            // Round numbers above 0xA are reserved
+            if rnum > 0xa {
+                return Err(EncodeError::ImmediateOutOfRange { imm: rnum as i64, min: 0, max: 0xa });
+            }
+            r_type(0b0010011, 0b001, 0b0011000, rd, rs1, 0b10000 | rnum)
+        }
+        Aes64ks2 { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0111111, rd, rs1, rs2),
+        Aes64im { rd, rs1 } => r_type(0b0010011, 0b001, 0b0011000, rd, rs1, 0b00000),
+        Sha256sum0 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b00000),
+        Sha256sum1 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b00001),
+        Sha256sig0 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b00010),
+        Sha256sig1 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b00011),
+        Sha512sum0 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b00100),
+        Sha512sum1 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b00101),
+        Sha512sig0 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b00110),
+        Sha512sig1 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b00111),
+        Sm3p0 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b01000),
+        Sm3p1 { rd, rs1 } => r_type(0b0010011, 0b001, 0b0001000, rd, rs1, 0b01001),
+        Sha512sum0r { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0101000, rd, rs1, rs2),
+        Sha512sum1r { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0101001, rd, rs1, rs2),
+        Sha512sig0l { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0101010, rd, rs1, rs2),
+        Sha512sig1l { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0101011, rd, rs1, rs2),
+        Sha512sig0h { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0101110, rd, rs1, rs2),
+        Sha512sig1h { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0101111, rd, rs1, rs2),
+        Sm4ed { rd, rs1, rs2, bs } => bs_type(0b11000, rd, rs1, rs2, bs),
+        Sm4ks { rd, rs1, rs2, bs } => bs_type(0b11010, rd, rs1, rs2, bs),
+
+        // RV64I word operations
+        Addiw { rd, rs1, imm } => i_type(0b0011011, 0b000, rd, rs1, imm),
+        Slliw { rd, rs1, shamt } => shift_type(0b0011011, 0b001, 0b0000000, rd, rs1, shamt, 5),
+        Srliw { rd, rs1, shamt } => shift_type(0b0011011, 0b101, 0b0000000, rd, rs1, shamt, 5),
+        Sraiw { rd, rs1, shamt } => shift_type(0b0011011, 0b101, 0b0100000, rd, rs1, shamt, 5),
+        Addw { rd, rs1, rs2 } => r_type(0b0111011, 0b000, 0b0000000, rd, rs1, rs2),
+        Subw { rd, rs1, rs2 } => r_type(0b0111011, 0b000, 0b0100000, rd, rs1, rs2),
+        Sllw { rd, rs1, rs2 } => r_type(0b0111011, 0b001, 0b0000000, rd, rs1, rs2),
+        Srlw { rd, rs1, rs2 } => r_type(0b0111011, 0b101, 0b0000000, rd, rs1, rs2),
+        Sraw { rd, rs1, rs2 } => r_type(0b0111011, 0b101, 0b0100000, rd, rs1, rs2),
+
+        // RV64M
+        Mulw { rd, rs1, rs2 } => r_type(0b0111011, 0b000, 0b0000001, rd, rs1, rs2),
+        Divw { rd, rs1, rs2 } => r_type(0b0111011, 0b100, 0b0000001, rd, rs1, rs2),
+        Divuw { rd, rs1, rs2 } => r_type(0b0111011, 0b101, 0b0000001, rd, rs1, rs2),
+        Remw { rd, rs1, rs2 } => r_type(0b0111011, 0b110, 0b0000001, rd, rs1, rs2),
+        Remuw { rd, rs1, rs2 } => r_type(0b0111011, 0b111, 0b0000001, rd, rs1, rs2),
+
+        // RV32M
+        Mul { rd, rs1, rs2 } => r_type(0b0110011, 0b000, 0b0000001, rd, rs1, rs2),
+        Mulh { rd, rs1, rs2 } => r_type(0b0110011, 0b001, 0b0000001, rd, rs1, rs2),
+        Mulhsu { rd, rs1, rs2 } => r_type(0b0110011, 0b010, 0b0000001, rd, rs1, rs2),
+        Mulhu { rd, rs1, rs2 } => r_type(0b0110011, 0b011, 0b0000001, rd, rs1, rs2),
+        Div { rd, rs1, rs2 } => r_type(0b0110011, 0b100, 0b0000001, rd, rs1, rs2),
+        Divu { rd, rs1, rs2 } => r_type(0b0110011, 0b101, 0b0000001, rd, rs1, rs2),
+        Rem { rd, rs1, rs2 } => r_type(0b0110011, 0b110, 0b0000001, rd, rs1, rs2),
+        Remu { rd, rs1, rs2 } => r_type(0b0110011, 0b111, 0b0000001, rd, rs1, rs2),
+
+        // RV32A
+        LrW { rd, rs1, aq, rl } => amo_type(0b00010, 0b010, rd, rs1, 0, aq, rl),
+        ScW { rd, rs1, rs2, aq, rl } => amo_type(0b00011, 0b010, rd, rs1, rs2, aq, rl),
+        AmoswapW { rd, rs1, rs2, aq, rl } => amo_type(0b00001, 0b010, rd, rs1, rs2, aq, rl),
+        AmoaddW { rd, rs1, rs2, aq, rl } => amo_type(0b00000, 0b010, rd, rs1, rs2, aq, rl),
+        AmoxorW { rd, rs1, rs2, aq, rl } => amo_type(0b00100, 0b010, rd, rs1, rs2, aq, rl),
+        AmoandW { rd, rs1, rs2, aq, rl } => amo_type(0b01100, 0b010, rd, rs1, rs2, aq, rl),
+        AmoorW { rd, rs1, rs2, aq, rl } => amo_type(0b01000, 0b010, rd, rs1, rs2, aq, rl),
+        AmominW { rd, rs1, rs2, aq, rl } => amo_type(0b10000, 0b010, rd, rs1, rs2, aq, rl),
+        AmomaxW { rd, rs1, rs2, aq, rl } => amo_type(0b10100, 0b010, rd, rs1, rs2, aq, rl),
+        AmominuW { rd, rs1, rs2, aq, rl } => amo_type(0b11000, 0b010, rd, rs1, rs2, aq, rl),
+        AmomaxuW { rd, rs1, rs2, aq, rl } => amo_type(0b11100, 0b010, rd, rs1, rs2, aq, rl),
+
+        // RV64A
+        LrD { rd, rs1, aq, rl } => amo_type(0b00010, 0b011, rd, rs1, 0, aq, rl),
+        ScD { rd, rs1, rs2, aq, rl } => amo_type(0b00011, 0b011, rd, rs1, rs2, aq, rl),
+        AmoswapD { rd, rs1, rs2, aq, rl } => amo_type(0b00001, 0b011, rd, rs1, rs2, aq, rl),
+        AmoaddD { rd, rs1, rs2, aq, rl } => amo_type(0b00000, 0b011, rd, rs1, rs2, aq, rl),
+        AmoxorD { rd, rs1, rs2, aq, rl } => amo_type(0b00100, 0b011, rd, rs1, rs2, aq, rl),
+        AmoandD { rd, rs1, rs2, aq, rl } => amo_type(0b01100, 0b011, rd, rs1, rs2, aq, rl),
+        AmoorD { rd, rs1, rs2, aq, rl } => amo_type(0b01000, 0b011, rd, rs1, rs2, aq, rl),
+        AmominD { rd, rs1, rs2, aq, rl } => amo_type(0b10000, 0b011, rd, rs1, rs2, aq, rl),
+        AmomaxD { rd, rs1, rs2, aq, rl } => amo_type(0b10100, 0b011, rd, rs1, rs2, aq, rl),
+        AmominuD { rd, rs1, rs2, aq, rl } => amo_type(0b11000, 0b011, rd, rs1, rs2, aq, rl),
+        AmomaxuD { rd, rs1, rs2, aq, rl } => amo_type(0b11100, 0b011, rd, rs1, rs2, aq, rl),
+
+        // Fused multiply-add
+        FmaddS { rd, rs1, rs2, rs3, rm } => r4_type(0b1000011, 0b00, rd, rs1, rs2, rs3, rm),
+        FmaddD { rd, rs1, rs2, rs3, rm } => r4_type(0b1000011, 0b01, rd, rs1, rs2, rs3, rm),
+        FmsubS { rd, rs1, rs2, rs3, rm } => r4_type(0b1000111, 0b00, rd, rs1, rs2, rs3, rm),
+        FmsubD { rd, rs1, rs2, rs3, rm } => r4_type(0b1000111, 0b01, rd, rs1, rs2, rs3, rm),
+        FnmsubS { rd, rs1, rs2, rs3, rm } => r4_type(0b1001011, 0b00, rd, rs1, rs2, rs3, rm),
+        FnmsubD { rd, rs1, rs2, rs3, rm } => r4_type(0b1001011, 0b01, rd, rs1, rs2, rs3, rm),
+        FnmaddS { rd, rs1, rs2, rs3, rm } => r4_type(0b1001111, 0b00, rd, rs1, rs2, rs3, rm),
+        FnmaddD { rd, rs1, rs2, rs3, rm } => r4_type(0b1001111, 0b01, rd, rs1, rs2, rs3, rm),
+
+        // Floating point arithmetic
+        FaddS { rd, rs1, rs2, rm } => fp_type(0b00000, 0b00, rm_encoder(rm), rd, rs1, rs2),
+        FaddD { rd, rs1, rs2, rm } => fp_type(0b00000, 0b01, rm_encoder(rm), rd, rs1, rs2),
+        FsubS { rd, rs1, rs2, rm } => fp_type(0b00001, 0b00, rm_encoder(rm), rd, rs1, rs2),
+        FsubD { rd, rs1, rs2, rm } => fp_type(0b00001, 0b01, rm_encoder(rm), rd, rs1, rs2),
+        FmulS { rd, rs1, rs2, rm } => fp_type(0b00010, 0b00, rm_encoder(rm), rd, rs1, rs2),
+        FmulD { rd, rs1, rs2, rm } => fp_type(0b00010, 0b01, rm_encoder(rm), rd, rs1, rs2),
+        FdivS { rd, rs1, rs2, rm } => fp_type(0b00011, 0b00, rm_encoder(rm), rd, rs1, rs2),
+        FdivD { rd, rs1, rs2, rm } => fp_type(0b00011, 0b01, rm_encoder(rm), rd, rs1, rs2),
+        FsqrtS { rd, rs1, rm } => fp_type(0b01011, 0b00, rm_encoder(rm), rd, rs1, 0),
+        FsqrtD { rd, rs1, rm } => fp_type(0b01011, 0b01, rm_encoder(rm), rd, rs1, 0),
+
+        // Sign injection, min/max
+        FsgnjS { rd, rs1, rs2 } => fp_type(0b00100, 0b00, 0b000, rd, rs1, rs2),
+        FsgnjD { rd, rs1, rs2 } => fp_type(0b00100, 0b01, 0b000, rd, rs1, rs2),
+        FsgnjnS { rd, rs1, rs2 } => fp_type(0b00100, 0b00, 0b001, rd, rs1, rs2),
+        FsgnjnD { rd, rs1, rs2 } => fp_type(0b00100, 0b01, 0b001, rd, rs1, rs2),
+        FsgnjxS { rd, rs1, rs2 } => fp_type(0b00100, 0b00, 0b010, rd, rs1, rs2),
+        FsgnjxD { rd, rs1, rs2 } => fp_type(0b00100, 0b01, 0b010, rd, rs1, rs2),
+        FminS { rd, rs1, rs2 } => fp_type(0b00101, 0b00, 0b000, rd, rs1, rs2),
+        FminD { rd, rs1, rs2 } => fp_type(0b00101, 0b01, 0b000, rd, rs1, rs2),
+        FmaxS { rd, rs1, rs2 } => fp_type(0b00101, 0b00, 0b001, rd, rs1, rs2),
+        FmaxD { rd, rs1, rs2 } => fp_type(0b00101, 0b01, 0b001, rd, rs1, rs2),
+
+        // Conversions and moves
+        FcvtSD { rd, rs1, rm } => fp_type(0b01000, 0b00, rm_encoder(rm), rd, rs1, 1),
+        FcvtDS { rd, rs1, rm } => fp_type(0b01000, 0b01, rm_encoder(rm), rd, rs1, 0),
+        FcvtWS { rd, rs1, rm } => fp_type(0b11000, 0b00, rm_encoder(rm), rd, rs1, 0),
+        FcvtWD { rd, rs1, rm } => fp_type(0b11000, 0b01, rm_encoder(rm), rd, rs1, 0),
+        FcvtWuS { rd, rs1, rm } => fp_type(0b11000, 0b00, rm_encoder(rm), rd, rs1, 1),
+        FcvtWuD { rd, rs1, rm } => fp_type(0b11000, 0b01, rm_encoder(rm), rd, rs1, 1),
+        FcvtSW { rd, rs1, rm } => fp_type(0b11010, 0b00, rm_encoder(rm), rd, rs1, 0),
+        FcvtDW { rd, rs1, rm } => fp_type(0b11010, 0b01, rm_encoder(rm), rd, rs1, 0),
+        FcvtSWu { rd, rs1, rm } => fp_type(0b11010, 0b00, rm_encoder(rm), rd, rs1, 1),
+        FcvtDWu { rd, rs1, rm } => fp_type(0b11010, 0b01, rm_encoder(rm), rd, rs1, 1),
+        FmvXW { rd, rs1 } => fp_type(0b11100, 0b00, 0b000, rd, rs1, 0),
+        FmvWX { rd, rs1 } => fp_type(0b11110, 0b00, 0b000, rd, rs1, 0),
+
+        // RV64F/RV64D conversions and moves
+        FcvtLS { rd, rs1, rm } => fp_type(0b11000, 0b00, rm_encoder(rm), rd, rs1, 2),
+        FcvtLuS { rd, rs1, rm } => fp_type(0b11000, 0b00, rm_encoder(rm), rd, rs1, 3),
+        FcvtSL { rd, rs1, rm } => fp_type(0b11010, 0b00, rm_encoder(rm), rd, rs1, 2),
+        FcvtSLu { rd, rs1, rm } => fp_type(0b11010, 0b00, rm_encoder(rm), rd, rs1, 3),
+        FcvtLD { rd, rs1, rm } => fp_type(0b11000, 0b01, rm_encoder(rm), rd, rs1, 2),
+        FcvtLuD { rd, rs1, rm } => fp_type(0b11000, 0b01, rm_encoder(rm), rd, rs1, 3),
+        FcvtDL { rd, rs1, rm } => fp_type(0b11010, 0b01, rm_encoder(rm), rd, rs1, 2),
+        FcvtDLu { rd, rs1, rm } => fp_type(0b11010, 0b01, rm_encoder(rm), rd, rs1, 3),
+        FmvXD { rd, rs1 } => fp_type(0b11100, 0b01, 0b000, rd, rs1, 0),
+        FmvDX { rd, rs1 } => fp_type(0b11110, 0b01, 0b000, rd, rs1, 0),
+
+        // Compare and classify
+        FeqS { rd, rs1, rs2 } => fp_type(0b10100, 0b00, 0b010, rd, rs1, rs2),
+        FeqD { rd, rs1, rs2 } => fp_type(0b10100, 0b01, 0b010, rd, rs1, rs2),
+        FltS { rd, rs1, rs2 } => fp_type(0b10100, 0b00, 0b001, rd, rs1, rs2),
+        FltD { rd, rs1, rs2 } => fp_type(0b10100, 0b01, 0b001, rd, rs1, rs2),
+        FleS { rd, rs1, rs2 } => fp_type(0b10100, 0b00, 0b000, rd, rs1, rs2),
+        FleD { rd, rs1, rs2 } => fp_type(0b10100, 0b01, 0b000, rd, rs1, rs2),
+        FclassS { rd, rs1 } => fp_type(0b11100, 0b00, 0b001, rd, rs1, 0),
+        FclassD { rd, rs1 } => fp_type(0b11100, 0b01, 0b001, rd, rs1, 0),
+
+        // Floating point loads and stores
+        Flw { rd, rs1, imm } => i_type(0b0000111, 0b010, rd, rs1, imm),
+        Fld { rd, rs1, imm } => i_type(0b0000111, 0b011, rd, rs1, imm),
+        Fsw { rs1, rs2, imm } => s_type(0b0100111, 0b010, rs1, rs2, imm),
+        Fsd { rs1, rs2, imm } => s_type(0b0100111, 0b011, rs1, rs2, imm),
+
+        // Half precision
+        FmaddH { rd, rs1, rs2, rs3, rm } => r4_type(0b1000011, 0b10, rd, rs1, rs2, rs3, rm),
+        FmsubH { rd, rs1, rs2, rs3, rm } => r4_type(0b1000111, 0b10, rd, rs1, rs2, rs3, rm),
+        FnmsubH { rd, rs1, rs2, rs3, rm } => r4_type(0b1001011, 0b10, rd, rs1, rs2, rs3, rm),
+        FnmaddH { rd, rs1, rs2, rs3, rm } => r4_type(0b1001111, 0b10, rd, rs1, rs2, rs3, rm),
+        FaddH { rd, rs1, rs2, rm } => fp_type(0b00000, 0b10, rm_encoder(rm), rd, rs1, rs2),
+        FsubH { rd, rs1, rs2, rm } => fp_type(0b00001, 0b10, rm_encoder(rm), rd, rs1, rs2),
+        FmulH { rd, rs1, rs2, rm } => fp_type(0b00010, 0b10, rm_encoder(rm), rd, rs1, rs2),
+        FdivH { rd, rs1, rs2, rm } => fp_type(0b00011, 0b10, rm_encoder(rm), rd, rs1, rs2),
+        FsqrtH { rd, rs1, rm } => fp_type(0b01011, 0b10, rm_encoder(rm), rd, rs1, 0),
+        FsgnjH { rd, rs1, rs2 } => fp_type(0b00100, 0b10, 0b000, rd, rs1, rs2),
+        FsgnjnH { rd, rs1, rs2 } => fp_type(0b00100, 0b10, 0b001, rd, rs1, rs2),
+        FsgnjxH { rd, rs1, rs2 } => fp_type(0b00100, 0b10, 0b010, rd, rs1, rs2),
+        FminH { rd, rs1, rs2 } => fp_type(0b00101, 0b10, 0b000, rd, rs1, rs2),
+        FmaxH { rd, rs1, rs2 } => fp_type(0b00101, 0b10, 0b001, rd, rs1, rs2),
+        FcvtWH { rd, rs1, rm } => fp_type(0b11000, 0b10, rm_encoder(rm), rd, rs1, 0),
+        FcvtWuH { rd, rs1, rm } => fp_type(0b11000, 0b10, rm_encoder(rm), rd, rs1, 1),
+        FcvtHW { rd, rs1, rm } => fp_type(0b11010, 0b10, rm_encoder(rm), rd, rs1, 0),
+        FcvtHWu { rd, rs1, rm } => fp_type(0b11010, 0b10, rm_encoder(rm), rd, rs1, 1),
+        FcvtLH { rd, rs1, rm } => fp_type(0b11000, 0b10, rm_encoder(rm), rd, rs1, 2),
+        FcvtLuH { rd, rs1, rm } => fp_type(0b11000, 0b10, rm_encoder(rm), rd, rs1, 3),
+        FcvtHL { rd, rs1, rm } => fp_type(0b11010, 0b10, rm_encoder(rm), rd, rs1, 2),
+        FcvtHLu { rd, rs1, rm } => fp_type(0b11010, 0b10, rm_encoder(rm), rd, rs1, 3),
+        FmvXH { rd, rs1 } => fp_type(0b11100, 0b10, 0b000, rd, rs1, 0),
+        FmvHX { rd, rs1 } => fp_type(0b11110, 0b10, 0b000, rd, rs1, 0),
+        FeqH { rd, rs1, rs2 } => fp_type(0b10100, 0b10, 0b010, rd, rs1, rs2),
+        FltH { rd, rs1, rs2 } => fp_type(0b10100, 0b10, 0b001, rd, rs1, rs2),
+        FleH { rd, rs1, rs2 } => fp_type(0b10100, 0b10, 0b000, rd, rs1, rs2),
+        FclassH { rd, rs1 } => fp_type(0b11100, 0b10, 0b001, rd, rs1, 0),
+        FcvtSH { rd, rs1, rm } => fp_type(0b01000, 0b00, rm_encoder(rm), rd, rs1, 2),
+        FcvtHS { rd, rs1, rm } => fp_type(0b01000, 0b10, rm_encoder(rm), rd, rs1, 0),
+        FcvtDH { rd, rs1, rm } => fp_type(0b01000, 0b01, rm_encoder(rm), rd, rs1, 2),
+        FcvtHD { rd, rs1, rm } => fp_type(0b01000, 0b10, rm_encoder(rm), rd, rs1, 1),
+        Flh { rd, rs1, imm } => i_type(0b0000111, 0b001, rd, rs1, imm),
+        Fsh { rs1, rs2, imm } => s_type(0b0100111, 0b001, rs1, rs2, imm),
+
+        // Quad precision
+        FmaddQ { rd, rs1, rs2, rs3, rm } => r4_type(0b1000011, 0b11, rd, rs1, rs2, rs3, rm),
+        FmsubQ { rd, rs1, rs2, rs3, rm } => r4_type(0b1000111, 0b11, rd, rs1, rs2, rs3, rm),
+        FnmsubQ { rd, rs1, rs2, rs3, rm } => r4_type(0b1001011, 0b11, rd, rs1, rs2, rs3, rm),
+        FnmaddQ { rd, rs1, rs2, rs3, rm } => r4_type(0b1001111, 0b11, rd, rs1, rs2, rs3, rm),
+        FaddQ { rd, rs1, rs2, rm } => fp_type(0b00000, 0b11, rm_encoder(rm), rd, rs1, rs2),
+        FsubQ { rd, rs1, rs2, rm } => fp_type(0b00001, 0b11, rm_encoder(rm), rd, rs1, rs2),
+        FmulQ { rd, rs1, rs2, rm } => fp_type(0b00010, 0b11, rm_encoder(rm), rd, rs1, rs2),
+        FdivQ { rd, rs1, rs2, rm } => fp_type(0b00011, 0b11, rm_encoder(rm), rd, rs1, rs2),
+        FsqrtQ { rd, rs1, rm } => fp_type(0b01011, 0b11, rm_encoder(rm), rd, rs1, 0),
+        FsgnjQ { rd, rs1, rs2 } => fp_type(0b00100, 0b11, 0b000, rd, rs1, rs2),
+        FsgnjnQ { rd, rs1, rs2 } => fp_type(0b00100, 0b11, 0b001, rd, rs1, rs2),
+        FsgnjxQ { rd, rs1, rs2 } => fp_type(0b00100, 0b11, 0b010, rd, rs1, rs2),
+        FminQ { rd, rs1, rs2 } => fp_type(0b00101, 0b11, 0b000, rd, rs1, rs2),
+        FmaxQ { rd, rs1, rs2 } => fp_type(0b00101, 0b11, 0b001, rd, rs1, rs2),
+        FcvtWQ { rd, rs1, rm } => fp_type(0b11000, 0b11, rm_encoder(rm), rd, rs1, 0),
+        FcvtWuQ { rd, rs1, rm } => fp_type(0b11000, 0b11, rm_encoder(rm), rd, rs1, 1),
+        FcvtQW { rd, rs1, rm } => fp_type(0b11010, 0b11, rm_encoder(rm), rd, rs1, 0),
+        FcvtQWu { rd, rs1, rm } => fp_type(0b11010, 0b11, rm_encoder(rm), rd, rs1, 1),
+        FcvtLQ { rd, rs1, rm } => fp_type(0b11000, 0b11, rm_encoder(rm), rd, rs1, 2),
+        FcvtLuQ { rd, rs1, rm } => fp_type(0b11000, 0b11, rm_encoder(rm), rd, rs1, 3),
+        FcvtQL { rd, rs1, rm } => fp_type(0b11010, 0b11, rm_encoder(rm), rd, rs1, 2),
+        FcvtQLu { rd, rs1, rm } => fp_type(0b11010, 0b11, rm_encoder(rm), rd, rs1, 3),
+        FeqQ { rd, rs1, rs2 } => fp_type(0b10100, 0b11, 0b010, rd, rs1, rs2),
+        FltQ { rd, rs1, rs2 } => fp_type(0b10100, 0b11, 0b001, rd, rs1, rs2),
+        FleQ { rd, rs1, rs2 } => fp_type(0b10100, 0b11, 0b000, rd, rs1, rs2),
+        FclassQ { rd, rs1 } => fp_type(0b11100, 0b11, 0b001, rd, rs1, 0),
+        FcvtSQ { rd, rs1, rm } => fp_type(0b01000, 0b00, rm_encoder(rm), rd, rs1, 3),
+        FcvtQS { rd, rs1, rm } => fp_type(0b01000, 0b11, rm_encoder(rm), rd, rs1, 0),
+        FcvtDQ { rd, rs1, rm } => fp_type(0b01000, 0b01, rm_encoder(rm), rd, rs1, 3),
+        FcvtQD { rd, rs1, rm } => fp_type(0b01000, 0b11, rm_encoder(rm), rd, rs1, 1),
+        FcvtHQ { rd, rs1, rm } => fp_type(0b01000, 0b10, rm_encoder(rm), rd, rs1, 3),
+        FcvtQH { rd, rs1, rm } => fp_type(0b01000, 0b11, rm_encoder(rm), rd, rs1, 2),
+        Flq { rd, rs1, imm } => i_type(0b0000111, 0b100, rd, rs1, imm),
+        Fsq { rs1, rs2, imm } => s_type(0b0100111, 0b100, rs1, rs2, imm),
+
+        // Zfa
+        FliS { rd, constant } => fp_type(0b11110, 0b00, 0b000, rd, constant.index(), 1),
+        FliD { rd, constant } => fp_type(0b11110, 0b01, 0b000, rd, constant.index(), 1),
+        FliH { rd, constant } => fp_type(0b11110, 0b10, 0b000, rd, constant.index(), 1),
+        FliQ { rd, constant } => fp_type(0b11110, 0b11, 0b000, rd, constant.index(), 1),
+        FminmS { rd, rs1, rs2 } => fp_type(0b00101, 0b00, 0b010, rd, rs1, rs2),
+        FmaxmS { rd, rs1, rs2 } => fp_type(0b00101, 0b00, 0b011, rd, rs1, rs2),
+        FminmD { rd, rs1, rs2 } => fp_type(0b00101, 0b01, 0b010, rd, rs1, rs2),
+        FmaxmD { rd, rs1, rs2 } => fp_type(0b00101, 0b01, 0b011, rd, rs1, rs2),
+        FminmH { rd, rs1, rs2 } => fp_type(0b00101, 0b10, 0b010, rd, rs1, rs2),
+        FmaxmH { rd, rs1, rs2 } => fp_type(0b00101, 0b10, 0b011, rd, rs1, rs2),
+        FminmQ { rd, rs1, rs2 } => fp_type(0b00101, 0b11, 0b010, rd, rs1, rs2),
+        FmaxmQ { rd, rs1, rs2 } => fp_type(0b00101, 0b11, 0b011, rd, rs1, rs2),
+        FroundS { rd, rs1, rm } => fp_type(0b01000, 0b00, rm_encoder(rm), rd, rs1, 4),
+        FroundnxS { rd, rs1, rm } => fp_type(0b01000, 0b00, rm_encoder(rm), rd, rs1, 5),
+        FroundD { rd, rs1, rm } => fp_type(0b01000, 0b01, rm_encoder(rm), rd, rs1, 4),
+        FroundnxD { rd, rs1, rm } => fp_type(0b01000, 0b01, rm_encoder(rm), rd, rs1, 5),
+        FroundH { rd, rs1, rm } => fp_type(0b01000, 0b10, rm_encoder(rm), rd, rs1, 4),
+        FroundnxH { rd, rs1, rm } => fp_type(0b01000, 0b10, rm_encoder(rm), rd, rs1, 5),
+        FroundQ { rd, rs1, rm } => fp_type(0b01000, 0b11, rm_encoder(rm), rd, rs1, 4),
+        FroundnxQ { rd, rs1, rm } => fp_type(0b01000, 0b11, rm_encoder(rm), rd, rs1, 5),
+        FleqS { rd, rs1, rs2 } => fp_type(0b10100, 0b00, 0b100, rd, rs1, rs2),
+        FltqS { rd, rs1, rs2 } => fp_type(0b10100, 0b00, 0b101, rd, rs1, rs2),
+        FleqD { rd, rs1, rs2 } => fp_type(0b10100, 0b01, 0b100, rd, rs1, rs2),
+        FltqD { rd, rs1, rs2 } => fp_type(0b10100, 0b01, 0b101, rd, rs1, rs2),
+        FleqH { rd, rs1, rs2 } => fp_type(0b10100, 0b10, 0b100, rd, rs1, rs2),
+        FltqH { rd, rs1, rs2 } => fp_type(0b10100, 0b10, 0b101, rd, rs1, rs2),
+        FleqQ { rd, rs1, rs2 } => fp_type(0b10100, 0b11, 0b100, rd, rs1, rs2),
+        FltqQ { rd, rs1, rs2 } => fp_type(0b10100, 0b11, 0b101, rd, rs1, rs2),
+        FcvtmodWD { rd, rs1 } => fp_type(0b11000, 0b01, 0b001, rd, rs1, 8),
+        FmvhXD { rd, rs1 } => fp_type(0b11100, 0b01, 0b000, rd, rs1, 1),
+        FmvhXQ { rd, rs1 } => fp_type(0b11100, 0b11, 0b000, rd, rs1, 1),
+        FmvpDX { rd, rs1, rs2 } => fp_type(0b10110, 0b01, 0b000, rd, rs1, rs2),
+        FmvpQX { rd, rs1, rs2 } => fp_type(0b10110, 0b11, 0b000, rd, rs1, rs2),
+
+        // RVV 1.0
+        Vsetvli { rd, rs1, vtype } => i_type(0b1010111, 0b111, rd, rs1, vtype.bits() as i32),
+        Vsetivli { rd, uimm, vtype } => {
+            Ok(0b11 << 30 | vtype.bits() << 20 | unsigned_imm(uimm, 5)? << 15 | 0b111 << 12 | reg(rd)? << 7 | 0b1010111)
+        }
+        Vsetvl { rd, rs1, rs2 } => r_type(0b1010111, 0b111, 0b1000000, rd, rs1, rs2),
+        VectorLoad { vd, rs1, addressing, eew, nf, vm } => vector_memory_type(0b0000111, vd, rs1, addressing, eew, nf, vm),
+        VectorStore { vs3, rs1, addressing, eew, nf, vm } => vector_memory_type(0b0100111, vs3, rs1, addressing, eew, nf, vm),
+        VectorArith { op, vd, vs2, src, vm } => {
+            let (opcode, funct3, funct6) = vector::arith_encoding(op, vs2, src, vm).ok_or(EncodeError::ReservedEncoding)?;
+            let source = vector_source(src, op.imm_range())?;
+            op_v_type(opcode, funct6 | source >> 5, funct3, vd, source & 0x1f, vs2, vm)
+        }
+        VectorUnary { op, vd, vs2, vm } => {
+            let (opcode, funct3, funct6, code) = vector::unary_encoding(op, vd, vs2, vm).ok_or(EncodeError::ReservedEncoding)?;
+            op_v_type(opcode, funct6, funct3, vd, code, vs2, vm)
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::Xlen;
+
+/// Reasons an instruction word could not be decoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The major opcode (bits 6:0) is not handled by the decoder
+    UnknownOpcode { word: u32, opcode: u32 },
+    /// A function field holds a value that is reserved or not handled
+    ReservedFunct { word: u32, field: &'static str, value: u32 },
+    /// The `rm` field holds one of the reserved rounding modes 101 or 110
+    ReservedRoundingMode { word: u32, rm: u32 },
+    /// The input holds fewer bits or bytes than an instruction needs
+    TooShort { len: usize, needed: usize },
+    /// The instruction does not exist for the configured base ISA
+    UnsupportedXlen { word: u32, xlen: Xlen },
+    /// An entry of a bit string vector is not "0" or "1"
+    InvalidBit { index: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DecodeError::UnknownOpcode { word, opcode } => {
+                write!(f, "unknown opcode {:#09b} in instruction {:#010x}", opcode, word)
+            }
+            DecodeError::ReservedFunct { word, field, value } => {
+                write!(f, "reserved {} value {:#b} in instruction {:#010x}", field, value, word)
+            }
+            DecodeError::ReservedRoundingMode { word, rm } => {
+                write!(f, "reserved rounding mode {:#05b} in instruction {:#010x}", rm, word)
+            }
+            DecodeError::TooShort { len, needed } => {
+                write!(f, "input too short: got {}, need {}", len, needed)
+            }
+            DecodeError::UnsupportedXlen { word, xlen } => {
+                write!(f, "instruction {:#010x} is not valid for {}", word, xlen)
+            }
+            DecodeError::InvalidBit { index } => {
+                write!(f, "bit string entry {} is not \"0\" or \"1\"", index)
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Reasons an instruction could not be encoded into a 32-bit word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// A register number does not fit the 5-bit register field
+    InvalidRegister { register: u8 },
+    /// An immediate does not fit the instruction's immediate field
+    ImmediateOutOfRange { imm: i64, min: i64, max: i64 },
+    /// An immediate is not a multiple of the instruction's required alignment
+    MisalignedImmediate { imm: i64, alignment: i64 },
+    /// The operands select an encoding the instruction does not define, e.g. a
+    /// vector operation in an operand form or masking it does not have
+    ReservedEncoding,
+    /// The instruction does not exist for the configured base ISA
+    UnsupportedXlen { xlen: Xlen },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            EncodeError::InvalidRegister { register } => {
+                write!(f, "register number {} is out of range 0..=31", register)
+            }
+            EncodeError::ImmediateOutOfRange { imm, min, max } => {
+                write!(f, "immediate {} is out of range {}..={}", imm, min, max)
+            }
+            EncodeError::MisalignedImmediate { imm, alignment } => {
+                write!(f, "immediate {} is not a multiple of {}", imm, alignment)
+            }
+            EncodeError::ReservedEncoding => f.write_str("the operands select a reserved encoding"),
+            EncodeError::UnsupportedXlen { xlen } => write!(f, "the instruction is not valid for {}", xlen),
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
+/// What went wrong while parsing a line of assembly text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The line holds no mnemonic
+    Empty,
+    /// The mnemonic is not a known instruction
+    UnknownMnemonic(String),
+    /// An operand is not a register, immediate, memory operand, rounding mode, CSR name, vtype setting or FLI constant
+    InvalidOperand(String),
+    /// An integer (`x`) register was expected
+    ExpectedIntRegister,
+    /// A floating point (`f`) register was expected
+    ExpectedFloatRegister,
+    /// A vector (`v`) register was expected
+    ExpectedVectorRegister,
+    /// The `v0` mask operand of a carry or merge instruction was expected
+    ExpectedMaskRegister,
+    /// A vtype setting such as `e32, m2, ta, ma` was expected
+    ExpectedVtype,
+    /// An immediate value was expected
+    ExpectedImmediate,
+    /// A memory operand of the form `imm(reg)` was expected
+    ExpectedMemory,
+    /// A rounding mode was expected
+    ExpectedRoundingMode,
+    /// One of the FLI constants such as `0.5`, `min` or `inf` was expected
+    ExpectedFliConstant,
+    /// A CSR name or 12-bit CSR number was expected
+    ExpectedCsr,
+    /// The instruction needs more operands
+    MissingOperand,
+    /// The instruction takes fewer operands
+    UnexpectedOperand,
+    /// The operands are well formed but cannot be encoded
+    Encode(EncodeError),
+}
+
+/// Error returned when parsing a line of assembly text fails
+///
+/// `column` is the 1-based character column where the problem starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "column {}: ", self.column)?;
+        match self.kind {
+            ParseErrorKind::Empty => write!(f, "expected an instruction"),
+            ParseErrorKind::UnknownMnemonic(ref mnemonic) => write!(f, "unknown mnemonic `{}`", mnemonic),
+            ParseErrorKind::InvalidOperand(ref operand) => write!(f, "invalid operand `{}`", operand),
+            ParseErrorKind::ExpectedIntRegister => write!(f, "expected an integer register"),
+            ParseErrorKind::ExpectedFloatRegister => write!(f, "expected a floating point register"),
+            ParseErrorKind::ExpectedVectorRegister => write!(f, "expected a vector register"),
+            ParseErrorKind::ExpectedMaskRegister => write!(f, "expected the mask register `v0`"),
+            ParseErrorKind::ExpectedVtype => write!(f, "expected a vtype setting such as `e32, m1, ta, ma`"),
+            ParseErrorKind::ExpectedImmediate => write!(f, "expected an immediate"),
+            ParseErrorKind::ExpectedMemory => write!(f, "expected a memory operand `imm(reg)`"),
+            ParseErrorKind::ExpectedRoundingMode => write!(f, "expected a rounding mode"),
+            ParseErrorKind::ExpectedFliConstant => write!(f, "expected an FLI constant"),
+            ParseErrorKind::ExpectedCsr => write!(f, "expected a CSR name or number"),
+            ParseErrorKind::MissingOperand => write!(f, "missing operand"),
+            ParseErrorKind::UnexpectedOperand => write!(f, "unexpected operand"),
+            ParseErrorKind::Encode(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for ParseError {}
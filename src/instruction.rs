@@ -0,0 +1,1735 @@
+use std::fmt;
+
+use crate::csr::csr_text;
+use crate::vector::{self, Sew, VectorAddressing, VectorOp, VectorOperand, VectorUnaryOp, Vtype};
+
+/// Floating point rounding mode held in the `rm` field of F/D instructions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    Rne,        // Round to nearest, ties to even
+    Rtz,        // Round towards zero
+    Rdn,        // Round down (towards negative infinity)
+    Rup,        // Round up (towards positive infinity)
+    Rmm,        // Round to nearest, ties to max magnitude
+    Dyn,        // Dynamic rounding mode
+}
+
+impl fmt::Display for RoundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            RoundingMode::Rne => "RNE",
+            RoundingMode::Rtz => "RTZ",
+            RoundingMode::Rdn => "RDN",
+            RoundingMode::Rup => "RUP",
+            RoundingMode::Rmm => "RMM",
+            RoundingMode::Dyn => "DYN",
+        };
+        f.write_str(name)
+    }
+}
+
+/// One of the 32 constants loaded by the Zfa FLI instructions, selected by the
+/// index held in the rs1 field
+///
+/// `min` is the smallest positive normal number of the destination format, so
+/// the constants are rendered as the assembler writes them rather than as a
+/// single floating point value.
+///
+/// ```
+/// use rv_decoder::FliConstant;
+///
+/// assert_eq!(FliConstant::from_index(12).unwrap().to_string(), "0.5");
+/// assert_eq!(FliConstant::from_index(1).unwrap().to_string(), "min");
+/// assert_eq!(FliConstant::from_index(32), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FliConstant(u8);
+
+const FLI_CONSTANTS: [&str; 32] = [
+    "-1.0", "min", "1.52587890625e-05", "3.0517578125e-05", "0.00390625", "0.0078125", "0.0625", "0.125",
+    "0.25", "0.3125", "0.375", "0.4375", "0.5", "0.625", "0.75", "0.875",
+    "1.0", "1.25", "1.5", "1.75", "2.0", "2.5", "3.0", "4.0",
+    "8.0", "16.0", "128.0", "256.0", "32768.0", "65536.0", "inf", "nan",
+];
+
+impl FliConstant {
+    /// The constant at `index` in the FLI table, `None` above 31
+    pub fn from_index(index: u8) -> Option<FliConstant> {
+        if index as usize >= FLI_CONSTANTS.len() {
+            return None;
+        }
+        return Some(FliConstant(index));
+    }
+
+    /// Index of the constant, the value of the rs1 field
+    pub fn index(&self) -> u8 {
+        return self.0;
+    }
+
+    /// Looks up a constant written as `min`, `inf`, `nan` or a decimal number
+    pub(crate) fn from_text(text: &str) -> Option<FliConstant> {
+        if let Some(index) = FLI_CONSTANTS.iter().position(|name| name.eq_ignore_ascii_case(text)) {
+            return Some(FliConstant(index as u8));
+        }
+        let value = text.parse::<f64>().ok()?;
+        let index = FLI_CONSTANTS.iter().position(|name| name.parse::<f64>().map_or(false, |v| v == value))?;
+        return Some(FliConstant(index as u8));
+    }
+}
+
+impl fmt::Display for FliConstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(FLI_CONSTANTS[self.0 as usize])
+    }
+}
+
+/// A register operand from the integer (`x`), floating point (`f`) or vector (`v`) register file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    X(u8),
+    F(u8),
+    V(u8),
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Register::X(n) => write!(f, "x{}", n),
+            Register::F(n) => write!(f, "f{}", n),
+            Register::V(n) => write!(f, "v{}", n),
+        }
+    }
+}
+
+/// An operand field of a decoded instruction, as reported by `Instruction::fields`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    Rd(Register),
+    Rs1(Register),
+    Rs2(Register),
+    Rs3(Register),
+    Imm(i32),
+    Rm(RoundingMode),
+    Shamt(u8),
+    Pred(u8),
+    Succ(u8),
+    Aq(bool),
+    Rl(bool),
+    Csr(u16),
+    Bs(u8),
+    Rnum(u8),
+    /// The vm bit of a vector instruction, clear when masked by v0
+    Vm(bool),
+    Vtype(Vtype),
+    /// The constant loaded by a Zfa FLI instruction
+    Fli(FliConstant),
+}
+
+/// Formats a FENCE predecessor/successor set as the assembler writes it, e.g. "iorw"
+pub(crate) fn fence_set(set: u8) -> String {
+    if set == 0 {
+        return "0".to_string();
+    }
+    let mut text = String::new();
+    for (bit, name) in [(0b1000, 'i'), (0b0100, 'o'), (0b0010, 'r'), (0b0001, 'w')] {
+        if set & bit != 0 {
+            text.push(name);
+        }
+    }
+    return text;
+}
+
+/// Formats the aq/rl bits of an atomic instruction as its mnemonic suffix, e.g. ".AQRL"
+pub(crate) fn ordering_suffix(aq: bool, rl: bool) -> &'static str {
+    match (aq, rl) {
+        (false, false) => "",
+        (true, false) => ".AQ",
+        (false, true) => ".RL",
+        (true, true) => ".AQRL",
+    }
+}
+
+/// A decoded RISC-V instruction
+///
+/// Register operands hold the register number (`x0`-`x31` or `f0`-`f31`
+/// depending on the instruction) and immediates hold the sign-extended
+/// value. `Display` renders the assembly text returned by
+/// `instruction_decoder`.
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{convert_binary_string_to_vector, decode_instruction, Instruction};
+///
+/// let instr = convert_binary_string_to_vector("00000000010000000000001000000011");
+/// let decoded = decode_instruction(instr).unwrap();
+/// assert_eq!(decoded, Instruction::Lb { rd: 4, rs1: 0, imm: 4 });
+/// assert_eq!(decoded.to_string(), "LB x4, 4(x0)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    // RV32I loads
+    Lb { rd: u8, rs1: u8, imm: i32 },
+    Lh { rd: u8, rs1: u8, imm: i32 },
+    Lw { rd: u8, rs1: u8, imm: i32 },
+    Lbu { rd: u8, rs1: u8, imm: i32 },
+    Lhu { rd: u8, rs1: u8, imm: i32 },
+
+    // RV32I stores
+    Sb { rs1: u8, rs2: u8, imm: i32 },
+    Sh { rs1: u8, rs2: u8, imm: i32 },
+    Sw { rs1: u8, rs2: u8, imm: i32 },
+
+    // RV32I branches
+    Beq { rs1: u8, rs2: u8, imm: i32 },
+    Bne { rs1: u8, rs2: u8, imm: i32 },
+    Blt { rs1: u8, rs2: u8, imm: i32 },
+    Bge { rs1: u8, rs2: u8, imm: i32 },
+    Bltu { rs1: u8, rs2: u8, imm: i32 },
+    Bgeu { rs1: u8, rs2: u8, imm: i32 },
+
+    // RV32I register-immediate
+    Addi { rd: u8, rs1: u8, imm: i32 },
+    Slti { rd: u8, rs1: u8, imm: i32 },
+    Sltiu { rd: u8, rs1: u8, imm: i32 },
+    Xori { rd: u8, rs1: u8, imm: i32 },
+    Ori { rd: u8, rs1: u8, imm: i32 },
+    Andi { rd: u8, rs1: u8, imm: i32 },
+    Slli { rd: u8, rs1: u8, shamt: u8 },
+    Srli { rd: u8, rs1: u8, shamt: u8 },
+    Srai { rd: u8, rs1: u8, shamt: u8 },
+
+    // RV32I upper immediates and jumps
+    Lui { rd: u8, imm: i32 },
+    Auipc { rd: u8, imm: i32 },
+    Jal { rd: u8, imm: i32 },
+    Jalr { rd: u8, rs1: u8, imm: i32 },
+
+    // RV32I register-register
+    Add { rd: u8, rs1: u8, rs2: u8 },
+    Sub { rd: u8, rs1: u8, rs2: u8 },
+    Sll { rd: u8, rs1: u8, rs2: u8 },
+    Slt { rd: u8, rs1: u8, rs2: u8 },
+    Sltu { rd: u8, rs1: u8, rs2: u8 },
+    Xor { rd: u8, rs1: u8, rs2: u8 },
+    Srl { rd: u8, rs1: u8, rs2: u8 },
+    Sra { rd: u8, rs1: u8, rs2: u8 },
+    Or { rd: u8, rs1: u8, rs2: u8 },
+    And { rd: u8, rs1: u8, rs2: u8 },
+
+    // RV32I memory ordering and environment calls
+    Fence { pred: u8, succ: u8 },
+    FenceTso,
+    Pause,
+    FenceI,
+    Ecall,
+    Ebreak,
+
+    // Privileged trap returns, interrupt wait and address translation fences
+    Sret,
+    Mret,
+    Mnret,
+    Dret,
+    Wfi,
+    SfenceVma { rs1: u8, rs2: u8 },
+    SinvalVma { rs1: u8, rs2: u8 },
+    SfenceWInval,
+    SfenceInvalIr,
+
+    // Zicsr, the immediate forms hold a 5-bit unsigned immediate in place of rs1
+    Csrrw { rd: u8, rs1: u8, csr: u16 },
+    Csrrs { rd: u8, rs1: u8, csr: u16 },
+    Csrrc { rd: u8, rs1: u8, csr: u16 },
+    Csrrwi { rd: u8, uimm: u8, csr: u16 },
+    Csrrsi { rd: u8, uimm: u8, csr: u16 },
+    Csrrci { rd: u8, uimm: u8, csr: u16 },
+
+    // RV64I loads, stores and word operations
+    Lwu { rd: u8, rs1: u8, imm: i32 },
+    Ld { rd: u8, rs1: u8, imm: i32 },
+    Sd { rs1: u8, rs2: u8, imm: i32 },
+    Addiw { rd: u8, rs1: u8, imm: i32 },
+    Slliw { rd: u8, rs1: u8, shamt: u8 },
+    Srliw { rd: u8, rs1: u8, shamt: u8 },
+    Sraiw { rd: u8, rs1: u8, shamt: u8 },
+    Addw { rd: u8, rs1: u8, rs2: u8 },
+    Subw { rd: u8, rs1: u8, rs2: u8 },
+    Sllw { rd: u8, rs1: u8, rs2: u8 },
+    Srlw { rd: u8, rs1: u8, rs2: u8 },
+    Sraw { rd: u8, rs1: u8, rs2: u8 },
+
+    // Zba/Zbb/Zbc/Zbs bit manipulation
+    Sh1add { rd: u8, rs1: u8, rs2: u8 },
+    Sh2add { rd: u8, rs1: u8, rs2: u8 },
+    Sh3add { rd: u8, rs1: u8, rs2: u8 },
+    Andn { rd: u8, rs1: u8, rs2: u8 },
+    Orn { rd: u8, rs1: u8, rs2: u8 },
+    Xnor { rd: u8, rs1: u8, rs2: u8 },
+    Max { rd: u8, rs1: u8, rs2: u8 },
+    Maxu { rd: u8, rs1: u8, rs2: u8 },
+    Min { rd: u8, rs1: u8, rs2: u8 },
+    Minu { rd: u8, rs1: u8, rs2: u8 },
+    Rol { rd: u8, rs1: u8, rs2: u8 },
+    Ror { rd: u8, rs1: u8, rs2: u8 },
+    Clmul { rd: u8, rs1: u8, rs2: u8 },
+    Clmulh { rd: u8, rs1: u8, rs2: u8 },
+    Clmulr { rd: u8, rs1: u8, rs2: u8 },
+    Bclr { rd: u8, rs1: u8, rs2: u8 },
+    Bext { rd: u8, rs1: u8, rs2: u8 },
+    Binv { rd: u8, rs1: u8, rs2: u8 },
+    Bset { rd: u8, rs1: u8, rs2: u8 },
+    Clz { rd: u8, rs1: u8 },
+    Ctz { rd: u8, rs1: u8 },
+    Cpop { rd: u8, rs1: u8 },
+    SextB { rd: u8, rs1: u8 },
+    SextH { rd: u8, rs1: u8 },
+    ZextH { rd: u8, rs1: u8 },
+    OrcB { rd: u8, rs1: u8 },
+    Rev8 { rd: u8, rs1: u8 },
+    Rori { rd: u8, rs1: u8, shamt: u8 },
+    Bclri { rd: u8, rs1: u8, shamt: u8 },
+    Bexti { rd: u8, rs1: u8, shamt: u8 },
+    Binvi { rd: u8, rs1: u8, shamt: u8 },
+    Bseti { rd: u8, rs1: u8, shamt: u8 },
+
+    // RV64 Zba/Zbb word operations
+    AddUw { rd: u8, rs1: u8, rs2: u8 },
+    Sh1addUw { rd: u8, rs1: u8, rs2: u8 },
+    Sh2addUw { rd: u8, rs1: u8, rs2: u8 },
+    Sh3addUw { rd: u8, rs1: u8, rs2: u8 },
+    Rolw { rd: u8, rs1: u8, rs2: u8 },
+    Rorw { rd: u8, rs1: u8, rs2: u8 },
+    Clzw { rd: u8, rs1: u8 },
+    Ctzw { rd: u8, rs1: u8 },
+    Cpopw { rd: u8, rs1: u8 },
+    SlliUw { rd: u8, rs1: u8, shamt: u8 },
+    Roriw { rd: u8, rs1: u8, shamt: u8 },
+
+    // Zbkb/Zbkx bit manipulation for cryptography, PACKW is RV64 only and ZIP/UNZIP are RV32 only
+    Pack { rd: u8, rs1: u8, rs2: u8 },
+    Packh { rd: u8, rs1: u8, rs2: u8 },
+    Packw { rd: u8, rs1: u8, rs2: u8 },
+    Xperm4 { rd: u8, rs1: u8, rs2: u8 },
+    Xperm8 { rd: u8, rs1: u8, rs2: u8 },
+    Brev8 { rd: u8, rs1: u8 },
+    Zip { rd: u8, rs1: u8 },
+    Unzip { rd: u8, rs1: u8 },
+
+    // Zknd/Zkne/Zknh/Zksed/Zksh scalar cryptography, bs selects a byte of rs2
+    Aes32esi { rd: u8, rs1: u8, rs2: u8, bs: u8 },
+    Aes32esmi { rd: u8, rs1: u8, rs2: u8, bs: u8 },
+    Aes32dsi { rd: u8, rs1: u8, rs2: u8, bs: u8 },
+    Aes32dsmi { rd: u8, rs1: u8, rs2: u8, bs: u8 },
+    Aes64es { rd: u8, rs1: u8, rs2: u8 },
+    Aes64esm { rd: u8, rs1: u8, rs2: u8 },
+    Aes64ds { rd: u8, rs1: u8, rs2: u8 },
+    Aes64dsm { rd: u8, rs1: u8, rs2: u8 },
+    Aes64ks1i { rd: u8, rs1: u8, rnum: u8 },
+    Aes64ks2 { rd: u8, rs1: u8, rs2: u8 },
+    Aes64im { rd: u8, rs1: u8 },
+    Sha256sig0 { rd: u8, rs1: u8 },
+    Sha256sig1 { rd: u8, rs1: u8 },
+    Sha256sum0 { rd: u8, rs1: u8 },
+    Sha256sum1 { rd: u8, rs1: u8 },
+    Sha512sig0h { rd: u8, rs1: u8, rs2: u8 },
+    Sha512sig0l { rd: u8, rs1: u8, rs2: u8 },
+    Sha512sig1h { rd: u8, rs1: u8, rs2: u8 },
+    Sha512sig1l { rd: u8, rs1: u8, rs2: u8 },
+    Sha512sum0r { rd: u8, rs1: u8, rs2: u8 },
+    Sha512sum1r { rd: u8, rs1: u8, rs2: u8 },
+    Sha512sig0 { rd: u8, rs1: u8 },
+    Sha512sig1 { rd: u8, rs1: u8 },
+    Sha512sum0 { rd: u8, rs1: u8 },
+    Sha512sum1 { rd: u8, rs1: u8 },
+    Sm4ed { rd: u8, rs1: u8, rs2: u8, bs: u8 },
+    Sm4ks { rd: u8, rs1: u8, rs2: u8, bs: u8 },
+    Sm3p0 { rd: u8, rs1: u8 },
+    Sm3p1 { rd: u8, rs1: u8 },
+
+    // RV32M
+    Mul { rd: u8, rs1: u8, rs2: u8 },
+    Mulh { rd: u8, rs1: u8, rs2: u8 },
+    Mulhsu { rd: u8, rs1: u8, rs2: u8 },
+    Mulhu { rd: u8, rs1: u8, rs2: u8 },
+    Div { rd: u8, rs1: u8, rs2: u8 },
+    Divu { rd: u8, rs1: u8, rs2: u8 },
+    Rem { rd: u8, rs1: u8, rs2: u8 },
+    Remu { rd: u8, rs1: u8, rs2: u8 },
+
+    // RV64M
+    Mulw { rd: u8, rs1: u8, rs2: u8 },
+    Divw { rd: u8, rs1: u8, rs2: u8 },
+    Divuw { rd: u8, rs1: u8, rs2: u8 },
+    Remw { rd: u8, rs1: u8, rs2: u8 },
+    Remuw { rd: u8, rs1: u8, rs2: u8 },
+
+    // RV32A, the aq/rl bits select the memory ordering suffix
+    LrW { rd: u8, rs1: u8, aq: bool, rl: bool },
+    ScW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoswapW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoaddW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoxorW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoandW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoorW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmominW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmomaxW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmominuW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmomaxuW { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+
+    // RV64A
+    LrD { rd: u8, rs1: u8, aq: bool, rl: bool },
+    ScD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoswapD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoaddD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoxorD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoandD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmoorD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmominD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmomaxD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmominuD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+    AmomaxuD { rd: u8, rs1: u8, rs2: u8, aq: bool, rl: bool },
+
+    // RV32F/RV32D fused multiply-add
+    FmaddS { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FmaddD { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FmsubS { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FmsubD { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FnmsubS { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FnmsubD { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FnmaddS { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FnmaddD { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+
+    // RV32F/RV32D arithmetic
+    FaddS { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FaddD { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FsubS { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FsubD { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FmulS { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FmulD { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FdivS { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FdivD { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FsqrtS { rd: u8, rs1: u8, rm: RoundingMode },
+    FsqrtD { rd: u8, rs1: u8, rm: RoundingMode },
+
+    // RV32F/RV32D sign injection, min/max
+    FsgnjS { rd: u8, rs1: u8, rs2: u8 },
+    FsgnjD { rd: u8, rs1: u8, rs2: u8 },
+    FsgnjnS { rd: u8, rs1: u8, rs2: u8 },
+    FsgnjnD { rd: u8, rs1: u8, rs2: u8 },
+    FsgnjxS { rd: u8, rs1: u8, rs2: u8 },
+    FsgnjxD { rd: u8, rs1: u8, rs2: u8 },
+    FminS { rd: u8, rs1: u8, rs2: u8 },
+    FminD { rd: u8, rs1: u8, rs2: u8 },
+    FmaxS { rd: u8, rs1: u8, rs2: u8 },
+    FmaxD { rd: u8, rs1: u8, rs2: u8 },
+
+    // RV32F/RV32D conversions and moves
+    FcvtDS { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtSD { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtWS { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtWD { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtWuS { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtWuD { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtSW { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtDW { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtSWu { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtDWu { rd: u8, rs1: u8, rm: RoundingMode },
+    FmvXW { rd: u8, rs1: u8 },
+    FmvWX { rd: u8, rs1: u8 },
+
+    // RV64F/RV64D conversions and moves
+    FcvtLS { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtLuS { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtSL { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtSLu { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtLD { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtLuD { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtDL { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtDLu { rd: u8, rs1: u8, rm: RoundingMode },
+    FmvXD { rd: u8, rs1: u8 },
+    FmvDX { rd: u8, rs1: u8 },
+
+    // RV32F/RV32D compare and classify
+    FeqS { rd: u8, rs1: u8, rs2: u8 },
+    FeqD { rd: u8, rs1: u8, rs2: u8 },
+    FltS { rd: u8, rs1: u8, rs2: u8 },
+    FltD { rd: u8, rs1: u8, rs2: u8 },
+    FleS { rd: u8, rs1: u8, rs2: u8 },
+    FleD { rd: u8, rs1: u8, rs2: u8 },
+    FclassS { rd: u8, rs1: u8 },
+    FclassD { rd: u8, rs1: u8 },
+
+    // RV32F/RV32D loads and stores
+    Flw { rd: u8, rs1: u8, imm: i32 },
+    Fld { rd: u8, rs1: u8, imm: i32 },
+    Fsw { rs1: u8, rs2: u8, imm: i32 },
+    Fsd { rs1: u8, rs2: u8, imm: i32 },
+
+    // Zfh half precision, Zfhmin is the loads, stores, moves and conversions
+    FmaddH { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FmsubH { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FnmsubH { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FnmaddH { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FaddH { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FsubH { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FmulH { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FdivH { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FsqrtH { rd: u8, rs1: u8, rm: RoundingMode },
+    FsgnjH { rd: u8, rs1: u8, rs2: u8 },
+    FsgnjnH { rd: u8, rs1: u8, rs2: u8 },
+    FsgnjxH { rd: u8, rs1: u8, rs2: u8 },
+    FminH { rd: u8, rs1: u8, rs2: u8 },
+    FmaxH { rd: u8, rs1: u8, rs2: u8 },
+    FcvtWH { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtWuH { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtHW { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtHWu { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtLH { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtLuH { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtHL { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtHLu { rd: u8, rs1: u8, rm: RoundingMode },
+    FmvXH { rd: u8, rs1: u8 },
+    FmvHX { rd: u8, rs1: u8 },
+    FeqH { rd: u8, rs1: u8, rs2: u8 },
+    FltH { rd: u8, rs1: u8, rs2: u8 },
+    FleH { rd: u8, rs1: u8, rs2: u8 },
+    FclassH { rd: u8, rs1: u8 },
+    FcvtSH { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtHS { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtDH { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtHD { rd: u8, rs1: u8, rm: RoundingMode },
+    Flh { rd: u8, rs1: u8, imm: i32 },
+    Fsh { rs1: u8, rs2: u8, imm: i32 },
+
+    // RV32Q/RV64Q quad precision
+    FmaddQ { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FmsubQ { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FnmsubQ { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FnmaddQ { rd: u8, rs1: u8, rs2: u8, rs3: u8, rm: RoundingMode },
+    FaddQ { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FsubQ { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FmulQ { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FdivQ { rd: u8, rs1: u8, rs2: u8, rm: RoundingMode },
+    FsqrtQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FsgnjQ { rd: u8, rs1: u8, rs2: u8 },
+    FsgnjnQ { rd: u8, rs1: u8, rs2: u8 },
+    FsgnjxQ { rd: u8, rs1: u8, rs2: u8 },
+    FminQ { rd: u8, rs1: u8, rs2: u8 },
+    FmaxQ { rd: u8, rs1: u8, rs2: u8 },
+    FcvtWQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtWuQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtQW { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtQWu { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtLQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtLuQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtQL { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtQLu { rd: u8, rs1: u8, rm: RoundingMode },
+    FeqQ { rd: u8, rs1: u8, rs2: u8 },
+    FltQ { rd: u8, rs1: u8, rs2: u8 },
+    FleQ { rd: u8, rs1: u8, rs2: u8 },
+    FclassQ { rd: u8, rs1: u8 },
+    FcvtSQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtQS { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtDQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtQD { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtHQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FcvtQH { rd: u8, rs1: u8, rm: RoundingMode },
+    Flq { rd: u8, rs1: u8, imm: i32 },
+    Fsq { rs1: u8, rs2: u8, imm: i32 },
+
+    // Zfa, FMVH.X.D/FMVP.D.X are RV32 only and FMVH.X.Q/FMVP.Q.X RV64 only
+    FliS { rd: u8, constant: FliConstant },
+    FliD { rd: u8, constant: FliConstant },
+    FliH { rd: u8, constant: FliConstant },
+    FliQ { rd: u8, constant: FliConstant },
+    FminmS { rd: u8, rs1: u8, rs2: u8 },
+    FmaxmS { rd: u8, rs1: u8, rs2: u8 },
+    FminmD { rd: u8, rs1: u8, rs2: u8 },
+    FmaxmD { rd: u8, rs1: u8, rs2: u8 },
+    FminmH { rd: u8, rs1: u8, rs2: u8 },
+    FmaxmH { rd: u8, rs1: u8, rs2: u8 },
+    FminmQ { rd: u8, rs1: u8, rs2: u8 },
+    FmaxmQ { rd: u8, rs1: u8, rs2: u8 },
+    FroundS { rd: u8, rs1: u8, rm: RoundingMode },
+    FroundnxS { rd: u8, rs1: u8, rm: RoundingMode },
+    FroundD { rd: u8, rs1: u8, rm: RoundingMode },
+    FroundnxD { rd: u8, rs1: u8, rm: RoundingMode },
+    FroundH { rd: u8, rs1: u8, rm: RoundingMode },
+    FroundnxH { rd: u8, rs1: u8, rm: RoundingMode },
+    FroundQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FroundnxQ { rd: u8, rs1: u8, rm: RoundingMode },
+    FleqS { rd: u8, rs1: u8, rs2: u8 },
+    FltqS { rd: u8, rs1: u8, rs2: u8 },
+    FleqD { rd: u8, rs1: u8, rs2: u8 },
+    FltqD { rd: u8, rs1: u8, rs2: u8 },
+    FleqH { rd: u8, rs1: u8, rs2: u8 },
+    FltqH { rd: u8, rs1: u8, rs2: u8 },
+    FleqQ { rd: u8, rs1: u8, rs2: u8 },
+    FltqQ { rd: u8, rs1: u8, rs2: u8 },
+    FcvtmodWD { rd: u8, rs1: u8 },
+    FmvhXD { rd: u8, rs1: u8 },
+    FmvhXQ { rd: u8, rs1: u8 },
+    FmvpDX { rd: u8, rs1: u8, rs2: u8 },
+    FmvpQX { rd: u8, rs1: u8, rs2: u8 },
+
+    // RVV 1.0, vm is the encoding bit and is clear when the operation is masked by v0
+    Vsetvli { rd: u8, rs1: u8, vtype: Vtype },
+    Vsetivli { rd: u8, uimm: u8, vtype: Vtype },
+    Vsetvl { rd: u8, rs1: u8, rs2: u8 },
+    VectorLoad { vd: u8, rs1: u8, addressing: VectorAddressing, eew: Sew, nf: u8, vm: bool },
+    VectorStore { vs3: u8, rs1: u8, addressing: VectorAddressing, eew: Sew, nf: u8, vm: bool },
+    VectorArith { op: VectorOp, vd: u8, vs2: u8, src: VectorOperand, vm: bool },
+    VectorUnary { op: VectorUnaryOp, vd: u8, vs2: u8, vm: bool },
+}
+
+impl Instruction {
+    /// Returns the assembly mnemonic of the instruction, e.g. "FMADD.S"
+    ///
+    /// Vector loads, stores and arithmetic leave out the element width, segment
+    /// count and operand form that `Display` spells out, e.g. "VADD" for VADD.VI.
+    pub fn mnemonic(&self) -> &'static str {
+        use Instruction::*;
+        match self {
+            Lb { .. } => "LB",
+            Lh { .. } => "LH",
+            Lw { .. } => "LW",
+            Lbu { .. } => "LBU",
+            Lhu { .. } => "LHU",
+            Sb { .. } => "SB",
+            Sh { .. } => "SH",
+            Sw { .. } => "SW",
+            Beq { .. } => "BEQ",
+            Bne { .. } => "BNE",
+            Blt { .. } => "BLT",
+            Bge { .. } => "BGE",
+            Bltu { .. } => "BLTU",
+            Bgeu { .. } => "BGEU",
+            Addi { .. } => "ADDI",
+            Slti { .. } => "SLTI",
+            Sltiu { .. } => "SLTIU",
+            Xori { .. } => "XORI",
+            Ori { .. } => "ORI",
+            Andi { .. } => "ANDI",
+            Slli { .. } => "SLLI",
+            Srli { .. } => "SRLI",
+            Srai { .. } => "SRAI",
+            Lui { .. } => "LUI",
+            Auipc { .. } => "AUIPC",
+            Jal { .. } => "JAL",
+            Jalr { .. } => "JALR",
+            Add { .. } => "ADD",
+            Sub { .. } => "SUB",
+            Sll { .. } => "SLL",
+            Slt { .. } => "SLT",
+            Sltu { .. } => "SLTU",
+            Xor { .. } => "XOR",
+            Srl { .. } => "SRL",
+            Sra { .. } => "SRA",
+            Or { .. } => "OR",
+            And { .. } => "AND",
+            Fence { .. } => "FENCE",
+            FenceTso => "FENCE.TSO",
+            Pause => "PAUSE",
+            FenceI => "FENCE.I",
+            Ecall => "ECALL",
+            Ebreak => "EBREAK",
+            Sret => "SRET",
+            Mret => "MRET",
+            Mnret => "MNRET",
+            Dret => "DRET",
+            Wfi => "WFI",
+            SfenceVma { .. } => "SFENCE.VMA",
+            SinvalVma { .. } => "SINVAL.VMA",
+            SfenceWInval => "SFENCE.W.INVAL",
+            SfenceInvalIr => "SFENCE.INVAL.IR",
+            Csrrw { .. } => "CSRRW",
+            Csrrs { .. } => "CSRRS",
+            Csrrc { .. } => "CSRRC",
+            Csrrwi { .. } => "CSRRWI",
+            Csrrsi { .. } => "CSRRSI",
+            Csrrci { .. } => "CSRRCI",
+            Lwu { .. } => "LWU",
+            Ld { .. } => "LD",
+            Sd { .. } => "SD",
+            Addiw { .. } => "ADDIW",
+            Slliw { .. } => "SLLIW",
+            Srliw { .. } => "SRLIW",
+            Sraiw { .. } => "SRAIW",
+            Addw { .. } => "ADDW",
+            Subw { .. } => "SUBW",
+            Sllw { .. } => "SLLW",
+            Srlw { .. } => "SRLW",
+            Sraw { .. } => "SRAW",
+            Sh1add { .. } => "SH1ADD",
+            Sh2add { .. } => "SH2ADD",
+            Sh3add { .. } => "SH3ADD",
+            Andn { .. } => "ANDN",
+            Orn { .. } => "ORN",
+            Xnor { .. } => "XNOR",
+            Max { .. } => "MAX",
+            Maxu { .. } => "MAXU",
+            Min { .. } => "MIN",
+            Minu { .. } => "MINU",
+            Rol { .. } => "ROL",
+            Ror { .. } => "ROR",
+            Clmul { .. } => "CLMUL",
+            Clmulh { .. } => "CLMULH",
+            Clmulr { .. } => "CLMULR",
+            Bclr { .. } => "BCLR",
+            Bext { .. } => "BEXT",
+            Binv { .. } => "BINV",
+            Bset { .. } => "BSET",
+            Clz { .. } => "CLZ",
+            Ctz { .. } => "CTZ",
+            Cpop { .. } => "CPOP",
+            SextB { .. } => "SEXT.B",
+            SextH { .. } => "SEXT.H",
+            ZextH { .. } => "ZEXT.H",
+            OrcB { .. } => "ORC.B",
+            Rev8 { .. } => "REV8",
+            Rori { .. } => "RORI",
+            Bclri { .. } => "BCLRI",
+            Bexti { .. } => "BEXTI",
+            Binvi { .. } => "BINVI",
+            Bseti { .. } => "BSETI",
+            AddUw { .. } => "ADD.UW",
+            Sh1addUw { .. } => "SH1ADD.UW",
+            Sh2addUw { .. } => "SH2ADD.UW",
+            Sh3addUw { .. } => "SH3ADD.UW",
+            Rolw { .. } => "ROLW",
+            Rorw { .. } => "RORW",
+            Clzw { .. } => "CLZW",
+            Ctzw { .. } => "CTZW",
+            Cpopw { .. } => "CPOPW",
+            SlliUw { .. } => "SLLI.UW",
+            Roriw { .. } => "RORIW",
+            Pack { .. } => "PACK",
+            Packh { .. } => "PACKH",
+            Packw { .. } => "PACKW",
+            Xperm4 { .. } => "XPERM4",
+            Xperm8 { .. } => "XPERM8",
+            Brev8 { .. } => "BREV8",
+            Zip { .. } => "ZIP",
+            Unzip { .. } => "UNZIP",
+            Aes32esi { .. } => "AES32ESI",
+            Aes32esmi { .. } => "AES32ESMI",
+            Aes32dsi { .. } => "AES32DSI",
+            Aes32dsmi { .. } => "AES32DSMI",
+            Aes64es { .. } => "AES64ES",
+            Aes64esm { .. } => "AES64ESM",
+            Aes64ds { .. } => "AES64DS",
+            Aes64dsm { .. } => "AES64DSM",
+            Aes64ks1i { .. } => "AES64KS1I",
+            Aes64ks2 { .. } => "AES64KS2",
+            Aes64im { .. } => "AES64IM",
+            Sha256sig0 { .. } => "SHA256SIG0",
+            Sha256sig1 { .. } => "SHA256SIG1",
+            Sha256sum0 { .. } => "SHA256SUM0",
+            Sha256sum1 { .. } => "SHA256SUM1",
+            Sha512sig0h { .. } => "SHA512SIG0H",
+            Sha512sig0l { .. } => "SHA512SIG0L",
+            Sha512sig1h { .. } => "SHA512SIG1H",
+            Sha512sig1l { .. } => "SHA512SIG1L",
+            Sha512sum0r { .. } => "SHA512SUM0R",
+            Sha512sum1r { .. } => "SHA512SUM1R",
+            Sha512sig0 { .. } => "SHA512SIG0",
+            Sha512sig1 { .. } => "SHA512SIG1",
+            Sha512sum0 { .. } => "SHA512SUM0",
+            Sha512sum1 { .. } => "SHA512SUM1",
+            Sm4ed { .. } => "SM4ED",
+            Sm4ks { .. } => "SM4KS",
+            Sm3p0 { .. } => "SM3P0",
+            Sm3p1 { .. } => "SM3P1",
+            Mul { .. } => "MUL",
+            Mulh { .. } => "MULH",
+            Mulhsu { .. } => "MULHSU",
+            Mulhu { .. } => "MULHU",
+            Div { .. } => "DIV",
+            Divu { .. } => "DIVU",
+            Rem { .. } => "REM",
+            Remu { .. } => "REMU",
+            Mulw { .. } => "MULW",
+            Divw { .. } => "DIVW",
+            Divuw { .. } => "DIVUW",
+            Remw { .. } => "REMW",
+            Remuw { .. } => "REMUW",
+            LrW { .. } => "LR.W",
+            ScW { .. } => "SC.W",
+            AmoswapW { .. } => "AMOSWAP.W",
+            AmoaddW { .. } => "AMOADD.W",
+            AmoxorW { .. } => "AMOXOR.W",
+            AmoandW { .. } => "AMOAND.W",
+            AmoorW { .. } => "AMOOR.W",
+            AmominW { .. } => "AMOMIN.W",
+            AmomaxW { .. } => "AMOMAX.W",
+            AmominuW { .. } => "AMOMINU.W",
+            AmomaxuW { .. } => "AMOMAXU.W",
+            LrD { .. } => "LR.D",
+            ScD { .. } => "SC.D",
+            AmoswapD { .. } => "AMOSWAP.D",
+            AmoaddD { .. } => "AMOADD.D",
+            AmoxorD { .. } => "AMOXOR.D",
+            AmoandD { .. } => "AMOAND.D",
+            AmoorD { .. } => "AMOOR.D",
+            AmominD { .. } => "AMOMIN.D",
+            AmomaxD { .. } => "AMOMAX.D",
+            AmominuD { .. } => "AMOMINU.D",
+            AmomaxuD { .. } => "AMOMAXU.D",
+            FmaddS { .. } => "FMADD.S",
+            FmaddD { .. } => "FMADD.D",
+            FmsubS { .. } => "FMSUB.S",
+            FmsubD { .. } => "FMSUB.D",
+            FnmsubS { .. } => "FNMSUB.S",
+            FnmsubD { .. } => "FNMSUB.D",
+            FnmaddS { .. } => "FNMADD.S",
+            FnmaddD { .. } => "FNMADD.D",
+            FaddS { .. } => "FADD.S",
+            FaddD { .. } => "FADD.D",
+            FsubS { .. } => "FSUB.S",
+            FsubD { .. } => "FSUB.D",
+            FmulS { .. } => "FMUL.S",
+            FmulD { .. } => "FMUL.D",
+            FdivS { .. } => "FDIV.S",
+            FdivD { .. } => "FDIV.D",
+            FsqrtS { .. } => "FSQRT.S",
+            FsqrtD { .. } => "FSQRT.D",
+            FsgnjS { .. } => "FSGNJ.S",
+            FsgnjD { .. } => "FSGNJ.D",
+            FsgnjnS { .. } => "FSGNJN.S",
+            FsgnjnD { .. } => "FSGNJN.D",
+            FsgnjxS { .. } => "FSGNJX.S",
+            FsgnjxD { .. } => "FSGNJX.D",
+            FminS { .. } => "FMIN.S",
+            FminD { .. } => "FMIN.D",
+            FmaxS { .. } => "FMAX.S",
+            FmaxD { .. } => "FMAX.D",
+            FcvtDS { .. } => "FCVT.D.S",
+            FcvtSD { .. } => "FCVT.S.D",
+            FcvtWS { .. } => "FCVT.W.S",
+            FcvtWD { .. } => "FCVT.W.D",
+            FcvtWuS { .. } => "FCVT.WU.S",
+            FcvtWuD { .. } => "FCVT.WU.D",
+            FcvtSW { .. } => "FCVT.S.W",
+            FcvtDW { .. } => "FCVT.D.W",
+            FcvtSWu { .. } => "FCVT.S.WU",
+            FcvtDWu { .. } => "FCVT.D.WU",
+            FmvXW { .. } => "FMV.X.W",
+            FmvWX { .. } => "FMV.W.X",
+            FcvtLS { .. } => "FCVT.L.S",
+            FcvtLuS { .. } => "FCVT.LU.S",
+            FcvtSL { .. } => "FCVT.S.L",
+            FcvtSLu { .. } => "FCVT.S.LU",
+            FcvtLD { .. } => "FCVT.L.D",
+            FcvtLuD { .. } => "FCVT.LU.D",
+            FcvtDL { .. } => "FCVT.D.L",
+            FcvtDLu { .. } => "FCVT.D.LU",
+            FmvXD { .. } => "FMV.X.D",
+            FmvDX { .. } => "FMV.D.X",
+            FeqS { .. } => "FEQ.S",
+            FeqD { .. } => "FEQ.D",
+            FltS { .. } => "FLT.S",
+            FltD { .. } => "FLT.D",
+            FleS { .. } => "FLE.S",
+            FleD { .. } => "FLE.D",
+            FclassS { .. } => "FCLASS.S",
+            FclassD { .. } => "FCLASS.D",
+            Flw { .. } => "FLW",
+            Fld { .. } => "FLD",
+            Fsw { .. } => "FSW",
+            Fsd { .. } => "FSD",
+            FmaddH { .. } => "FMADD.H",
+            FmsubH { .. } => "FMSUB.H",
+            FnmsubH { .. } => "FNMSUB.H",
+            FnmaddH { .. } => "FNMADD.H",
+            FaddH { .. } => "FADD.H",
+            FsubH { .. } => "FSUB.H",
+            FmulH { .. } => "FMUL.H",
+            FdivH { .. } => "FDIV.H",
+            FsqrtH { .. } => "FSQRT.H",
+            FsgnjH { .. } => "FSGNJ.H",
+            FsgnjnH { .. } => "FSGNJN.H",
+            FsgnjxH { .. } => "FSGNJX.H",
+            FminH { .. } => "FMIN.H",
+            FmaxH { .. } => "FMAX.H",
+            FcvtWH { .. } => "FCVT.W.H",
+            FcvtWuH { .. } => "FCVT.WU.H",
+            FcvtHW { .. } => "FCVT.H.W",
+            FcvtHWu { .. } => "FCVT.H.WU",
+            FcvtLH { .. } => "FCVT.L.H",
+            FcvtLuH { .. } => "FCVT.LU.H",
+            FcvtHL { .. } => "FCVT.H.L",
+            FcvtHLu { .. } => "FCVT.H.LU",
+            FmvXH { .. } => "FMV.X.H",
+            FmvHX { .. } => "FMV.H.X",
+            FeqH { .. } => "FEQ.H",
+            FltH { .. } => "FLT.H",
+            FleH { .. } => "FLE.H",
+            FclassH { .. } => "FCLASS.H",
+            FcvtSH { .. } => "FCVT.S.H",
+            FcvtHS { .. } => "FCVT.H.S",
+            FcvtDH { .. } => "FCVT.D.H",
+            FcvtHD { .. } => "FCVT.H.D",
+            Flh { .. } => "FLH",
+            Fsh { .. } => "FSH",
+            FmaddQ { .. } => "FMADD.Q",
+            FmsubQ { .. } => "FMSUB.Q",
+            FnmsubQ { .. } => "FNMSUB.Q",
+            FnmaddQ { .. } => "FNMADD.Q",
+            FaddQ { .. } => "FADD.Q",
+            FsubQ { .. } => "FSUB.Q",
+            FmulQ { .. } => "FMUL.Q",
+            FdivQ { .. } => "FDIV.Q",
+            FsqrtQ { .. } => "FSQRT.Q",
+            FsgnjQ { .. } => "FSGNJ.Q",
+            FsgnjnQ { .. } => "FSGNJN.Q",
+            FsgnjxQ { .. } => "FSGNJX.Q",
+            FminQ { .. } => "FMIN.Q",
+            FmaxQ { .. } => "FMAX.Q",
+            FcvtWQ { .. } => "FCVT.W.Q",
+            FcvtWuQ { .. } => "FCVT.WU.Q",
+            FcvtQW { .. } => "FCVT.Q.W",
+            FcvtQWu { .. } => "FCVT.Q.WU",
+            FcvtLQ { .. } => "FCVT.L.Q",
+            FcvtLuQ { .. } => "FCVT.LU.Q",
+            FcvtQL { .. } => "FCVT.Q.L",
+            FcvtQLu { .. } => "FCVT.Q.LU",
+            FeqQ { .. } => "FEQ.Q",
+            FltQ { .. } => "FLT.Q",
+            FleQ { .. } => "FLE.Q",
+            FclassQ { .. } => "FCLASS.Q",
+            FcvtSQ { .. } => "FCVT.S.Q",
+            FcvtQS { .. } => "FCVT.Q.S",
+            FcvtDQ { .. } => "FCVT.D.Q",
+            FcvtQD { .. } => "FCVT.Q.D",
+            FcvtHQ { .. } => "FCVT.H.Q",
+            FcvtQH { .. } => "FCVT.Q.H",
+            Flq { .. } => "FLQ",
+            Fsq { .. } => "FSQ",
+            FliS { .. } => "FLI.S",
+            FliD { .. } => "FLI.D",
+            FliH { .. } => "FLI.H",
+            FliQ { .. } => "FLI.Q",
+            FminmS { .. } => "FMINM.S",
+            FmaxmS { .. } => "FMAXM.S",
+            FminmD { .. } => "FMINM.D",
+            FmaxmD { .. } => "FMAXM.D",
+            FminmH { .. } => "FMINM.H",
+            FmaxmH { .. } => "FMAXM.H",
+            FminmQ { .. } => "FMINM.Q",
+            FmaxmQ { .. } => "FMAXM.Q",
+            FroundS { .. } => "FROUND.S",
+            FroundnxS { .. } => "FROUNDNX.S",
+            FroundD { .. } => "FROUND.D",
+            FroundnxD { .. } => "FROUNDNX.D",
+            FroundH { .. } => "FROUND.H",
+            FroundnxH { .. } => "FROUNDNX.H",
+            FroundQ { .. } => "FROUND.Q",
+            FroundnxQ { .. } => "FROUNDNX.Q",
+            FleqS { .. } => "FLEQ.S",
+            FltqS { .. } => "FLTQ.S",
+            FleqD { .. } => "FLEQ.D",
+            FltqD { .. } => "FLTQ.D",
+            FleqH { .. } => "FLEQ.H",
+            FltqH { .. } => "FLTQ.H",
+            FleqQ { .. } => "FLEQ.Q",
+            FltqQ { .. } => "FLTQ.Q",
+            FcvtmodWD { .. } => "FCVTMOD.W.D",
+            FmvhXD { .. } => "FMVH.X.D",
+            FmvhXQ { .. } => "FMVH.X.Q",
+            FmvpDX { .. } => "FMVP.D.X",
+            FmvpQX { .. } => "FMVP.Q.X",
+            Vsetvli { .. } => "VSETVLI",
+            Vsetivli { .. } => "VSETIVLI",
+            Vsetvl { .. } => "VSETVL",
+            VectorLoad { addressing, .. } => vector::memory_family(true, *addressing),
+            VectorStore { addressing, .. } => vector::memory_family(false, *addressing),
+            VectorArith { op, .. } => op.name(),
+            VectorUnary { op, .. } => op.name(),
+        }
+    }
+
+    /// Returns a short description of the instruction, e.g. "Load Byte"
+    pub fn description(&self) -> &'static str {
+        use Instruction::*;
+        match self {
+            Lb { .. } => "Load Byte",
+            Lh { .. } => "Load Half-word",
+            Lw { .. } => "Load Word",
+            Lbu { .. } => "Load Byte Unsigned",
+            Lhu { .. } => "Load Half-word Unsigned",
+            Sb { .. } => "Store Byte",
+            Sh { .. } => "Store Half-word",
+            Sw { .. } => "Store Word",
+            Beq { .. } => "Branch Equal",
+            Bne { .. } => "Branch Not Equal",
+            Blt { .. } => "Branch Less Than",
+            Bge { .. } => "Branch Greater Than or Equal",
+            Bltu { .. } => "Branch Less Than Unsigned",
+            Bgeu { .. } => "Branch Greater Than or Equal Unsigned",
+            Addi { .. } => "Add Immediate",
+            Slti { .. } => "Set less than Immediate",
+            Sltiu { .. } => "Set less than Immediate unsigned",
+            Xori { .. } => "XOR Immediate",
+            Ori { .. } => "OR Immediate",
+            Andi { .. } => "AND Immediate",
+            Slli { .. } => "Shift Left Logical Immediate",
+            Srli { .. } => "Shift Right Logical Immediate",
+            Srai { .. } => "Shift Right Arithmetic Immediate",
+            Lui { .. } => "Load Upper Immediate",
+            Auipc { .. } => "Add upper immediate with PC",
+            Jal { .. } => "Jump and Link",
+            Jalr { .. } => "Jump and Link to register",
+            Add { .. } => "Addition",
+            Sub { .. } => "Subtraction",
+            Sll { .. } => "Shift Left Logical",
+            Slt { .. } => "Set less than",
+            Sltu { .. } => "Set less than unsigned",
+            Xor { .. } => "XOR",
+            Srl { .. } => "Shift Right Logical",
+            Sra { .. } => "Shift Right Arithmetic",
+            Or { .. } => "OR",
+            And { .. } => "AND",
+            Fence { .. } => "Fence",
+            FenceTso => "Fence with Total Store Ordering",
+            Pause => "Pause Hint",
+            FenceI => "Instruction Fence",
+            Ecall => "Environment Call",
+            Ebreak => "Environment Breakpoint",
+            Sret => "Supervisor Trap Return",
+            Mret => "Machine Trap Return",
+            Mnret => "Machine NMI Trap Return",
+            Dret => "Debug Mode Return",
+            Wfi => "Wait for Interrupt",
+            SfenceVma { .. } => "Supervisor Address Translation Fence",
+            SinvalVma { .. } => "Supervisor Address Translation Invalidate",
+            SfenceWInval => "Fence Stores before Address Translation Invalidates",
+            SfenceInvalIr => "Fence Address Translation Invalidates before Implicit References",
+            Csrrw { .. } => "Atomic Read/Write CSR",
+            Csrrs { .. } => "Atomic Read and Set Bits in CSR",
+            Csrrc { .. } => "Atomic Read and Clear Bits in CSR",
+            Csrrwi { .. } => "Atomic Read/Write CSR Immediate",
+            Csrrsi { .. } => "Atomic Read and Set Bits in CSR Immediate",
+            Csrrci { .. } => "Atomic Read and Clear Bits in CSR Immediate",
+            Lwu { .. } => "Load Word Unsigned",
+            Ld { .. } => "Load Double-word",
+            Sd { .. } => "Store Double-word",
+            Addiw { .. } => "Add Immediate Word",
+            Slliw { .. } => "Shift left logical Immediate Word",
+            Srliw { .. } => "Shift right logical Immediate Word",
+            Sraiw { .. } => "Shift right arithmetic Immediate Word",
+            Addw { .. } => "Add Word",
+            Subw { .. } => "Subtract Word",
+            Sllw { .. } => "Shift left logical Word",
+            Srlw { .. } => "Shift right logical Word",
+            Sraw { .. } => "Shift right arithmetic Word",
+            Sh1add { .. } => "Shift Left by 1 and Add",
+            Sh2add { .. } => "Shift Left by 2 and Add",
+            Sh3add { .. } => "Shift Left by 3 and Add",
+            Andn { .. } => "AND with Inverted Operand",
+            Orn { .. } => "OR with Inverted Operand",
+            Xnor { .. } => "Exclusive NOR",
+            Max { .. } => "Maximum",
+            Maxu { .. } => "Unsigned Maximum",
+            Min { .. } => "Minimum",
+            Minu { .. } => "Unsigned Minimum",
+            Rol { .. } => "Rotate Left",
+            Ror { .. } => "Rotate Right",
+            Clmul { .. } => "Carry-Less Multiply (Low-Part)",
+            Clmulh { .. } => "Carry-Less Multiply (High-Part)",
+            Clmulr { .. } => "Carry-Less Multiply (Reversed)",
+            Bclr { .. } => "Single-Bit Clear",
+            Bext { .. } => "Single-Bit Extract",
+            Binv { .. } => "Single-Bit Invert",
+            Bset { .. } => "Single-Bit Set",
+            Clz { .. } => "Count Leading Zero Bits",
+            Ctz { .. } => "Count Trailing Zero Bits",
+            Cpop { .. } => "Count Set Bits",
+            SextB { .. } => "Sign-Extend Byte",
+            SextH { .. } => "Sign-Extend Halfword",
+            ZextH { .. } => "Zero-Extend Halfword",
+            OrcB { .. } => "Bitwise OR-Combine, Byte Granule",
+            Rev8 { .. } => "Byte-Reverse Register",
+            Rori { .. } => "Rotate Right Immediate",
+            Bclri { .. } => "Single-Bit Clear Immediate",
+            Bexti { .. } => "Single-Bit Extract Immediate",
+            Binvi { .. } => "Single-Bit Invert Immediate",
+            Bseti { .. } => "Single-Bit Set Immediate",
+            AddUw { .. } => "Add Unsigned Word",
+            Sh1addUw { .. } => "Shift Unsigned Word Left by 1 and Add",
+            Sh2addUw { .. } => "Shift Unsigned Word Left by 2 and Add",
+            Sh3addUw { .. } => "Shift Unsigned Word Left by 3 and Add",
+            Rolw { .. } => "Rotate Left Word",
+            Rorw { .. } => "Rotate Right Word",
+            Clzw { .. } => "Count Leading Zero Bits in Word",
+            Ctzw { .. } => "Count Trailing Zero Bits in Word",
+            Cpopw { .. } => "Count Set Bits in Word",
+            SlliUw { .. } => "Shift Left Logical Immediate Unsigned Word",
+            Roriw { .. } => "Rotate Right Immediate Word",
+            Pack { .. } => "Pack Low Halves",
+            Packh { .. } => "Pack Low Bytes",
+            Packw { .. } => "Pack Low Halfwords of Words",
+            Xperm4 { .. } => "Crossbar Permutation of Nibbles",
+            Xperm8 { .. } => "Crossbar Permutation of Bytes",
+            Brev8 { .. } => "Reverse Bits in Bytes",
+            Zip { .. } => "Bit Interleave",
+            Unzip { .. } => "Bit Deinterleave",
+            Aes32esi { .. } => "AES Final Round Encryption (RV32)",
+            Aes32esmi { .. } => "AES Middle Round Encryption (RV32)",
+            Aes32dsi { .. } => "AES Final Round Decryption (RV32)",
+            Aes32dsmi { .. } => "AES Middle Round Decryption (RV32)",
+            Aes64es { .. } => "AES Final Round Encryption (RV64)",
+            Aes64esm { .. } => "AES Middle Round Encryption (RV64)",
+            Aes64ds { .. } => "AES Final Round Decryption (RV64)",
+            Aes64dsm { .. } => "AES Middle Round Decryption (RV64)",
+            Aes64ks1i { .. } => "AES Key Schedule Instruction 1",
+            Aes64ks2 { .. } => "AES Key Schedule Instruction 2",
+            Aes64im { .. } => "AES Inverse MixColumns",
+            Sha256sig0 { .. } => "SHA2-256 Sigma0",
+            Sha256sig1 { .. } => "SHA2-256 Sigma1",
+            Sha256sum0 { .. } => "SHA2-256 Sum0",
+            Sha256sum1 { .. } => "SHA2-256 Sum1",
+            Sha512sig0h { .. } => "SHA2-512 Sigma0 High (RV32)",
+            Sha512sig0l { .. } => "SHA2-512 Sigma0 Low (RV32)",
+            Sha512sig1h { .. } => "SHA2-512 Sigma1 High (RV32)",
+            Sha512sig1l { .. } => "SHA2-512 Sigma1 Low (RV32)",
+            Sha512sum0r { .. } => "SHA2-512 Sum0 (RV32)",
+            Sha512sum1r { .. } => "SHA2-512 Sum1 (RV32)",
+            Sha512sig0 { .. } => "SHA2-512 Sigma0",
+            Sha512sig1 { .. } => "SHA2-512 Sigma1",
+            Sha512sum0 { .. } => "SHA2-512 Sum0",
+            Sha512sum1 { .. } => "SHA2-512 Sum1",
+            Sm4ed { .. } => "SM4 Encrypt/Decrypt Round",
+            Sm4ks { .. } => "SM4 Key Schedule",
+            Sm3p0 { .. } => "SM3 P0 Transform",
+            Sm3p1 { .. } => "SM3 P1 Transform",
+            Mul { .. } => "Multiplication",
+            Mulh { .. } => "Multiply High Signed",
+            Mulhsu { .. } => "Multiply High Signed with Unsigned",
+            Mulhu { .. } => "Multiply High Unsigned",
+            Div { .. } => "Divide Signed",
+            Divu { .. } => "Divide Unsigned",
+            Rem { .. } => "Remainder Signed",
+            Remu { .. } => "Remainder Unsigned",
+            Mulw { .. } => "Multiply Word",
+            Divw { .. } => "Divide Word",
+            Divuw { .. } => "Divide Unsigned Word",
+            Remw { .. } => "Remainder Word",
+            Remuw { .. } => "Remainder Unsigned Word",
+            LrW { .. } => "Load Reserved Word",
+            ScW { .. } => "Store Conditional Word",
+            AmoswapW { .. } => "Atomic Swap",
+            AmoaddW { .. } => "Atomic Add",
+            AmoxorW { .. } => "Atomic XOR",
+            AmoandW { .. } => "Atomic AND",
+            AmoorW { .. } => "Atomic OR",
+            AmominW { .. } => "Atomic Minimum",
+            AmomaxW { .. } => "Atomic Maximum",
+            AmominuW { .. } => "Atomic Unsigned Minimum",
+            AmomaxuW { .. } => "Atomic Unsigned Maximum",
+            LrD { .. } => "Load Reserved Double-word",
+            ScD { .. } => "Store Conditional Double-word",
+            AmoswapD { .. } => "Atomic Swap Double-word",
+            AmoaddD { .. } => "Atomic Add Double-word",
+            AmoxorD { .. } => "Atomic XOR Double-word",
+            AmoandD { .. } => "Atomic AND Double-word",
+            AmoorD { .. } => "Atomic OR Double-word",
+            AmominD { .. } => "Atomic Minimum Double-word",
+            AmomaxD { .. } => "Atomic Maximum Double-word",
+            AmominuD { .. } => "Atomic Unsigned Minimum Double-word",
+            AmomaxuD { .. } => "Atomic Unsigned Maximum Double-word",
+            FmaddS { .. } => "Floating Point Fused Multiply-Add",
+            FmaddD { .. } => "Double Floating Point Fused Multiply-Add",
+            FmsubS { .. } => "Floating Point Fused Multiply-Subtract",
+            FmsubD { .. } => "Double Floating Point Fused Multiply-Subtract",
+            FnmsubS { .. } => "Floating Point Negative Fused Multiply-Subtract",
+            FnmsubD { .. } => "Double Floating Point Negative Fused Multiply-Subtract",
+            FnmaddS { .. } => "Floating Point Negative Fused Multiply-Add",
+            FnmaddD { .. } => "Double Floating Point Negative Fused Multiply-Add",
+            FaddS { .. } => "Floating Point Addition",
+            FaddD { .. } => "Double Floating Point Addition",
+            FsubS { .. } => "Floating Point Subtraction",
+            FsubD { .. } => "Double Floating Point Subtraction",
+            FmulS { .. } => "Floating Point Multiplication",
+            FmulD { .. } => "Double Floating Point Multiplication",
+            FdivS { .. } => "Floating Point Division",
+            FdivD { .. } => "Double Floating Point Division",
+            FsqrtS { .. } => "Floating Point Square Root",
+            FsqrtD { .. } => "Double Floating Point Square Root",
+            FsgnjS { .. } => "Floating Point Sign Injection",
+            FsgnjD { .. } => "Double Sign Injection",
+            FsgnjnS { .. } => "Floating Point Negated Sign Injection",
+            FsgnjnD { .. } => "Double Negated Sign Injection",
+            FsgnjxS { .. } => "Floating Point XOR Sign Injection",
+            FsgnjxD { .. } => "Double XOR Sign Injection",
+            FminS { .. } => "Floating Point Minimum",
+            FminD { .. } => "Double Floating Point Minimum",
+            FmaxS { .. } => "Floating Point Maximum",
+            FmaxD { .. } => "Double Floating Point Maximum",
+            FcvtDS { .. } => "Single to Double Conversion",
+            FcvtSD { .. } => "Double to Single Conversion",
+            FcvtWS { .. } => "Floating Point Conversion to Integer",
+            FcvtWD { .. } => "Double Conversion to Integer",
+            FcvtWuS { .. } => "Floating Point Conversion to Unsigned Integer",
+            FcvtWuD { .. } => "Double Conversion to Unsigned Integer",
+            FcvtSW { .. } => "Integer Conversion to Floating Point",
+            FcvtDW { .. } => "Integer Conversion to Double",
+            FcvtSWu { .. } => "Unsigned Integer Conversion to Floating Point",
+            FcvtDWu { .. } => "Unsigned Integer Conversion to Double",
+            FmvXW { .. } => "Floating Point Move to Integer Register",
+            FmvWX { .. } => "Integer Move to Floating Point Register",
+            FcvtLS { .. } => "Floating Point Conversion to Long",
+            FcvtLuS { .. } => "Floating Point Conversion to Unsigned Long",
+            FcvtSL { .. } => "Long Conversion to Floating Point",
+            FcvtSLu { .. } => "Unsigned Long Conversion to Floating Point",
+            FcvtLD { .. } => "Double Conversion to Long",
+            FcvtLuD { .. } => "Double Conversion to Unsigned Long",
+            FcvtDL { .. } => "Long Conversion to Double",
+            FcvtDLu { .. } => "Unsigned Long Conversion to Double",
+            FmvXD { .. } => "Double Move to Integer Register",
+            FmvDX { .. } => "Integer Move to Double Register",
+            FeqS { .. } => "Floating Point Equal",
+            FeqD { .. } => "Double Equal",
+            FltS { .. } => "Floating Point Less Than",
+            FltD { .. } => "Double Less Than",
+            FleS { .. } => "Floating Point Less Than or Equal",
+            FleD { .. } => "Double Less Than or Equal",
+            FclassS { .. } => "Floating Point Class",
+            FclassD { .. } => "Double Class",
+            Flw { .. } => "Floating Point Load Word",
+            Fld { .. } => "Load Double",
+            Fsw { .. } => "Floating Point Store Word",
+            Fsd { .. } => "Store Double",
+            FmaddH { .. } => "Half Floating Point Fused Multiply-Add",
+            FmsubH { .. } => "Half Floating Point Fused Multiply-Subtract",
+            FnmsubH { .. } => "Half Floating Point Negative Fused Multiply-Subtract",
+            FnmaddH { .. } => "Half Floating Point Negative Fused Multiply-Add",
+            FaddH { .. } => "Half Floating Point Addition",
+            FsubH { .. } => "Half Floating Point Subtraction",
+            FmulH { .. } => "Half Floating Point Multiplication",
+            FdivH { .. } => "Half Floating Point Division",
+            FsqrtH { .. } => "Half Floating Point Square Root",
+            FsgnjH { .. } => "Half Sign Injection",
+            FsgnjnH { .. } => "Half Negated Sign Injection",
+            FsgnjxH { .. } => "Half XOR Sign Injection",
+            FminH { .. } => "Half Floating Point Minimum",
+            FmaxH { .. } => "Half Floating Point Maximum",
+            FcvtWH { .. } => "Half Conversion to Integer",
+            FcvtWuH { .. } => "Half Conversion to Unsigned Integer",
+            FcvtHW { .. } => "Integer Conversion to Half",
+            FcvtHWu { .. } => "Unsigned Integer Conversion to Half",
+            FcvtLH { .. } => "Half Conversion to Long",
+            FcvtLuH { .. } => "Half Conversion to Unsigned Long",
+            FcvtHL { .. } => "Long Conversion to Half",
+            FcvtHLu { .. } => "Unsigned Long Conversion to Half",
+            FmvXH { .. } => "Half Move to Integer Register",
+            FmvHX { .. } => "Integer Move to Half Register",
+            FeqH { .. } => "Half Equal",
+            FltH { .. } => "Half Less Than",
+            FleH { .. } => "Half Less Than or Equal",
+            FclassH { .. } => "Half Class",
+            FcvtSH { .. } => "Half to Single Conversion",
+            FcvtHS { .. } => "Single to Half Conversion",
+            FcvtDH { .. } => "Half to Double Conversion",
+            FcvtHD { .. } => "Double to Half Conversion",
+            Flh { .. } => "Floating Point Load Half-word",
+            Fsh { .. } => "Floating Point Store Half-word",
+            FmaddQ { .. } => "Quad Floating Point Fused Multiply-Add",
+            FmsubQ { .. } => "Quad Floating Point Fused Multiply-Subtract",
+            FnmsubQ { .. } => "Quad Floating Point Negative Fused Multiply-Subtract",
+            FnmaddQ { .. } => "Quad Floating Point Negative Fused Multiply-Add",
+            FaddQ { .. } => "Quad Floating Point Addition",
+            FsubQ { .. } => "Quad Floating Point Subtraction",
+            FmulQ { .. } => "Quad Floating Point Multiplication",
+            FdivQ { .. } => "Quad Floating Point Division",
+            FsqrtQ { .. } => "Quad Floating Point Square Root",
+            FsgnjQ { .. } => "Quad Sign Injection",
+            FsgnjnQ { .. } => "Quad Negated Sign Injection",
+            FsgnjxQ { .. } => "Quad XOR Sign Injection",
+            FminQ { .. } => "Quad Floating Point Minimum",
+            FmaxQ { .. } => "Quad Floating Point Maximum",
+            FcvtWQ { .. } => "Quad Conversion to Integer",
+            FcvtWuQ { .. } => "Quad Conversion to Unsigned Integer",
+            FcvtQW { .. } => "Integer Conversion to Quad",
+            FcvtQWu { .. } => "Unsigned Integer Conversion to Quad",
+            FcvtLQ { .. } => "Quad Conversion to Long",
+            FcvtLuQ { .. } => "Quad Conversion to Unsigned Long",
+            FcvtQL { .. } => "Long Conversion to Quad",
+            FcvtQLu { .. } => "Unsigned Long Conversion to Quad",
+            FeqQ { .. } => "Quad Equal",
+            FltQ { .. } => "Quad Less Than",
+            FleQ { .. } => "Quad Less Than or Equal",
+            FclassQ { .. } => "Quad Class",
+            FcvtSQ { .. } => "Quad to Single Conversion",
+            FcvtQS { .. } => "Single to Quad Conversion",
+            FcvtDQ { .. } => "Quad to Double Conversion",
+            FcvtQD { .. } => "Double to Quad Conversion",
+            FcvtHQ { .. } => "Quad to Half Conversion",
+            FcvtQH { .. } => "Half to Quad Conversion",
+            Flq { .. } => "Load Quad",
+            Fsq { .. } => "Store Quad",
+            FliS { .. } => "Floating Point Load Immediate",
+            FliD { .. } => "Double Load Immediate",
+            FliH { .. } => "Half Load Immediate",
+            FliQ { .. } => "Quad Load Immediate",
+            FminmS { .. } => "Floating Point Minimum, NaN Propagating",
+            FmaxmS { .. } => "Floating Point Maximum, NaN Propagating",
+            FminmD { .. } => "Double Floating Point Minimum, NaN Propagating",
+            FmaxmD { .. } => "Double Floating Point Maximum, NaN Propagating",
+            FminmH { .. } => "Half Floating Point Minimum, NaN Propagating",
+            FmaxmH { .. } => "Half Floating Point Maximum, NaN Propagating",
+            FminmQ { .. } => "Quad Floating Point Minimum, NaN Propagating",
+            FmaxmQ { .. } => "Quad Floating Point Maximum, NaN Propagating",
+            FroundS { .. } => "Floating Point Round to Integer",
+            FroundnxS { .. } => "Floating Point Round to Integer, Inexact",
+            FroundD { .. } => "Double Floating Point Round to Integer",
+            FroundnxD { .. } => "Double Floating Point Round to Integer, Inexact",
+            FroundH { .. } => "Half Floating Point Round to Integer",
+            FroundnxH { .. } => "Half Floating Point Round to Integer, Inexact",
+            FroundQ { .. } => "Quad Floating Point Round to Integer",
+            FroundnxQ { .. } => "Quad Floating Point Round to Integer, Inexact",
+            FleqS { .. } => "Floating Point Quiet Less Than or Equal",
+            FltqS { .. } => "Floating Point Quiet Less Than",
+            FleqD { .. } => "Double Quiet Less Than or Equal",
+            FltqD { .. } => "Double Quiet Less Than",
+            FleqH { .. } => "Half Quiet Less Than or Equal",
+            FltqH { .. } => "Half Quiet Less Than",
+            FleqQ { .. } => "Quad Quiet Less Than or Equal",
+            FltqQ { .. } => "Quad Quiet Less Than",
+            FcvtmodWD { .. } => "Double Modular Conversion to Integer",
+            FmvhXD { .. } => "Double Upper Half Move to Integer Register",
+            FmvhXQ { .. } => "Quad Upper Half Move to Integer Register",
+            FmvpDX { .. } => "Integer Pair Move to Double Register",
+            FmvpQX { .. } => "Integer Pair Move to Quad Register",
+            Vsetvli { .. } => "Set Vector Length and Type",
+            Vsetivli { .. } => "Set Vector Length and Type, Immediate Length",
+            Vsetvl { .. } => "Set Vector Length and Type from Register",
+            VectorLoad { addressing, nf, .. } => vector::memory_description(true, *addressing, *nf),
+            VectorStore { addressing, nf, .. } => vector::memory_description(false, *addressing, *nf),
+            VectorArith { op, .. } => op.description(),
+            VectorUnary { op, .. } => op.description(),
+        }
+    }
+
+    /// Returns the operand fields of the instruction in encoding order
+    /// (rd, rs1, rs2, rs3, immediate, rounding mode, aq/rl, CSR, byte select, round number,
+    /// vector mask, vector type or FLI constant)
+    pub fn fields(&self) -> Vec<Field> {
+        use Field::*;
+        use Instruction::*;
+        use Register::{F, X};
+        match *self {
+            Lb { rd, rs1, imm } | Lh { rd, rs1, imm } | Lw { rd, rs1, imm }
+            | Lbu { rd, rs1, imm } | Lhu { rd, rs1, imm } | Addi { rd, rs1, imm }
+            | Slti { rd, rs1, imm } | Sltiu { rd, rs1, imm } | Xori { rd, rs1, imm }
+            | Ori { rd, rs1, imm } | Andi { rd, rs1, imm } | Jalr { rd, rs1, imm }
+            | Lwu { rd, rs1, imm } | Ld { rd, rs1, imm } | Addiw { rd, rs1, imm } => {
+                vec![Rd(X(rd)), Rs1(X(rs1)), Imm(imm)]
+            }
+            Sb { rs1, rs2, imm } | Sh { rs1, rs2, imm } | Sw { rs1, rs2, imm }
+            | Beq { rs1, rs2, imm } | Bne { rs1, rs2, imm } | Blt { rs1, rs2, imm }
+            | Bge { rs1, rs2, imm } | Bltu { rs1, rs2, imm } | Bgeu { rs1, rs2, imm }
+            | Sd { rs1, rs2, imm } => {
+                vec![Rs1(X(rs1)), Rs2(X(rs2)), Imm(imm)]
+            }
+            Lui { rd, imm } | Auipc { rd, imm } | Jal { rd, imm } => vec![Rd(X(rd)), Imm(imm)],
+            Slli { rd, rs1, shamt } | Srli { rd, rs1, shamt } | Srai { rd, rs1, shamt }
+            | Slliw { rd, rs1, shamt } | Srliw { rd, rs1, shamt } | Sraiw { rd, rs1, shamt } => {
+                vec![Rd(X(rd)), Rs1(X(rs1)), Shamt(shamt)]
+            }
+            Fence { pred, succ } => vec![Pred(pred), Succ(succ)],
+            Sh1add { rd, rs1, rs2 } | Sh2add { rd, rs1, rs2 } | Sh3add { rd, rs1, rs2 }
+            | Andn { rd, rs1, rs2 } | Orn { rd, rs1, rs2 } | Xnor { rd, rs1, rs2 }
+            | Max { rd, rs1, rs2 } | Maxu { rd, rs1, rs2 } | Min { rd, rs1, rs2 }
+            | Minu { rd, rs1, rs2 } | Rol { rd, rs1, rs2 } | Ror { rd, rs1, rs2 }
+            | Clmul { rd, rs1, rs2 } | Clmulh { rd, rs1, rs2 } | Clmulr { rd, rs1, rs2 }
+            | Bclr { rd, rs1, rs2 } | Bext { rd, rs1, rs2 } | Binv { rd, rs1, rs2 }
+            | Bset { rd, rs1, rs2 } | AddUw { rd, rs1, rs2 } | Sh1addUw { rd, rs1, rs2 }
+            | Sh2addUw { rd, rs1, rs2 } | Sh3addUw { rd, rs1, rs2 } | Rolw { rd, rs1, rs2 }
+            | Rorw { rd, rs1, rs2 } | Pack { rd, rs1, rs2 } | Packh { rd, rs1, rs2 }
+            | Packw { rd, rs1, rs2 } | Xperm4 { rd, rs1, rs2 } | Xperm8 { rd, rs1, rs2 }
+            | Aes64es { rd, rs1, rs2 } | Aes64esm { rd, rs1, rs2 } | Aes64ds { rd, rs1, rs2 }
+            | Aes64dsm { rd, rs1, rs2 } | Aes64ks2 { rd, rs1, rs2 } | Sha512sig0h { rd, rs1, rs2 }
+            | Sha512sig0l { rd, rs1, rs2 } | Sha512sig1h { rd, rs1, rs2 } | Sha512sig1l { rd, rs1, rs2 }
+            | Sha512sum0r { rd, rs1, rs2 } | Sha512sum1r { rd, rs1, rs2 } => {
+                vec![Rd(X(rd)), Rs1(X(rs1)), Rs2(X(rs2))]
+            }
+            Clz { rd, rs1 } | Ctz { rd, rs1 } | Cpop { rd, rs1 } | SextB { rd, rs1 }
+            | SextH { rd, rs1 } | ZextH { rd, rs1 } | OrcB { rd, rs1 } | Rev8 { rd, rs1 }
+            | Clzw { rd, rs1 } | Ctzw { rd, rs1 } | Cpopw { rd, rs1 } | Brev8 { rd, rs1 }
+            | Zip { rd, rs1 } | Unzip { rd, rs1 } | Aes64im { rd, rs1 } | Sha256sig0 { rd, rs1 }
+            | Sha256sig1 { rd, rs1 } | Sha256sum0 { rd, rs1 } | Sha256sum1 { rd, rs1 }
+            | Sha512sig0 { rd, rs1 } | Sha512sig1 { rd, rs1 } | Sha512sum0 { rd, rs1 }
+            | Sha512sum1 { rd, rs1 } | Sm3p0 { rd, rs1 } | Sm3p1 { rd, rs1 } => {
+                vec![Rd(X(rd)), Rs1(X(rs1))]
+            }
+            Rori { rd, rs1, shamt } | Bclri { rd, rs1, shamt } | Bexti { rd, rs1, shamt }
+            | Binvi { rd, rs1, shamt } | Bseti { rd, rs1, shamt } | SlliUw { rd, rs1, shamt }
+            | Roriw { rd, rs1, shamt } => {
+                vec![Rd(X(rd)), Rs1(X(rs1)), Shamt(shamt)]
+            }
+            Aes32esi { rd, rs1, rs2, bs } | Aes32esmi { rd, rs1, rs2, bs } | Aes32dsi { rd, rs1, rs2, bs }
+            | Aes32dsmi { rd, rs1, rs2, bs } | Sm4ed { rd, rs1, rs2, bs } | Sm4ks { rd, rs1, rs2, bs } => {
+                vec![Rd(X(rd)), Rs1(X(rs1)), Rs2(X(rs2)), Bs(bs)]
+            }
+            Aes64ks1i { rd, rs1, rnum } => vec![Rd(X(rd)), Rs1(X(rs1)), Rnum(rnum)],
+            FenceTso | Pause | FenceI | Ecall | Ebreak => vec![],
+            Sret | Mret | Mnret | Dret | Wfi | SfenceWInval | SfenceInvalIr => vec![],
+            SfenceVma { rs1, rs2 } | SinvalVma { rs1, rs2 } => vec![Rs1(X(rs1)), Rs2(X(rs2))],
+            Csrrw { rd, rs1, csr } | Csrrs { rd, rs1, csr } | Csrrc { rd, rs1, csr } => {
+                vec![Rd(X(rd)), Rs1(X(rs1)), Csr(csr)]
+            }
+            Csrrwi { rd, uimm, csr } | Csrrsi { rd, uimm, csr } | Csrrci { rd, uimm, csr } => {
+                vec![Rd(X(rd)), Imm(uimm as i32), Csr(csr)]
+            }
+            Add { rd, rs1, rs2 } | Sub { rd, rs1, rs2 } | Sll { rd, rs1, rs2 }
+            | Slt { rd, rs1, rs2 } | Sltu { rd, rs1, rs2 } | Xor { rd, rs1, rs2 }
+            | Srl { rd, rs1, rs2 } | Sra { rd, rs1, rs2 } | Or { rd, rs1, rs2 }
+            | And { rd, rs1, rs2 } | Mul { rd, rs1, rs2 } | Mulh { rd, rs1, rs2 }
+            | Mulhsu { rd, rs1, rs2 } | Mulhu { rd, rs1, rs2 } | Div { rd, rs1, rs2 }
+            | Divu { rd, rs1, rs2 } | Rem { rd, rs1, rs2 } | Remu { rd, rs1, rs2 }
+            | Addw { rd, rs1, rs2 } | Subw { rd, rs1, rs2 } | Sllw { rd, rs1, rs2 }
+            | Srlw { rd, rs1, rs2 } | Sraw { rd, rs1, rs2 } | Mulw { rd, rs1, rs2 }
+            | Divw { rd, rs1, rs2 } | Divuw { rd, rs1, rs2 } | Remw { rd, rs1, rs2 }
+            | Remuw { rd, rs1, rs2 } => {
+                vec![Rd(X(rd)), Rs1(X(rs1)), Rs2(X(rs2))]
+            }
+            ScW { rd, rs1, rs2, aq, rl } | AmoswapW { rd, rs1, rs2, aq, rl } | AmoaddW { rd, rs1, rs2, aq, rl }
+            | AmoxorW { rd, rs1, rs2, aq, rl } | AmoandW { rd, rs1, rs2, aq, rl } | AmoorW { rd, rs1, rs2, aq, rl }
+            | AmominW { rd, rs1, rs2, aq, rl } | AmomaxW { rd, rs1, rs2, aq, rl } | AmominuW { rd, rs1, rs2, aq, rl }
+            | AmomaxuW { rd, rs1, rs2, aq, rl }
+            | ScD { rd, rs1, rs2, aq, rl } | AmoswapD { rd, rs1, rs2, aq, rl } | AmoaddD { rd, rs1, rs2, aq, rl }
+            | AmoxorD { rd, rs1, rs2, aq, rl } | AmoandD { rd, rs1, rs2, aq, rl } | AmoorD { rd, rs1, rs2, aq, rl }
+            | AmominD { rd, rs1, rs2, aq, rl } | AmomaxD { rd, rs1, rs2, aq, rl } | AmominuD { rd, rs1, rs2, aq, rl }
+            | AmomaxuD { rd, rs1, rs2, aq, rl } => {
+                vec![Rd(X(rd)), Rs1(X(rs1)), Rs2(X(rs2)), Aq(aq), Rl(rl)]
+            }
+            LrW { rd, rs1, aq, rl } | LrD { rd, rs1, aq, rl } => vec![Rd(X(rd)), Rs1(X(rs1)), Aq(aq), Rl(rl)],
+            FmaddS { rd, rs1, rs2, rs3, rm } | FmaddD { rd, rs1, rs2, rs3, rm }
+            | FmsubS { rd, rs1, rs2, rs3, rm } | FmsubD { rd, rs1, rs2, rs3, rm }
+            | FnmsubS { rd, rs1, rs2, rs3, rm } | FnmsubD { rd, rs1, rs2, rs3, rm }
+            | FnmaddS { rd, rs1, rs2, rs3, rm } | FnmaddD { rd, rs1, rs2, rs3, rm }
+            | FmaddH { rd, rs1, rs2, rs3, rm } | FmsubH { rd, rs1, rs2, rs3, rm } | FnmsubH { rd, rs1, rs2, rs3, rm }
+            | FnmaddH { rd, rs1, rs2, rs3, rm }
+            | FmaddQ { rd, rs1, rs2, rs3, rm } | FmsubQ { rd, rs1, rs2, rs3, rm } | FnmsubQ { rd, rs1, rs2, rs3, rm }
+            | FnmaddQ { rd, rs1, rs2, rs3, rm } => {
+                vec![Rd(F(rd)), Rs1(F(rs1)), Rs2(F(rs2)), Rs3(F(rs3)), Rm(rm)]
+            }
+            FaddS { rd, rs1, rs2, rm } | FaddD { rd, rs1, rs2, rm }
+            | FsubS { rd, rs1, rs2, rm } | FsubD { rd, rs1, rs2, rm }
+            | FmulS { rd, rs1, rs2, rm } | FmulD { rd, rs1, rs2, rm }
+            | FdivS { rd, rs1, rs2, rm } | FdivD { rd, rs1, rs2, rm }
+            | FaddH { rd, rs1, rs2, rm } | FsubH { rd, rs1, rs2, rm } | FmulH { rd, rs1, rs2, rm }
+            | FdivH { rd, rs1, rs2, rm }
+            | FaddQ { rd, rs1, rs2, rm } | FsubQ { rd, rs1, rs2, rm } | FmulQ { rd, rs1, rs2, rm }
+            | FdivQ { rd, rs1, rs2, rm } => {
+                vec![Rd(F(rd)), Rs1(F(rs1)), Rs2(F(rs2)), Rm(rm)]
+            }
+            FsqrtS { rd, rs1, rm } | FsqrtD { rd, rs1, rm }
+            | FcvtDS { rd, rs1, rm } | FcvtSD { rd, rs1, rm }
+            | FsqrtH { rd, rs1, rm } | FcvtSH { rd, rs1, rm } | FcvtHS { rd, rs1, rm }
+            | FcvtDH { rd, rs1, rm } | FcvtHD { rd, rs1, rm }
+            | FsqrtQ { rd, rs1, rm } | FcvtSQ { rd, rs1, rm } | FcvtQS { rd, rs1, rm }
+            | FcvtDQ { rd, rs1, rm } | FcvtQD { rd, rs1, rm } | FcvtHQ { rd, rs1, rm }
+            | FcvtQH { rd, rs1, rm } => {
+                vec![Rd(F(rd)), Rs1(F(rs1)), Rm(rm)]
+            }
+            FsgnjS { rd, rs1, rs2 } | FsgnjD { rd, rs1, rs2 }
+            | FsgnjnS { rd, rs1, rs2 } | FsgnjnD { rd, rs1, rs2 }
+            | FsgnjxS { rd, rs1, rs2 } | FsgnjxD { rd, rs1, rs2 }
+            | FminS { rd, rs1, rs2 } | FminD { rd, rs1, rs2 }
+            | FmaxS { rd, rs1, rs2 } | FmaxD { rd, rs1, rs2 }
+            | FsgnjH { rd, rs1, rs2 } | FsgnjnH { rd, rs1, rs2 } | FsgnjxH { rd, rs1, rs2 }
+            | FminH { rd, rs1, rs2 } | FmaxH { rd, rs1, rs2 }
+            | FsgnjQ { rd, rs1, rs2 } | FsgnjnQ { rd, rs1, rs2 } | FsgnjxQ { rd, rs1, rs2 }
+            | FminQ { rd, rs1, rs2 } | FmaxQ { rd, rs1, rs2 } => {
+                vec![Rd(F(rd)), Rs1(F(rs1)), Rs2(F(rs2))]
+            }
+            FcvtWS { rd, rs1, rm } | FcvtWD { rd, rs1, rm }
+            | FcvtWuS { rd, rs1, rm } | FcvtWuD { rd, rs1, rm }
+            | FcvtLS { rd, rs1, rm } | FcvtLuS { rd, rs1, rm } | FcvtLD { rd, rs1, rm }
+            | FcvtLuD { rd, rs1, rm }
+            | FcvtWH { rd, rs1, rm } | FcvtWuH { rd, rs1, rm } | FcvtLH { rd, rs1, rm }
+            | FcvtLuH { rd, rs1, rm }
+            | FcvtWQ { rd, rs1, rm } | FcvtWuQ { rd, rs1, rm } | FcvtLQ { rd, rs1, rm }
+            | FcvtLuQ { rd, rs1, rm } => {
+                vec![Rd(X(rd)), Rs1(F(rs1)), Rm(rm)]
+            }
+            FcvtSW { rd, rs1, rm } | FcvtDW { rd, rs1, rm }
+            | FcvtSWu { rd, rs1, rm } | FcvtDWu { rd, rs1, rm }
+            | FcvtSL { rd, rs1, rm } | FcvtSLu { rd, rs1, rm } | FcvtDL { rd, rs1, rm }
+            | FcvtDLu { rd, rs1, rm }
+            | FcvtHW { rd, rs1, rm } | FcvtHWu { rd, rs1, rm } | FcvtHL { rd, rs1, rm }
+            | FcvtHLu { rd, rs1, rm }
+            | FcvtQW { rd, rs1, rm } | FcvtQWu { rd, rs1, rm } | FcvtQL { rd, rs1, rm }
+            | FcvtQLu { rd, rs1, rm } => {
+                vec![Rd(F(rd)), Rs1(X(rs1)), Rm(rm)]
+            }
+            FmvXW { rd, rs1 } | FmvXD { rd, rs1 } | FclassS { rd, rs1 } | FclassD { rd, rs1 }
+            | FmvXH { rd, rs1 } | FclassH { rd, rs1 }
+            | FclassQ { rd, rs1 } => {
+                vec![Rd(X(rd)), Rs1(F(rs1))]
+            }
+            FmvWX { rd, rs1 } | FmvDX { rd, rs1 }
+            | FmvHX { rd, rs1 } => vec![Rd(F(rd)), Rs1(X(rs1))],
+            FeqS { rd, rs1, rs2 } | FeqD { rd, rs1, rs2 }
+            | FltS { rd, rs1, rs2 } | FltD { rd, rs1, rs2 }
+            | FleS { rd, rs1, rs2 } | FleD { rd, rs1, rs2 }
+            | FeqH { rd, rs1, rs2 } | FltH { rd, rs1, rs2 } | FleH { rd, rs1, rs2 }
+            | FeqQ { rd, rs1, rs2 } | FltQ { rd, rs1, rs2 } | FleQ { rd, rs1, rs2 } => {
+                vec![Rd(X(rd)), Rs1(F(rs1)), Rs2(F(rs2))]
+            }
+            Flw { rd, rs1, imm } | Fld { rd, rs1, imm }
+            | Flh { rd, rs1, imm }
+            | Flq { rd, rs1, imm } => vec![Rd(F(rd)), Rs1(X(rs1)), Imm(imm)],
+            Fsw { rs1, rs2, imm } | Fsd { rs1, rs2, imm }
+            | Fsh { rs1, rs2, imm }
+            | Fsq { rs1, rs2, imm } => vec![Rs1(X(rs1)), Rs2(F(rs2)), Imm(imm)],
+            FliS { rd, constant } | FliD { rd, constant } | FliH { rd, constant }
+            | FliQ { rd, constant } => vec![Rd(F(rd)), Fli(constant)],
+            FminmS { rd, rs1, rs2 } | FmaxmS { rd, rs1, rs2 } | FminmD { rd, rs1, rs2 }
+            | FmaxmD { rd, rs1, rs2 } | FminmH { rd, rs1, rs2 } | FmaxmH { rd, rs1, rs2 }
+            | FminmQ { rd, rs1, rs2 } | FmaxmQ { rd, rs1, rs2 } => {
+                vec![Rd(F(rd)), Rs1(F(rs1)), Rs2(F(rs2))]
+            }
+            FroundS { rd, rs1, rm } | FroundnxS { rd, rs1, rm } | FroundD { rd, rs1, rm }
+            | FroundnxD { rd, rs1, rm } | FroundH { rd, rs1, rm } | FroundnxH { rd, rs1, rm }
+            | FroundQ { rd, rs1, rm } | FroundnxQ { rd, rs1, rm } => {
+                vec![Rd(F(rd)), Rs1(F(rs1)), Rm(rm)]
+            }
+            FcvtmodWD { rd, rs1 } => vec![Rd(X(rd)), Rs1(F(rs1)), Rm(RoundingMode::Rtz)],
+            FmvhXD { rd, rs1 } | FmvhXQ { rd, rs1 } => vec![Rd(X(rd)), Rs1(F(rs1))],
+            FmvpDX { rd, rs1, rs2 } | FmvpQX { rd, rs1, rs2 } => vec![Rd(F(rd)), Rs1(X(rs1)), Rs2(X(rs2))],
+            FleqS { rd, rs1, rs2 } | FltqS { rd, rs1, rs2 } | FleqD { rd, rs1, rs2 }
+            | FltqD { rd, rs1, rs2 } | FleqH { rd, rs1, rs2 } | FltqH { rd, rs1, rs2 }
+            | FleqQ { rd, rs1, rs2 } | FltqQ { rd, rs1, rs2 } => {
+                vec![Rd(X(rd)), Rs1(F(rs1)), Rs2(F(rs2))]
+            }
+            Vsetvli { rd, rs1, vtype } => vec![Rd(X(rd)), Rs1(X(rs1)), Vtype(vtype)],
+            Vsetivli { rd, uimm, vtype } => vec![Rd(X(rd)), Imm(uimm as i32), Vtype(vtype)],
+            Vsetvl { rd, rs1, rs2 } => vec![Rd(X(rd)), Rs1(X(rs1)), Rs2(X(rs2))],
+            VectorLoad { vd, rs1, addressing, vm, .. } => vector::memory_fields(true, vd, rs1, addressing, vm),
+            VectorStore { vs3, rs1, addressing, vm, .. } => vector::memory_fields(false, vs3, rs1, addressing, vm),
+            VectorArith { op, vd, vs2, src, vm } => vector::arith_fields(op, vd, vs2, src, vm),
+            VectorUnary { op, vd, vs2, vm } => vector::unary_fields(op, vd, vs2, vm),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Instruction::*;
+        let m = self.mnemonic();
+        match *self {
+            Lb { rd, rs1, imm } | Lh { rd, rs1, imm } | Lw { rd, rs1, imm }
+            | Lbu { rd, rs1, imm } | Lhu { rd, rs1, imm } | Lwu { rd, rs1, imm }
+            | Ld { rd, rs1, imm } => {
+                write!(f, "{} x{}, {}(x{})", m, rd, imm, rs1)
+            }
+            Sb { rs1, rs2, imm } | Sh { rs1, rs2, imm } | Sw { rs1, rs2, imm }
+            | Sd { rs1, rs2, imm } => {
+                write!(f, "{} x{}, {}(x{})", m, rs2, imm, rs1)
+            }
+            Beq { rs1, rs2, imm } | Bne { rs1, rs2, imm } | Blt { rs1, rs2, imm }
+            | Bge { rs1, rs2, imm } | Bltu { rs1, rs2, imm } | Bgeu { rs1, rs2, imm } => {
+                write!(f, "{} x{}, x{}, {}", m, rs1, rs2, imm)
+            }
+            Addi { rd, rs1, imm } | Slti { rd, rs1, imm } | Sltiu { rd, rs1, imm }
+            | Xori { rd, rs1, imm } | Ori { rd, rs1, imm } | Andi { rd, rs1, imm }
+            | Jalr { rd, rs1, imm } | Addiw { rd, rs1, imm } => {
+                write!(f, "{} x{}, x{}, {}", m, rd, rs1, imm)
+            }
+            Lui { rd, imm } | Auipc { rd, imm } | Jal { rd, imm } => {
+                write!(f, "{} x{}, {}", m, rd, imm)
+            }
+            Slli { rd, rs1, shamt } | Srli { rd, rs1, shamt } | Srai { rd, rs1, shamt }
+            | Slliw { rd, rs1, shamt } | Srliw { rd, rs1, shamt } | Sraiw { rd, rs1, shamt } => {
+                write!(f, "{} x{}, x{}, {}", m, rd, rs1, shamt)
+            }
+            Fence { pred, succ } => write!(f, "{} {}, {}", m, fence_set(pred), fence_set(succ)),
+            Sh1add { rd, rs1, rs2 } | Sh2add { rd, rs1, rs2 } | Sh3add { rd, rs1, rs2 }
+            | Andn { rd, rs1, rs2 } | Orn { rd, rs1, rs2 } | Xnor { rd, rs1, rs2 }
+            | Max { rd, rs1, rs2 } | Maxu { rd, rs1, rs2 } | Min { rd, rs1, rs2 }
+            | Minu { rd, rs1, rs2 } | Rol { rd, rs1, rs2 } | Ror { rd, rs1, rs2 }
+            | Clmul { rd, rs1, rs2 } | Clmulh { rd, rs1, rs2 } | Clmulr { rd, rs1, rs2 }
+            | Bclr { rd, rs1, rs2 } | Bext { rd, rs1, rs2 } | Binv { rd, rs1, rs2 }
+            | Bset { rd, rs1, rs2 } | AddUw { rd, rs1, rs2 } | Sh1addUw { rd, rs1, rs2 }
+            | Sh2addUw { rd, rs1, rs2 } | Sh3addUw { rd, rs1, rs2 } | Rolw { rd, rs1, rs2 }
+            | Rorw { rd, rs1, rs2 } | Pack { rd, rs1, rs2 } | Packh { rd, rs1, rs2 }
+            | Packw { rd, rs1, rs2 } | Xperm4 { rd, rs1, rs2 } | Xperm8 { rd, rs1, rs2 }
+            | Aes64es { rd, rs1, rs2 } | Aes64esm { rd, rs1, rs2 } | Aes64ds { rd, rs1, rs2 }
+            | Aes64dsm { rd, rs1, rs2 } | Aes64ks2 { rd, rs1, rs2 } | Sha512sig0h { rd, rs1, rs2 }
+            | Sha512sig0l { rd, rs1, rs2 } | Sha512sig1h { rd, rs1, rs2 } | Sha512sig1l { rd, rs1, rs2 }
+            | Sha512sum0r { rd, rs1, rs2 } | Sha512sum1r { rd, rs1, rs2 } => {
+                write!(f, "{} x{}, x{}, x{}", m, rd, rs1, rs2)
+            }
+            Clz { rd, rs1 } | Ctz { rd, rs1 } | Cpop { rd, rs1 } | SextB { rd, rs1 }
+            | SextH { rd, rs1 } | ZextH { rd, rs1 } | OrcB { rd, rs1 } | Rev8 { rd, rs1 }
+            | Clzw { rd, rs1 } | Ctzw { rd, rs1 } | Cpopw { rd, rs1 } | Brev8 { rd, rs1 }
+            | Zip { rd, rs1 } | Unzip { rd, rs1 } | Aes64im { rd, rs1 } | Sha256sig0 { rd, rs1 }
+            | Sha256sig1 { rd, rs1 } | Sha256sum0 { rd, rs1 } | Sha256sum1 { rd, rs1 }
+            | Sha512sig0 { rd, rs1 } | Sha512sig1 { rd, rs1 } | Sha512sum0 { rd, rs1 }
+            | Sha512sum1 { rd, rs1 } | Sm3p0 { rd, rs1 } | Sm3p1 { rd, rs1 } => {
+                write!(f, "{} x{}, x{}", m, rd, rs1)
+            }
+            Rori { rd, rs1, shamt } | Bclri { rd, rs1, shamt } | Bexti { rd, rs1, shamt }
+            | Binvi { rd, rs1, shamt } | Bseti { rd, rs1, shamt } | SlliUw { rd, rs1, shamt }
+            | Roriw { rd, rs1, shamt } => {
+                write!(f, "{} x{}, x{}, {}", m, rd, rs1, shamt)
+            }
+            Aes32esi { rd, rs1, rs2, bs } | Aes32esmi { rd, rs1, rs2, bs } | Aes32dsi { rd, rs1, rs2, bs }
+            | Aes32dsmi { rd, rs1, rs2, bs } | Sm4ed { rd, rs1, rs2, bs } | Sm4ks { rd, rs1, rs2, bs } => {
+                write!(f, "{} x{}, x{}, x{}, {}", m, rd, rs1, rs2, bs)
+            }
+            Aes64ks1i { rd, rs1, rnum } => write!(f, "{} x{}, x{}, {}", m, rd, rs1, rnum),
+            FenceTso | Pause | FenceI | Ecall | Ebreak => f.write_str(m),
+            Sret | Mret | Mnret | Dret | Wfi | SfenceWInval | SfenceInvalIr => f.write_str(m),
+            SfenceVma { rs1, rs2 } | SinvalVma { rs1, rs2 } => write!(f, "{} x{}, x{}", m, rs1, rs2),
+            Csrrw { rd, rs1, csr } | Csrrs { rd, rs1, csr } | Csrrc { rd, rs1, csr } => {
+                write!(f, "{} x{}, {}, x{}", m, rd, csr_text(csr), rs1)
+            }
+            Csrrwi { rd, uimm, csr } | Csrrsi { rd, uimm, csr } | Csrrci { rd, uimm, csr } => {
+                write!(f, "{} x{}, {}, {}", m, rd, csr_text(csr), uimm)
+            }
+            Add { rd, rs1, rs2 } | Sub { rd, rs1, rs2 } | Sll { rd, rs1, rs2 }
+            | Slt { rd, rs1, rs2 } | Sltu { rd, rs1, rs2 } | Xor { rd, rs1, rs2 }
+            | Srl { rd, rs1, rs2 } | Sra { rd, rs1, rs2 } | Or { rd, rs1, rs2 }
+            | And { rd, rs1, rs2 } | Mul { rd, rs1, rs2 } | Mulh { rd, rs1, rs2 }
+            | Mulhsu { rd, rs1, rs2 } | Mulhu { rd, rs1, rs2 } | Div { rd, rs1, rs2 }
+            | Divu { rd, rs1, rs2 } | Rem { rd, rs1, rs2 } | Remu { rd, rs1, rs2 }
+            | Addw { rd, rs1, rs2 } | Subw { rd, rs1, rs2 } | Sllw { rd, rs1, rs2 }
+            | Srlw { rd, rs1, rs2 } | Sraw { rd, rs1, rs2 } | Mulw { rd, rs1, rs2 }
+            | Divw { rd, rs1, rs2 } | Divuw { rd, rs1, rs2 } | Remw { rd, rs1, rs2 }
+            | Remuw { rd, rs1, rs2 } => {
+                write!(f, "{} x{}, x{}, x{}", m, rd, rs1, rs2)
+            }
+            LrW { rd, rs1, aq, rl } | LrD { rd, rs1, aq, rl } => {
+                write!(f, "{}{} x{}, x{}", m, ordering_suffix(aq, rl), rd, rs1)
+            }
+            ScW { rd, rs1, rs2, aq, rl } | AmoswapW { rd, rs1, rs2, aq, rl } | AmoaddW { rd, rs1, rs2, aq, rl }
+            | AmoxorW { rd, rs1, rs2, aq, rl } | AmoandW { rd, rs1, rs2, aq, rl } | AmoorW { rd, rs1, rs2, aq, rl }
+            | AmominW { rd, rs1, rs2, aq, rl } | AmomaxW { rd, rs1, rs2, aq, rl } | AmominuW { rd, rs1, rs2, aq, rl }
+            | AmomaxuW { rd, rs1, rs2, aq, rl }
+            | ScD { rd, rs1, rs2, aq, rl } | AmoswapD { rd, rs1, rs2, aq, rl } | AmoaddD { rd, rs1, rs2, aq, rl }
+            | AmoxorD { rd, rs1, rs2, aq, rl } | AmoandD { rd, rs1, rs2, aq, rl } | AmoorD { rd, rs1, rs2, aq, rl }
+            | AmominD { rd, rs1, rs2, aq, rl } | AmomaxD { rd, rs1, rs2, aq, rl } | AmominuD { rd, rs1, rs2, aq, rl }
+            | AmomaxuD { rd, rs1, rs2, aq, rl } => {
+                write!(f, "{}{} x{}, x{}, x{}", m, ordering_suffix(aq, rl), rd, rs2, rs1)
+            }
+            FmaddS { rd, rs1, rs2, rs3, rm } | FmaddD { rd, rs1, rs2, rs3, rm }
+            | FmsubS { rd, rs1, rs2, rs3, rm } | FmsubD { rd, rs1, rs2, rs3, rm }
+            | FnmsubS { rd, rs1, rs2, rs3, rm } | FnmsubD { rd, rs1, rs2, rs3, rm }
+            | FnmaddS { rd, rs1, rs2, rs3, rm } | FnmaddD { rd, rs1, rs2, rs3, rm }
+            | FmaddH { rd, rs1, rs2, rs3, rm } | FmsubH { rd, rs1, rs2, rs3, rm } | FnmsubH { rd, rs1, rs2, rs3, rm }
+            | FnmaddH { rd, rs1, rs2, rs3, rm }
+            | FmaddQ { rd, rs1, rs2, rs3, rm } | FmsubQ { rd, rs1, rs2, rs3, rm } | FnmsubQ { rd, rs1, rs2, rs3, rm }
+            | FnmaddQ { rd, rs1, rs2, rs3, rm } => {
+                write!(f, "{} f{}, f{}, f{}, f{}, {}", m, rd, rs1, rs2, rs3, rm)
+            }
+            FaddS { rd, rs1, rs2, rm } | FaddD { rd, rs1, rs2, rm }
+            | FsubS { rd, rs1, rs2, rm } | FsubD { rd, rs1, rs2, rm }
+            | FmulS { rd, rs1, rs2, rm } | FmulD { rd, rs1, rs2, rm }
+            | FdivS { rd, rs1, rs2, rm } | FdivD { rd, rs1, rs2, rm }
+            | FaddH { rd, rs1, rs2, rm } | FsubH { rd, rs1, rs2, rm } | FmulH { rd, rs1, rs2, rm }
+            | FdivH { rd, rs1, rs2, rm }
+            | FaddQ { rd, rs1, rs2, rm } | FsubQ { rd, rs1, rs2, rm } | FmulQ { rd, rs1, rs2, rm }
+            | FdivQ { rd, rs1, rs2, rm } => {
+                write!(f, "{} f{}, f{}, f{}, {}", m, rd, rs1, rs2, rm)
+            }
+            FsqrtS { rd, rs1, rm } | FsqrtD { rd, rs1, rm }
+            | FcvtDS { rd, rs1, rm } | FcvtSD { rd, rs1, rm }
+            | FsqrtH { rd, rs1, rm } | FcvtSH { rd, rs1, rm } | FcvtHS { rd, rs1, rm }
+            | FcvtDH { rd, rs1, rm } | FcvtHD { rd, rs1, rm }
+            | FsqrtQ { rd, rs1, rm } | FcvtSQ { rd, rs1, rm } | FcvtQS { rd, rs1, rm }
+            | FcvtDQ { rd, rs1, rm } | FcvtQD { rd, rs1, rm } | FcvtHQ { rd, rs1, rm }
+            | FcvtQH { rd, rs1, rm } => {
+                write!(f, "{} f{}, f{}, {}", m, rd, rs1, rm)
+            }
+            FsgnjS { rd, rs1, rs2 } | FsgnjD { rd, rs1, rs2 }
+            | FsgnjnS { rd, rs1, rs2 } | FsgnjnD { rd, rs1, rs2 }
+            | FsgnjxS { rd, rs1, rs2 } | FsgnjxD { rd, rs1, rs2 }
+            | FminS { rd, rs1, rs2 } | FminD { rd, rs1, rs2 }
+            | FmaxS { rd, rs1, rs2 } | FmaxD { rd, rs1, rs2 }
+            | FsgnjH { rd, rs1, rs2 } | FsgnjnH { rd, rs1, rs2 } | FsgnjxH { rd, rs1, rs2 }
+            | FminH { rd, rs1, rs2 } | FmaxH { rd, rs1, rs2 }
+            | FsgnjQ { rd, rs1, rs2 } | FsgnjnQ { rd, rs1, rs2 } | FsgnjxQ { rd, rs1, rs2 }
+            | FminQ { rd, rs1, rs2 } | FmaxQ { rd, rs1, rs2 } => {
+                write!(f, "{} f{}, f{}, f{}", m, rd, rs1, rs2)
+            }
+            FcvtWS { rd, rs1, rm } | FcvtWD { rd, rs1, rm }
+            | FcvtWuS { rd, rs1, rm } | FcvtWuD { rd, rs1, rm }
+            | FcvtLS { rd, rs1, rm } | FcvtLuS { rd, rs1, rm } | FcvtLD { rd, rs1, rm }
+            | FcvtLuD { rd, rs1, rm }
+            | FcvtWH { rd, rs1, rm } | FcvtWuH { rd, rs1, rm } | FcvtLH { rd, rs1, rm }
+            | FcvtLuH { rd, rs1, rm }
+            | FcvtWQ { rd, rs1, rm } | FcvtWuQ { rd, rs1, rm } | FcvtLQ { rd, rs1, rm }
+            | FcvtLuQ { rd, rs1, rm } => {
+                write!(f, "{} x{}, f{}, {}", m, rd, rs1, rm)
+            }
+            FcvtSW { rd, rs1, rm } | FcvtDW { rd, rs1, rm }
+            | FcvtSWu { rd, rs1, rm } | FcvtDWu { rd, rs1, rm }
+            | FcvtSL { rd, rs1, rm } | FcvtSLu { rd, rs1, rm } | FcvtDL { rd, rs1, rm }
+            | FcvtDLu { rd, rs1, rm }
+            | FcvtHW { rd, rs1, rm } | FcvtHWu { rd, rs1, rm } | FcvtHL { rd, rs1, rm }
+            | FcvtHLu { rd, rs1, rm }
+            | FcvtQW { rd, rs1, rm } | FcvtQWu { rd, rs1, rm } | FcvtQL { rd, rs1, rm }
+            | FcvtQLu { rd, rs1, rm } => {
+                write!(f, "{} f{}, x{}, {}", m, rd, rs1, rm)
+            }
+            FmvXW { rd, rs1 } | FmvXD { rd, rs1 } | FclassS { rd, rs1 } | FclassD { rd, rs1 }
+            | FmvXH { rd, rs1 } | FclassH { rd, rs1 }
+            | FclassQ { rd, rs1 } => {
+                write!(f, "{} x{}, f{}", m, rd, rs1)
+            }
+            FmvWX { rd, rs1 } | FmvDX { rd, rs1 }
+            | FmvHX { rd, rs1 } => write!(f, "{} f{}, x{}", m, rd, rs1),
+            FeqS { rd, rs1, rs2 } | FeqD { rd, rs1, rs2 }
+            | FltS { rd, rs1, rs2 } | FltD { rd, rs1, rs2 }
+            | FleS { rd, rs1, rs2 } | FleD { rd, rs1, rs2 }
+            | FeqH { rd, rs1, rs2 } | FltH { rd, rs1, rs2 } | FleH { rd, rs1, rs2 }
+            | FeqQ { rd, rs1, rs2 } | FltQ { rd, rs1, rs2 } | FleQ { rd, rs1, rs2 } => {
+                write!(f, "{} x{}, f{}, f{}", m, rd, rs1, rs2)
+            }
+            Flw { rd, rs1, imm } | Fld { rd, rs1, imm }
+            | Flh { rd, rs1, imm }
+            | Flq { rd, rs1, imm } => {
+                write!(f, "{} f{}, {}(x{})", m, rd, imm, rs1)
+            }
+            Fsw { rs1, rs2, imm } | Fsd { rs1, rs2, imm }
+            | Fsh { rs1, rs2, imm }
+            | Fsq { rs1, rs2, imm } => {
+                write!(f, "{} f{}, {}(x{})", m, rs2, imm, rs1)
+            }
+            FliS { rd, constant } | FliD { rd, constant } | FliH { rd, constant }
+            | FliQ { rd, constant } => write!(f, "{} f{}, {}", m, rd, constant),
+            FminmS { rd, rs1, rs2 } | FmaxmS { rd, rs1, rs2 } | FminmD { rd, rs1, rs2 }
+            | FmaxmD { rd, rs1, rs2 } | FminmH { rd, rs1, rs2 } | FmaxmH { rd, rs1, rs2 }
+            | FminmQ { rd, rs1, rs2 } | FmaxmQ { rd, rs1, rs2 } => {
+                write!(f, "{} f{}, f{}, f{}", m, rd, rs1, rs2)
+            }
+            FroundS { rd, rs1, rm } | FroundnxS { rd, rs1, rm } | FroundD { rd, rs1, rm }
+            | FroundnxD { rd, rs1, rm } | FroundH { rd, rs1, rm } | FroundnxH { rd, rs1, rm }
+            | FroundQ { rd, rs1, rm } | FroundnxQ { rd, rs1, rm } => {
+                write!(f, "{} f{}, f{}, {}", m, rd, rs1, rm)
+            }
+            FcvtmodWD { rd, rs1 } => write!(f, "{} x{}, f{}, {}", m, rd, rs1, RoundingMode::Rtz),
+            FmvhXD { rd, rs1 } | FmvhXQ { rd, rs1 } => write!(f, "{} x{}, f{}", m, rd, rs1),
+            FmvpDX { rd, rs1, rs2 } | FmvpQX { rd, rs1, rs2 } => write!(f, "{} f{}, x{}, x{}", m, rd, rs1, rs2),
+            FleqS { rd, rs1, rs2 } | FltqS { rd, rs1, rs2 } | FleqD { rd, rs1, rs2 }
+            | FltqD { rd, rs1, rs2 } | FleqH { rd, rs1, rs2 } | FltqH { rd, rs1, rs2 }
+            | FleqQ { rd, rs1, rs2 } | FltqQ { rd, rs1, rs2 } => {
+                write!(f, "{} x{}, f{}, f{}", m, rd, rs1, rs2)
+            }
+            Vsetvli { rd, rs1, vtype } => write!(f, "{} x{}, x{}, {}", m, rd, rs1, vtype),
+            Vsetivli { rd, uimm, vtype } => write!(f, "{} x{}, {}, {}", m, rd, uimm, vtype),
+            Vsetvl { rd, rs1, rs2 } => write!(f, "{} x{}, x{}, x{}", m, rd, rs1, rs2),
+            VectorLoad { vd, rs1, addressing, eew, nf, vm } => {
+                vector::fmt_memory(f, true, vd, rs1, addressing, eew, nf, vm)
+            }
+            VectorStore { vs3, rs1, addressing, eew, nf, vm } => {
+                vector::fmt_memory(f, false, vs3, rs1, addressing, eew, nf, vm)
+            }
+            VectorArith { op, vd, vs2, src, vm } => vector::fmt_arith(f, op, vd, vs2, src, vm),
+            VectorUnary { op, vd, vs2, vm } => vector::fmt_unary(f, op, vd, vs2, vm),
+        }
+    }
+}
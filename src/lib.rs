@@ -1,13 +1,38 @@
 #![allow(warnings, unused)]
 
-/// Takes a RISC-V 32-bit binary instruction and returns the decoded assembly instruction
+mod compressed;
+mod config;
+mod csr;
+mod debug;
+mod encoder;
+mod error;
+mod instruction;
+mod observer;
+mod parser;
+mod vector;
+
+pub use compressed::{compress, compression_summary, decode_compressed, expand_compressed, CompressedInstruction, CompressionSummary, Extensions};
+pub use config::{DecoderConfig, Xlen};
+pub use csr::{csr_name, csr_number};
+pub use debug::{abstract_command_decoder, decode_abstract_command, AbstractCommand};
+pub use encoder::encode;
+pub use error::{DecodeError, EncodeError, ParseError, ParseErrorKind};
+pub use instruction::{Field, FliConstant, Instruction, Register, RoundingMode};
+pub use observer::{decode_with_observer, DecodeObserver, VerboseObserver};
+pub use vector::{Lmul, Sew, VectorAddressing, VectorOp, VectorOperand, VectorUnaryOp, Vtype};
+
+/// Splits a string of "0"/"1" characters into the vector form read by
+/// `instruction_decoder` and `decode_instruction`, one string per bit
 ///
 /// # Examples:
 ///
-/// let mut instr = Vec::new();
-/// instr = ["1", "1", "1", "1", "1", "1", "1", "0", "0", "0", "0", "0", "0", "0", "0", "1", "0", "0", "0", "0", "0", "0", "0", "1", "0", "0", "0", "1", "0", "0", "1", "1"];
-/// assert_eq!("Add Immediate (ADDI) instruction decoded", rv_decoder::instruction_decoder(instr));
-
+/// ```
+/// use rv_decoder::{convert_binary_string_to_vector, instruction_decoder};
+///
+/// let instr = convert_binary_string_to_vector("00000000010000000000001000010011");
+/// assert_eq!(instr.len(), 32);
+/// assert_eq!(instruction_decoder(instr), "ADDI x4, x0, 4");
+/// ```
 pub fn convert_binary_string_to_vector(binary_string: &str) -> Vec<String> {
     let mut vector = Vec::new();
     for i in 0..binary_string.len() {
@@ -16,38 +41,171 @@ pub fn convert_binary_string_to_vector(binary_string: &str) -> Vec<String> {
     return vector;
   }
 
-fn rm_decoder(rm_slice_joined: &str) -> String {
-    match rm_slice_joined {
-        "000" => {
-            return "RNE".to_string(); // Round to nearest, ties to even
-        }
-        "001" => {
-            return "RTZ".to_string(); // Round towards zero
+/// Extracts the bit field `instr[hi:lo]`
+fn bits(instr: u32, hi: u32, lo: u32) -> u32 {
+    (instr >> lo) & (u32::MAX >> (31 - (hi - lo)))
+}
+
+/// Sign-extends the low `width` bits of `value`
+fn sign_extend(value: u32, width: u32) -> i32 {
+    ((value << (32 - width)) as i32) >> (32 - width)
+}
+
+/*
+ * Immediate extraction, one function per instruction format. Each returns the
+ * sign-extended immediate exactly as the assembler writes it: byte offsets for
+ * I/S/B/J-type and the raw 20-bit field for U-type.
+ */
+
+/// I-type: imm[11:0] = instr[31:20]
+fn i_imm(instr: u32) -> i32 {
+    sign_extend(bits(instr, 31, 20), 12)
+}
+
+/// S-type: imm[11:5] = instr[31:25], imm[4:0] = instr[11:7]
+fn s_imm(instr: u32) -> i32 {
+    sign_extend(bits(instr, 31, 25) << 5 | bits(instr, 11, 7), 12)
+}
+
+/// B-type: imm[12|10:5] = instr[31:25], imm[4:1|11] = instr[11:7]
+fn b_imm(instr: u32) -> i32 {
+    let imm = bits(instr, 31, 31) << 12     // imm [12]
+        | bits(instr, 7, 7) << 11           // imm [11]
+        | bits(instr, 30, 25) << 5          // imm [10:5]
+        | bits(instr, 11, 8) << 1;          // imm [4:1]
+    sign_extend(imm, 13)
+}
+
+/// U-type: imm[31:12] = instr[31:12], returned as the 20-bit field value
+fn u_imm(instr: u32) -> i32 {
+    sign_extend(bits(instr, 31, 12), 20)
+}
+
+/// J-type: imm[20|10:1|11|19:12] = instr[31:12]
+fn j_imm(instr: u32) -> i32 {
+    let imm = bits(instr, 31, 31) << 20     // imm [20]
+        | bits(instr, 19, 12) << 12         // imm [19:12]
+        | bits(instr, 20, 20) << 11         // imm [11]
+        | bits(instr, 30, 21) << 1;         // imm [10:1]
+    sign_extend(imm, 21)
+}
+
+fn rm_decoder(instr: u32, rm: u32) -> Result<RoundingMode, DecodeError> {
+    match rm {
+        0b000 => {
+            return Ok(RoundingMode::Rne); // Round to nearest, ties to even
         }
-        "010" => {
-            return "RDN".to_string(); // Round down (towards negative infinity)
+        0b001 => {
+            return Ok(RoundingMode::Rtz); // Round towards zero
         }
-        "011" => {
-            return "RUP".to_string(); // Round up (towards positive infinity)
+        0b010 => {
+            return Ok(RoundingMode::Rdn); // Round down (towards negative infinity)
         }
-        "100" => {
-            return "RMM".to_string(); // Round to nearest, ties to max magnitude
+        0b011 => {
+            return Ok(RoundingMode::Rup); // Round up (towards positive infinity)
         }
-        "101" => {
-            return "Invalid".to_string();
+        0b100 => {
+            return Ok(RoundingMode::Rmm); // Round to nearest, ties to max magnitude
         }
-        "110" => {
-            return "Invalid".to_string();
+        0b111 => {
+            return Ok(RoundingMode::Dyn); // Dynamic rounding mode 
         }
-        "111" => {
-            return "DYN".to_string(); // Dynamic rounding mode 
+        _ => {
+            return Err(DecodeError::ReservedRoundingMode { word: instr, rm });
         }
-        &_ => todo!()
     }
 }
 
 
+/// Takes a RISC-V 32-bit binary instruction and returns the decoded assembly instruction
+///
+/// The returned text is the `Display` form of the `Instruction` produced by
+/// `decode_instruction`. A 16 bit vector is decoded as a compressed
+/// instruction and returns the `Display` form of `CompressedInstruction`.
+///
+/// # Panics
+///
+/// Panics if the instruction cannot be decoded. Use `decode_instruction`,
+/// `decode` or `decode_compressed` to get a `DecodeError` instead.
 pub fn instruction_decoder(instr: Vec<String>) -> String {
+    if instr.len() == 16 {
+        let parcel = match word_from_bit_strings(&instr) {
+            Ok(parcel) => parcel as u16,
+            Err(err) => panic!("{}", err),
+        };
+        match decode_compressed(parcel) {
+            Ok(decoded) => return decoded.to_string(),
+            Err(err) => panic!("{}", err),
+        }
+    }
+    match decode_instruction(instr) {
+        Ok(decoded) => return decoded.to_string(),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Takes a RISC-V 32-bit binary instruction and returns the decoded `Instruction`
+///
+/// This is a compatibility wrapper around `decode` for callers holding the
+/// instruction as a vector of "0"/"1" strings, most significant bit first.
+/// Only the first 32 entries are read.
+pub fn decode_instruction(instr: Vec<String>) -> Result<Instruction, DecodeError> {
+    if instr.len() < 32 {
+        return Err(DecodeError::TooShort { len: instr.len(), needed: 32 });
+    }
+    let word = word_from_bit_strings(&instr[..32])?;
+    return decode(word);
+}
+
+/// Packs "0"/"1" strings, most significant bit first, into an instruction word
+fn word_from_bit_strings(bits: &[String]) -> Result<u32, DecodeError> {
+    let mut word = 0;
+    for (index, bit) in bits.iter().enumerate() {
+        let value = match bit.as_str() {
+            "0" => 0,
+            "1" => 1,
+            _ => return Err(DecodeError::InvalidBit { index }),
+        };
+        word = word << 1 | value;
+    }
+    return Ok(word);
+}
+
+/// Decodes an instruction stored as little-endian bytes, as found in memory
+///
+/// Only the first four bytes are read.
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{decode_bytes, Instruction};
+///
+/// let decoded = decode_bytes(&[0x03, 0x02, 0x40, 0x00]).unwrap();
+/// assert_eq!(decoded, Instruction::Lb { rd: 4, rs1: 0, imm: 4 });
+/// assert!(decode_bytes(&[0x03, 0x02]).is_err());
+/// ```
+pub fn decode_bytes(bytes: &[u8]) -> Result<Instruction, DecodeError> {
+    return DecoderConfig::default().decode_bytes(bytes);
+}
+
+/// Decodes a RISC-V 32-bit instruction word
+///
+/// Decodes for RV32, use `DecoderConfig` to select RV64.
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{decode, Instruction};
+///
+/// let decoded = decode(0x00400203).unwrap();
+/// assert_eq!(decoded, Instruction::Lb { rd: 4, rs1: 0, imm: 4 });
+/// assert_eq!(decoded.to_string(), "LB x4, 4(x0)");
+/// ```
+pub fn decode(instr: u32) -> Result<Instruction, DecodeError> {
+    return decode_with_config(instr, &DecoderConfig::default());
+}
+
+pub(crate) fn decode_with_config(instr: u32, config: &DecoderConfig) -> Result<Instruction, DecodeError> {
     /*
      * This decoder is based on the RISC-V Unprivileged Spec v2.2
      *
@@ -59,1628 +217,1752 @@ pub fn instruction_decoder(instr: Vec<String>) -> String {
      * R-type 
      * 31------25 24-----20 19-----15 14-----12 11-------7 6-------0
      * /func7/     /rs2/     /rs1/     /func3/     /rd/      /opcode/
-     * 0-------6 7-----11 12-----16 17--------19 20-----24 25--------31
-     * /func7/    /rs2/     /rs1/     /func3/      /rd/      /opcode/
      *  
      * I-type
      * 31---------20 19-----15 14-------12 11------7 6--------0
      * /imm[11:0]/     /rs1/     /func3/     /rd/      /opcode/
-     * 0----------11 12-----16 17-------19 20-----24 25-------31
-     * /imm[11:0]/     /rs1/     /func3/     /rd/      /opcode/ 
      * 
      * S-type
      * 31--------25 24-----20 19-----15 14--------12 11-----------7 6---------0
      * /imm[11:5]/    /rs2/     /rs1/     /func3/      /imm[4:0]/     /opcode/
-     * 0----------6 7------11 12-----16 17--------19 20----------24 25--------31
-     * /imm[11:5]/    /rs2/      /rs1/    /func3/      /imm[4:0]/     /opcode/
      * 
      * B-type
      * 31------------25 24-----20 19-----15 14-------12 11--------------7 6---------0
      * /imm[12|10:5]/     /rs2/     /rs1/     /func3/     /imm[4:1|11]/    /opcode/
-     * 0--------------6 7------11 12-----16 17-------19 20-------------24 25--------31
-     * /imm[12|10:5]/     /rs2/     /rs1/     /func3/      /imm[4:1|11]/    /opcode/
      * 
      * U-type
      * 31-----------12 11-------7 6---------0
      * /imm[31:12]/       /rd/     /opcode/
-     * 0------------19 20------24 25--------31
-     * /imm[31:12]/       /rd/      /opcode/
      * 
      * J-type
      * 31----------------------12 11------7 6----------0
      * /imm[20|10:1|11|19:12]/      /rd/      /opcode/
-     * 0-----------------------19 20-----24 25--------31
-     * /imm[20|10:1|11|19:12]/      /rd/      /opcode/ 
      *                               
      * 
      * RV32A Atomic Instruction Breakdown
      * 
      * 31--------27 26----- 25-----  24-----20 19------15 14-------12 11------7 6--------0
      *   /func5/      /aq/    /rl/     /rs2/     /rs1/      /func3/      /rd/    /opcode/
-     * 0---------4  5------ 6------  7------11 12------16 17-------19 20-----24 25-------31
-     *   /func5/      /aq/    /rl/     /rs2/     /rs1/      /func3/      /rd/    /opcode/
      * 
      * RV32F/RV32D Instruction Breakdown
      * 
      * 31------27 26----25  24-----20 19------15 14----12 11----7 6--------0
      *   /rs3/      /00/      /rs2/     /rs1/      /rm/     /rd/   /opcode/
-     * 0-------4  5-----6  7------11 12------16 17-----19 20----24 25-------31
-     *  /rs3/      /00/      /rs2/     /rs1/      /rm/     /rd/     /opcode/
      * 
      */
 
-    let opcode_slice = &instr[25..];    // opcode field
-    let opcode_slice_joined = opcode_slice.join("");
-
-    println!("--------------------------------");
-
-    match opcode_slice_joined.as_str() {
-        "0000011" => {      // Load Instructions
-            let funct3_slice = &instr[17..20];
-            let funct3_slice_joined = funct3_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let mut imm_slice = &instr[0..12];
-            let mut imm_slice_joined = imm_slice.join("");
-
-            let rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let mut imm_bits = i32::from_str_radix(&imm_slice_joined, 2).unwrap();
-
-            // Immediate generator/handler
-            if imm_slice[0] == "1" {
-                let mut x = 1;
-                loop {
-                    let mut twos = i32::pow(2, x);
-                    if (imm_bits as f32)/(twos as f32) < 1.0 {
-                        imm_bits = imm_bits - twos;
-                        break;
-                    }
-                    else {
-                        x = x + 1;
-                    }
-                }
-            }
+    let opcode = bits(instr, 6, 0);     // opcode field
+
+    match opcode {
+        0b0000011 => {      // Load Instructions
+            let funct3 = bits(instr, 14, 12);
+            let rd_bits = bits(instr, 11, 7);
+            let rs1_bits = bits(instr, 19, 15);
+            let imm_bits = i_imm(instr);
 
-            match funct3_slice_joined.as_str() {
-                "000" => {      // Load Byte (8-bits)
-                    println!("Load Byte (LB) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("LB x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("LB x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                }
-                "001" => {      // Load Half-word (16-bits)
-                    println!("Load Half-word (LH) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("LH x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("LH x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                }
-                "010" => {      // Load Word (32-bits)
-                    println!("Load Word (LW) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("LW x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("LW x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                }
-                "100" => {      // Load Byte Unsigned (u8-bits)
-                    println!("Load Byte Unsigned (LBU) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("LBU x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("LBU x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                }
-                "101" => {      // Load Half-word Unsigned (u16-bits)
-                    println!("Load Half-word Unsigned (LHU) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("LHU x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("LHU x{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                }
-                default => {
-                    panic!("Instruction format error!");
-                }
-            &_ => todo!()
+            match funct3 {
+                0b000 => {      // Load Byte (8-bits)
+                    return Ok(Instruction::Lb { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b001 => {      // Load Half-word (16-bits)
+                    return Ok(Instruction::Lh { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b010 => {      // Load Word (32-bits)
+                    return Ok(Instruction::Lw { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b100 => {      // Load Byte Unsigned (u8-bits)
+                    return Ok(Instruction::Lbu { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b101 => {      // Load Half-word Unsigned (u16-bits)
+                    return Ok(Instruction::Lhu { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b110 => {      // Load Word Unsigned (u32-bits), RV64 only
+                    config.require_rv64(instr)?;
+                    return Ok(Instruction::Lwu { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b011 => {      // Load Double-word (64-bits), RV64 only
+                    config.require_rv64(instr)?;
+                    return Ok(Instruction::Ld { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                _ => {
+                    return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 });
+                }
             }
         }
 
-        "0100011" => {      // Store Instructions
-            let funct3_slice = &instr[17..20];
-            let funct3_slice_joined = funct3_slice.join("");
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let mut imm_slice = &instr[0..7];
-            let mut imm_slice_joined = imm_slice.join("");
-            let imm2_slice = &instr[20..25];
-            let imm2_slice_joined = imm2_slice.join("");
-
-            imm_slice_joined = imm_slice_joined + &imm2_slice_joined;
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-            let mut imm_bits = i32::from_str_radix(&imm_slice_joined, 2).unwrap();
-
-            // Immediate generator/handler
-            if imm_slice[0] == "1" {
-                let mut x = 1;
-                loop {
-                    let mut twos = i32::pow(2, x);
-                    if (imm_bits as f32)/(twos as f32) < 1.0 {
-                        imm_bits = imm_bits - twos;
-                        break;
-                    }
-                    else {
-                        x = x + 1;
-                    }
+        0b0100011 => {      // Store Instructions
+            let funct3 = bits(instr, 14, 12);
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let imm_bits = s_imm(instr);
+
+            match funct3 {
+                0b000 => {      // Store Byte (8-bits)
+                    return Ok(Instruction::Sb { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b001 => {      // Store Half-word (16-bit)
+                    return Ok(Instruction::Sh { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b010 => {      // Store Word (32-bit)
+                    return Ok(Instruction::Sw { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b011 => {      // Store Double-word (64-bit), RV64 only
+                    config.require_rv64(instr)?;
+                    return Ok(Instruction::Sd { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                _ => {
+                    return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 });
                 }
             }
+        }
+
+        0b1100011 => {
+            let funct3 = bits(instr, 14, 12);
+            let rs1_bits = bits(instr, 19, 15);
+            let rs2_bits = bits(instr, 24, 20);
+            let imm_bits = b_imm(instr);
 
-            match funct3_slice_joined.as_str() {
-                "000" => {      // Store Byte (8-bits)
-                    println!("Store Byte (SB) instruction decoded");
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("SB x{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("SB x{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                }
-                "001" => {      // Store Half-word (16-bit)
-                    println!("Store Half-word (SH) instruction decoded");
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("SH x{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("SH x{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                }
-                "010" => {      // Store Word (32-bit)
-                    println!("Store Word (SW) instruction decoded");
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("SW x{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("SW x{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                }
-                default => {
-                    panic!("Instruction format error!");
-                }
-            &_ => todo!()
+            match funct3 {
+                0b000 => {      // Branch Equal
+                    return Ok(Instruction::Beq { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b001 => {      // Branch Not Equal
+                    return Ok(Instruction::Bne { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b100 => {      // Branch Less Than
+                    return Ok(Instruction::Blt { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b101 => {      // Branch Greater Than or Equal
+                    return Ok(Instruction::Bge { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b110 => {      // Branch Less Than Unsigned
+                    return Ok(Instruction::Bltu { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b111 => {      // Branch Greater Than or Equal Unsigned
+                    return Ok(Instruction::Bgeu { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                _ => {
+                    return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 });
+                }
             }
         }
 
-        "1100011" =>{
-            let funct3_slice = &instr[17..20];
-            let funct3_slice_joined = funct3_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let imm1_slice = &instr[0..7];
-            let imm2_slice = &instr[20..25];
-            let imm_slice_1 = imm1_slice[0].to_string(); // imm [12]
-            let imm_slice_2 = &imm1_slice[1..7]; // imm [10:5]
-            let imm_slice_2_joined = imm_slice_2.join("");
-            let imm_slice_3 = &imm2_slice[0..4]; // imm [4:1]
-            let imm_slice_3_joined = imm_slice_3.join("");
-            let imm_slice_4 = imm2_slice[4].to_string(); // imm [11]
-            let zero = "0".to_string();
-            let mut imm_final = imm_slice_1.clone() + &imm_slice_4 + &imm_slice_2_joined + &imm_slice_3_joined + &zero;
-
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-            let mut imm_bits = i32::from_str_radix(&imm_final, 2).unwrap();
-
-            // Immediate generator/handler
-            if imm_slice_1 == "1" {
-                let mut x = 1;
-                loop {
-                    let mut twos = i32::pow(2, x);
-                    if (imm_bits as f32)/(twos as f32) < 1.0 {
-                        imm_bits = imm_bits - twos;
-                        break;
+        0b0010011 => {      // Immediate type instructions
+            let funct3 = bits(instr, 14, 12);
+            let rd_bits = bits(instr, 11, 7);
+            let rs1_bits = bits(instr, 19, 15);
+            let imm_bits = i_imm(instr);
+
+            match funct3 {
+                0b000 => {      // Add immediate
+                    return Ok(Instruction::Addi { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b010 => {      // Set less than immediate
+                    return Ok(Instruction::Slti { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b011 => {      // Set less than immediate unsigned
+                    return Ok(Instruction::Sltiu { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b100 => {      // XOR Immediate
+                    return Ok(Instruction::Xori { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b110 => {      // OR Immediate
+                    return Ok(Instruction::Ori { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b111 => {      // AND Immediate
+                    return Ok(Instruction::Andi { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b001 => {      // Shift left logical immediate
+                    let funct6 = bits(instr, 31, 26);       // shamt[5] sits in bit 25 under RV64
+                    match funct6 {
+                        0b000000 => {
+                            let shamt = config.shamt(instr)?;
+                            return Ok(Instruction::Slli { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                        }
+                        0b010010 => {      // Single-bit clear immediate
+                            let shamt = config.shamt(instr)?;
+                            return Ok(Instruction::Bclri { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                        }
+                        0b011010 => {      // Single-bit invert immediate
+                            let shamt = config.shamt(instr)?;
+                            return Ok(Instruction::Binvi { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                        }
+                        0b001010 => {      // Single-bit set immediate
+                            let shamt = config.shamt(instr)?;
+                            return Ok(Instruction::Bseti { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                        }
+                        0b011000 => {      // Count and sign-extend, the rs2 field selects the operation
+                            let funct12 = bits(instr, 31, 20);
+                            match funct12 {
+                                0b011000000000 => return Ok(Instruction::Clz { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                0b011000000001 => return Ok(Instruction::Ctz { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                0b011000000010 => return Ok(Instruction::Cpop { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                0b011000000100 => return Ok(Instruction::SextB { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                0b011000000101 => return Ok(Instruction::SextH { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct12", value: funct12 }),
+                            }
+                        }
+                        0b000010 => {      // Bit interleave, RV32 only
+                            let funct12 = bits(instr, 31, 20);
+                            if funct12 != 0b000010001111 {
+                                return Err(DecodeError::ReservedFunct { word: instr, field: "funct12", value: funct12 });
+                            }
+                            config.require_rv32(instr)?;
+                            return Ok(Instruction::Zip { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                        }
+                        0b000100 => {      // SHA2 and SM3 hash functions, the rs2 field selects the operation
+                            let funct12 = bits(instr, 31, 20);
+                            match funct12 {
+                                0b000100000000 => return Ok(Instruction::Sha256sum0 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                0b000100000001 => return Ok(Instruction::Sha256sum1 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                0b000100000010 => return Ok(Instruction::Sha256sig0 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                0b000100000011 => return Ok(Instruction::Sha256sig1 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                0b000100000100..=0b000100000111 => {      // SHA2-512 on a single register, RV64 only
+                                    config.require_rv64(instr)?;
+                                    match funct12 & 0b11 {
+                                        0b00 => return Ok(Instruction::Sha512sum0 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                        0b01 => return Ok(Instruction::Sha512sum1 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                        0b10 => return Ok(Instruction::Sha512sig0 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                        _ => return Ok(Instruction::Sha512sig1 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                    }
+                                }
+                                0b000100001000 => return Ok(Instruction::Sm3p0 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                0b000100001001 => return Ok(Instruction::Sm3p1 { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct12", value: funct12 }),
+                            }
+                        }
+                        0b001100 => {      // AES64 inverse MixColumns and key schedule, RV64 only
+                            let funct12 = bits(instr, 31, 20);
+                            match funct12 {
+                                0b001100000000 => {
+                                    config.require_rv64(instr)?;
+                                    return Ok(Instruction::Aes64im { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                0b001100010000..=0b001100011111 => {
+                                    config.require_rv64(instr)?;
+                                    let rnum = bits(instr, 23, 20);
+                                    if rnum > 0xa {
+                                        return Err(DecodeError::ReservedFunct { word: instr, field: "rnum", value: rnum });
+                                    }
+                                    return Ok(Instruction::Aes64ks1i { rd: rd_bits as u8, rs1: rs1_bits as u8, rnum: rnum as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct12", value: funct12 }),
+                            }
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct6", value: funct6 }),
                     }
-                    else {
-                        x = x + 1;
+                }
+                0b101 => {      // Shift right immediate
+                    let funct6 = bits(instr, 31, 26);
+                    match funct6 {
+                        0b000000 => {      // Shift right logical immediate
+                            let shamt = config.shamt(instr)?;
+                            return Ok(Instruction::Srli { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                        }
+                        0b010000 => {      // Shift right arithmetic immediate
+                            let shamt = config.shamt(instr)?;
+                            return Ok(Instruction::Srai { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                        }
+                        0b011000 => {      // Rotate right immediate
+                            let shamt = config.shamt(instr)?;
+                            return Ok(Instruction::Rori { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                        }
+                        0b010010 => {      // Single-bit extract immediate
+                            let shamt = config.shamt(instr)?;
+                            return Ok(Instruction::Bexti { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                        }
+                        0b001010 => {      // OR-combine bytes
+                            let funct12 = bits(instr, 31, 20);
+                            if funct12 != 0b001010000111 {
+                                return Err(DecodeError::ReservedFunct { word: instr, field: "funct12", value: funct12 });
+                            }
+                            return Ok(Instruction::OrcB { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                        }
+                        0b011010 => {      // Byte-reverse, the shamt field holds XLEN - 8
+                            let funct12 = bits(instr, 31, 20);
+                            match (funct12, config.xlen) {
+                                (0b011010011000, Xlen::Rv32) | (0b011010111000, Xlen::Rv64) => {
+                                    return Ok(Instruction::Rev8 { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                (0b011010000111, _) => {      // Reverse bits in each byte
+                                    return Ok(Instruction::Brev8 { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                (0b011010111000, _) => return Err(DecodeError::UnsupportedXlen { word: instr, xlen: config.xlen }),
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct12", value: funct12 }),
+                            }
+                        }
+                        0b000010 => {      // Bit deinterleave, RV32 only
+                            let funct12 = bits(instr, 31, 20);
+                            if funct12 != 0b000010001111 {
+                                return Err(DecodeError::ReservedFunct { word: instr, field: "funct12", value: funct12 });
+                            }
+                            config.require_rv32(instr)?;
+                            return Ok(Instruction::Unzip { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct6", value: funct6 }),
                     }
                 }
+                _ => {
+                    return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 });
+                }
             }
+        }
+
+        0b0011011 => {      // Immediate type word instructions, RV64 only
+            config.require_rv64(instr)?;
+            let funct3 = bits(instr, 14, 12);
+            let funct7 = bits(instr, 31, 25);
+            let rd_bits = bits(instr, 11, 7);
+            let rs1_bits = bits(instr, 19, 15);
+            let shamt = bits(instr, 24, 20) as u8;
 
-            match funct3_slice_joined.as_str() {
-                "000" => {      // Branch Equal
-                    println!("Branch Equal (BEQ) instruction decoded");
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("BEQ x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("BEQ x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                }
-                "001" => {      // Branch Not Equal
-                    println!("Branch Not Equal (BNE) instruction decoded");
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("BNE x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("BNE x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                }
-                "100" => {      // Branch Less Than
-                    println!("Branch Less Than (BLT) instruction decoded");
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("BLT x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("BLT x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                }
-                "101" => {      // Branch Greater Than or Equal
-                    println!("Branch Greater Than or Equal (BGE) instruction decoded");
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("BGE x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("BGE x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                }
-                "110" => {      // Branch Less Than Unsigned
-                    println!("Branch Less Than Unsigned (BLTU) instruction decoded");
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("BLTU x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("BLTU x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                }
-                "111" => {      // Branch Greater Than or Equal Unsigned
-                    println!("Branch Greater Than or Equal Unsigned (BGEU) instruction decoded");
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("BGEU x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("BGEU x{}, x{}, {}", rs1_bits, rs2_bits, imm_bits);
-                }
-                default => {
-                    panic!("Instruction format error!");
-                }
-            &_ => todo!()
+            match (funct3, funct7) {
+                (0b000, _) => {      // Add immediate word
+                    return Ok(Instruction::Addiw { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: i_imm(instr) });
+                }
+                (0b001, 0b0000000) => {      // Shift left logical immediate word
+                    return Ok(Instruction::Slliw { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                }
+                (0b101, 0b0000000) => {      // Shift right logical immediate word
+                    return Ok(Instruction::Srliw { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                }
+                (0b101, 0b0100000) => {      // Shift right arithmetic immediate word
+                    return Ok(Instruction::Sraiw { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                }
+                (0b001, 0b0000100) | (0b001, 0b0000101) => {      // Shift left logical immediate unsigned word, 6-bit shamt
+                    let shamt = bits(instr, 25, 20) as u8;
+                    return Ok(Instruction::SlliUw { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                }
+                (0b001, 0b0110000) => {      // Count word, the rs2 field selects the operation
+                    match shamt {
+                        0b00000 => return Ok(Instruction::Clzw { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                        0b00001 => return Ok(Instruction::Ctzw { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                        0b00010 => return Ok(Instruction::Cpopw { rd: rd_bits as u8, rs1: rs1_bits as u8 }),
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct12", value: bits(instr, 31, 20) }),
+                    }
+                }
+                (0b101, 0b0110000) => {      // Rotate right immediate word
+                    return Ok(Instruction::Roriw { rd: rd_bits as u8, rs1: rs1_bits as u8, shamt });
+                }
+                (0b001, _) | (0b101, _) => {
+                    return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 });
+                }
+                _ => {
+                    return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 });
+                }
             }
         }
 
-        "0010011" => {      // Immediate type instructions
-            let funct3_slice = &instr[17..20];
-            let funct3_slice_joined = funct3_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let imm_slice = &instr[0..12];
-            let mut imm_slice_joined = imm_slice.join("");
-
-            let rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let mut imm_bits = i32::from_str_radix(&imm_slice_joined, 2).unwrap();
-
-            // Immediate generator/handler
-            if imm_slice[0] == "1" {
-                let mut x = 1;
-                loop {
-                    let mut twos = i32::pow(2, x);
-                    if  (imm_bits as f32)/(twos as f32) < 1.0 {
-                        imm_bits = imm_bits - twos;
-                        break;
+        0b0111011 => {      // Arithmetic word instructions, RV64 only
+            config.require_rv64(instr)?;
+            let funct3 = bits(instr, 14, 12);
+            let funct7 = bits(instr, 31, 25);
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let rd_bits = bits(instr, 11, 7);
+
+            match funct3 {
+                0b000 => {
+                    match funct7 {
+                        0b0000000 => {      // Add word
+                            return Ok(Instruction::Addw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0100000 => {      // Subtract word
+                            return Ok(Instruction::Subw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000100 => {      // Add unsigned word
+                            return Ok(Instruction::AddUw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Multiply word
+                            return Ok(Instruction::Mulw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
+                    }
+                }
+                0b001 => {
+                    match funct7 {
+                        0b0000000 => {      // Shift left logical word
+                            return Ok(Instruction::Sllw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0110000 => {      // Rotate left word
+                            return Ok(Instruction::Rolw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
+                    }
+                }
+                0b010 => {
+                    match funct7 {
+                        0b0010000 => {      // Shift unsigned word left by 1 and add
+                            return Ok(Instruction::Sh1addUw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
+                    }
+                }
+                0b100 => {
+                    match funct7 {
+                        0b0010000 => {      // Shift unsigned word left by 2 and add
+                            return Ok(Instruction::Sh2addUw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000100 if rs2_bits == 0 => {      // Zero-extend halfword
+                            return Ok(Instruction::ZextH { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                        }
+                        0b0000100 => {      // Pack low halfwords of words
+                            return Ok(Instruction::Packw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Divide signed word
+                            return Ok(Instruction::Divw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
+                    }
+                }
+                0b101 => {
+                    match funct7 {
+                        0b0000000 => {      // Shift right logical word
+                            return Ok(Instruction::Srlw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0100000 => {      // Shift right arithmetic word
+                            return Ok(Instruction::Sraw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0110000 => {      // Rotate right word
+                            return Ok(Instruction::Rorw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Divide unsigned word
+                            return Ok(Instruction::Divuw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
                     }
-                    else {
-                        x = x + 1;
+                }
+                0b110 => {
+                    match funct7 {
+                        0b0010000 => {      // Shift unsigned word left by 3 and add
+                            return Ok(Instruction::Sh3addUw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Remainder signed word
+                            return Ok(Instruction::Remw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
+                    }
+                }
+                0b111 => {
+                    match funct7 {
+                        0b0000001 => {      // Remainder unsigned word
+                            return Ok(Instruction::Remuw { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
                     }
                 }
+                _ => {
+                    return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 });
+                }
             }
+        }
 
-            match funct3_slice_joined.as_str() {
-                "000" => {      // Add immediate
-                    println!("Add Immediate (ADDI) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("ADDI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("ADDI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                }
-                "010" => {      // Set less than immediate
-                    println!("Set less than Immediate (SLTI) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("SLTI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("SLTI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                }
-                "011" => {      // Set less than immediate unsigned
-                    println!("Set less than Immediate unsigned (SLTIU) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("SLTIU x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("SLTIU x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                }
-                "100" => {      // XOR Immediate
-                    println!("XOR Immediate (XORI) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("XORI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("XORI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                }
-                "110" => {      // OR Immediate
-                    println!("OR Immediate (ORI) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("ORI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("ORI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                }
-                "111" => {      // AND Immediate
-                    println!("AND Immediate (ANDI) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immediate value: {}", imm_bits);
-                    println!("ANDI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                    println!("--------------------------------");
-                    return format!("ANDI x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-                }
-                default => {
-                    panic!("Instruction format error!");
-                }
-            &_ => todo!()
+        0b0110111 => {      // Load upper immediate
+            let rd_bits = bits(instr, 11, 7);
+            let imm_bits = u_imm(instr);
+
+            return Ok(Instruction::Lui { rd: rd_bits as u8, imm: imm_bits });
+        }
+
+        0b0010111 => {      // Add upper immediate with PC
+            let rd_bits = bits(instr, 11, 7);
+            let imm_bits = u_imm(instr);
+
+            return Ok(Instruction::Auipc { rd: rd_bits as u8, imm: imm_bits });
+        }
+
+        0b1101111 => {      // Jump and link
+            let rd_bits = bits(instr, 11, 7);
+            let imm_bits = j_imm(instr);
+
+            return Ok(Instruction::Jal { rd: rd_bits as u8, imm: imm_bits });
+        }
+
+        0b1100111 => {      // Jump and link to register
+            let funct3 = bits(instr, 14, 12);
+            let rd_bits = bits(instr, 11, 7);
+            let rs1_bits = bits(instr, 19, 15);
+            let imm_bits = i_imm(instr);
+
+            if funct3 != 0b000 {
+                return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 });
             }
+            return Ok(Instruction::Jalr { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
         }
 
-        "0110111" => {      // Load upper immediate
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-            let imm_slice = &instr[0..20];
-            let imm_slice_joined = imm_slice.join("");
-
-            let rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-            let mut imm_bits = i32::from_str_radix(&imm_slice_joined, 2).unwrap();
-
-            // Immediate generator/handler
-            if imm_slice[0] == "1" {
-                let mut x = 1;
-                loop {
-                    let mut twos = i32::pow(2, x);
-                    if  (imm_bits as f32)/(twos as f32) < 1.0 {
-                        imm_bits = imm_bits - twos;
-                        break;
+        0b0110011 => {      // Arithmetic instructions
+            let funct3 = bits(instr, 14, 12);
+            let funct7 = bits(instr, 31, 25);
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let rd_bits = bits(instr, 11, 7);
+
+            match funct3 {
+                0b000 => {
+                    match funct7 {
+                        0b0000000 => {      // Add
+                            return Ok(Instruction::Add { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0100000 => {      // Sub
+                            return Ok(Instruction::Sub { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Multiply signed rs1 and rs2
+                            return Ok(Instruction::Mul { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0101000..=0b0101111 => {      // SHA2-512 on register pairs, RV32 only
+                            let op = match funct7 {
+                                0b0101000 => Instruction::Sha512sum0r { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 },
+                                0b0101001 => Instruction::Sha512sum1r { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 },
+                                0b0101010 => Instruction::Sha512sig0l { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 },
+                                0b0101011 => Instruction::Sha512sig1l { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 },
+                                0b0101110 => Instruction::Sha512sig0h { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 },
+                                0b0101111 => Instruction::Sha512sig1h { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 },
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
+                            };
+                            config.require_rv32(instr)?;
+                            return Ok(op);
+                        }
+                        0b0011001 | 0b0011011 | 0b0011101 | 0b0011111 | 0b0111111 => {      // AES64 rounds and key schedule, RV64 only
+                            config.require_rv64(instr)?;
+                            let (rd, rs1, rs2) = (rd_bits as u8, rs1_bits as u8, rs2_bits as u8);
+                            match funct7 {
+                                0b0011001 => return Ok(Instruction::Aes64es { rd, rs1, rs2 }),
+                                0b0011011 => return Ok(Instruction::Aes64esm { rd, rs1, rs2 }),
+                                0b0011101 => return Ok(Instruction::Aes64ds { rd, rs1, rs2 }),
+                                0b0011111 => return Ok(Instruction::Aes64dsm { rd, rs1, rs2 }),
+                                _ => return Ok(Instruction::Aes64ks2 { rd, rs1, rs2 }),
+                            }
+                        }
+                        _ => {      // AES32 and SM4 rounds, bs in bits 31:30 selects the byte of rs2
+                            let funct5 = bits(instr, 29, 25);
+                            let bs = bits(instr, 31, 30) as u8;
+                            let (rd, rs1, rs2) = (rd_bits as u8, rs1_bits as u8, rs2_bits as u8);
+                            match funct5 {
+                                0b11000 => return Ok(Instruction::Sm4ed { rd, rs1, rs2, bs }),
+                                0b11010 => return Ok(Instruction::Sm4ks { rd, rs1, rs2, bs }),
+                                0b10001 | 0b10011 | 0b10101 | 0b10111 => {
+                                    config.require_rv32(instr)?;
+                                    match funct5 {
+                                        0b10001 => return Ok(Instruction::Aes32esi { rd, rs1, rs2, bs }),
+                                        0b10011 => return Ok(Instruction::Aes32esmi { rd, rs1, rs2, bs }),
+                                        0b10101 => return Ok(Instruction::Aes32dsi { rd, rs1, rs2, bs }),
+                                        _ => return Ok(Instruction::Aes32dsmi { rd, rs1, rs2, bs }),
+                                    }
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
+                            }
+                        }
+                    }
+                }
+                0b001 => {      
+                    match funct7 {
+                        0b0000000 => {      // Shift left logical
+                            return Ok(Instruction::Sll { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0110000 => {      // Rotate left
+                            return Ok(Instruction::Rol { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000101 => {      // Carry-less multiply, low half
+                            return Ok(Instruction::Clmul { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0100100 => {      // Single-bit clear
+                            return Ok(Instruction::Bclr { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0110100 => {      // Single-bit invert
+                            return Ok(Instruction::Binv { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0010100 => {      // Single-bit set
+                            return Ok(Instruction::Bset { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Multiply high signed rs1 and rs2
+                            return Ok(Instruction::Mulh { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
                     }
-                    else {
-                        x = x + 1;
+                }
+                0b010 => {      
+                    match funct7 {
+                        0b0000000 => {      // Set less than
+                            return Ok(Instruction::Slt { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0010000 => {      // Shift left by 1 and add
+                            return Ok(Instruction::Sh1add { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000101 => {      // Carry-less multiply, reversed
+                            return Ok(Instruction::Clmulr { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0010100 => {      // Crossbar permutation of nibbles
+                            return Ok(Instruction::Xperm4 { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Multiply signed rs1 and unsigned rs2
+                            return Ok(Instruction::Mulhsu { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
                     }
                 }
-            }
-
-            println!("Load Upper Immediate (LUI) instruction decoded");
-            println!("Destination Register address: x{}", rd_bits);
-            println!("Immediate address: x{}", imm_bits);
-            println!("LUI x{}, {}", rd_bits, imm_bits);
-            println!("--------------------------------");
-            return format!("LUI x{}, {}", rd_bits, imm_bits);
-        }
-
-        "0010111" => {      // Add upper immediate with PC
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-            let imm_slice = &instr[0..20];
-            let imm_slice_joined = imm_slice.join("");
-
-            let rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-            let mut imm_bits = i32::from_str_radix(&imm_slice_joined, 2).unwrap();
-
-            // Immediate generator/handler
-            if imm_slice[0] == "1" {
-                let mut x = 1;
-                loop {
-                    let mut twos = i32::pow(2, x);
-                    if  (imm_bits as f32)/(twos as f32) < 1.0 {
-                        imm_bits = imm_bits - twos;
-                        break;
+                0b011 => {      
+                    match funct7 {
+                        0b0000000 => {      // Set less than unsigned
+                            return Ok(Instruction::Sltu { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000101 => {      // Carry-less multiply, high half
+                            return Ok(Instruction::Clmulh { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Multiply unsigned rs1 and rs2
+                            return Ok(Instruction::Mulhu { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
                     }
-                    else {
-                        x = x + 1;
+                }
+                0b100 => {      
+                    match funct7 {
+                        0b0000000 => {      // XOR
+                            return Ok(Instruction::Xor { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0010000 => {      // Shift left by 2 and add
+                            return Ok(Instruction::Sh2add { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0100000 => {      // Exclusive NOR
+                            return Ok(Instruction::Xnor { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000101 => {      // Minimum
+                            return Ok(Instruction::Min { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000100 if rs2_bits == 0 && config.xlen == Xlen::Rv32 => {      // Zero-extend halfword
+                            return Ok(Instruction::ZextH { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                        }
+                        0b0000100 => {      // Pack low halves
+                            return Ok(Instruction::Pack { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0010100 => {      // Crossbar permutation of bytes
+                            return Ok(Instruction::Xperm8 { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Divide signed rs1 and rs2 (rounding towards zero)
+                            return Ok(Instruction::Div { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
                     }
                 }
-            }
-
-            println!("Add upper immediate with PC (AUIPC) instruction decoded");
-            println!("Destination Register address: x{}", rd_bits);
-            println!("Immediate address: x{}", imm_bits);
-            println!("AUIPC x{}, {}", rd_bits, imm_bits);
-            println!("--------------------------------");
-            return format!("AUIPC x{}, {}", rd_bits, imm_bits);
-        }
-
-        "1101111" => {      // Jump and link
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-            let imm_slice = &instr[0..20];                      // imm[20:1]
-            let mut imm_slice_offset = &imm_slice[0..12];
-            let imm_slice_joined = imm_slice_offset.join("");
-
-            let rd_bits = u32::from_str_radix(&rd_slice_joined, 2).unwrap();
-            let mut imm_bits = i32::from_str_radix(&imm_slice_joined, 2).unwrap();
-
-            // Immediate generator/handler
-            if imm_slice[0] == "1" {
-                let mut x = 1;
-                loop {
-                    let mut twos = i32::pow(2, x);
-                    if  (imm_bits as f32)/(twos as f32) < 1.0 {
-                        imm_bits = imm_bits - twos;
-                        break;
+                0b101 => {      // Shift right
+                    match funct7 {
+                        0b0000000 => {      // Shift right logical
+                            return Ok(Instruction::Srl { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0110000 => {      // Rotate right
+                            return Ok(Instruction::Ror { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000101 => {      // Unsigned minimum
+                            return Ok(Instruction::Minu { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0100100 => {      // Single-bit extract
+                            return Ok(Instruction::Bext { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Divide unsigned rs1 and rs2 (rounding towards zero)
+                            return Ok(Instruction::Divu { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0100000 => {      // Shift right arithmetic
+                            return Ok(Instruction::Sra { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
                     }
-                    else {
-                        x = x + 1;
+                }
+                0b110 => {      
+                    match funct7 {
+                        0b0000000 => {      // OR
+                            return Ok(Instruction::Or { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0010000 => {      // Shift left by 3 and add
+                            return Ok(Instruction::Sh3add { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0100000 => {      // OR with inverted operand
+                            return Ok(Instruction::Orn { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000101 => {      // Maximum
+                            return Ok(Instruction::Max { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Remainder signed rs1 and rs2
+                            return Ok(Instruction::Rem { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
                     }
                 }
+                0b111 => {      
+                    match funct7 {
+                        0b0000000 => {      // AND
+                            return Ok(Instruction::And { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0100000 => {      // AND with inverted operand
+                            return Ok(Instruction::Andn { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000101 => {      // Unsigned maximum
+                            return Ok(Instruction::Maxu { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000100 => {      // Pack low bytes
+                            return Ok(Instruction::Packh { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b0000001 => {      // Remainder unsigned rs1 and rs2
+                            return Ok(Instruction::Remu { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 }),
+                    }
+                }
+            _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 }),
             }
-
-            println!("Jump and Link (JAL) instruction decoded");
-            println!("Destination Register address: x{}", rd_bits);
-            println!("Immediate address: {}", imm_bits);
-            println!("JAL x{}, {}", rd_bits, imm_bits);
-            println!("--------------------------------");
-            return format!("JAL x{}, {}", rd_bits, imm_bits);
         }
 
-        "1100111" => {      // Jump and link to register
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-            let imm_slice = &instr[0..12];
-            let imm_slice_joined = imm_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-
-            let rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let mut imm_bits = i32::from_str_radix(&imm_slice_joined, 2).unwrap();
-
-            // Immediate generator/handler
-            if imm_slice[0] == "1" {
-                let mut x = 1;
-                loop {
-                    let mut twos = i32::pow(2, x);
-                    if  (imm_bits as f32)/(twos as f32) < 1.0 {
-                        imm_bits = imm_bits - twos;
-                        break;
+        0b0101111 => {      // Atomic Instructions
+            let funct5 = bits(instr, 31, 27);
+            let funct3 = bits(instr, 14, 12);
+            let aq = bits(instr, 26, 26) == 1;
+            let rl = bits(instr, 25, 25) == 1;
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let rd_bits = bits(instr, 11, 7);
+
+            // funct3 selects the access width, double-words are RV64 only
+            let double = match funct3 {
+                0b010 => false,
+                0b011 => {
+                    config.require_rv64(instr)?;
+                    true
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 }),
+            };
+
+            match funct5 {
+                0b00010 => {      // Load Reserved
+                    if rs2_bits != 0 {
+                        return Err(DecodeError::ReservedFunct { word: instr, field: "rs2", value: rs2_bits });
                     }
-                    else {
-                        x = x + 1;
+                    if double {
+                        return Ok(Instruction::LrD { rd: rd_bits as u8, rs1: rs1_bits as u8, aq, rl });
                     }
+                    return Ok(Instruction::LrW { rd: rd_bits as u8, rs1: rs1_bits as u8, aq, rl });
                 }
-            }
-
-            println!("Jump and Link to register (JALR) instruction decoded");
-            println!("Destination Register address: x{}", rd_bits);
-            println!("Register one address: x{}", rs1_bits);
-            println!("Immediate value: {}", imm_bits);
-            println!("JALR x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-            println!("--------------------------------");
-            return format!("JALR x{}, x{}, {}", rd_bits, rs1_bits, imm_bits);
-        }
-
-        "0110011" => {      // Arithmetic instructions
-            let funct3_slice = &instr[17..20];
-            let funct3_slice_joined = funct3_slice.join("");
-            let funct7_slice = &instr[0..7];
-            let funct7_slice_joined = funct7_slice.join("");
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-            let mut rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-
-            match funct3_slice_joined.as_str() {
-                "000" => {
-                    match funct7_slice_joined.as_str() {
-                        "0000000" => {      // Add
-                            println!("Addition (ADD) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("ADD x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("ADD x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0100000" => {      // Sub
-                            println!("Subtraction (SUB) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("SUB x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("SUB x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0000001" => {      // Multiply signed rs1 and rs2
-                            println!("Multiplication (MUL) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("MUL x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("MUL x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        &_ => todo!()
+                0b00011 => {       // Store Conditional
+                    if double {
+                        return Ok(Instruction::ScD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
+                    }
+                    return Ok(Instruction::ScW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
+                }
+                0b00001 => {       // Atomic Swap
+                    if double {
+                        return Ok(Instruction::AmoswapD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
+                    }
+                    return Ok(Instruction::AmoswapW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
+                }
+                0b00000 => {       // Atomic Add
+                    if double {
+                        return Ok(Instruction::AmoaddD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                     }
+                    return Ok(Instruction::AmoaddW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                 }
-                "001" => {      
-                    match funct7_slice_joined.as_str() {
-                        "0000000" => {      // Shift left logical
-                            println!("Shift Left Logical (SLL) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("SLL x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("SLL x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0000001" => {      // Multiply high signed rs1 and rs2
-                            println!("Multiply High Signed (MULH) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("MULH x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("MULH x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        &_ => todo!()
+                0b00100 => {       // Atomic XOR
+                    if double {
+                        return Ok(Instruction::AmoxorD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                     }
+                    return Ok(Instruction::AmoxorW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                 }
-                "010" => {      
-                    match funct7_slice_joined.as_str() {
-                        "0000000" => {      // Set less than
-                            println!("Set less than (SLT) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("SLT x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("SLT x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0000001" => {      // Multiply signed rs1 and unsigned rs2
-                            println!("Multiply High Unsigned with signed (MULHSU) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("MULHSU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("MULHSU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        &_ => todo!()
+                0b01100 => {       // Atomic AND
+                    if double {
+                        return Ok(Instruction::AmoandD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                     }
+                    return Ok(Instruction::AmoandW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                 }
-                "011" => {      
-                    match funct7_slice_joined.as_str() {
-                        "0000000" => {      // Set less than unsigned
-                            println!("Set less than unsigned (SLTU) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("SLTU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("SLTU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0000001" => {      // Multiply unsigned rs1 and rs2
-                            println!("Multiply High Unsigned (MULHU) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("MULHU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("MULHU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        &_ => todo!()
+                0b01000 => {       // Atomic OR
+                    if double {
+                        return Ok(Instruction::AmoorD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                     }
+                    return Ok(Instruction::AmoorW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                 }
-                "100" => {      
-                    match funct7_slice_joined.as_str() {
-                        "0000000" => {      // XOR
-                            println!("XOR instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("XOR x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("XOR x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0000001" => {      // Divide signed rs1 and rs2 (rounding towards zero)
-                            println!("Divide Signed (DIV) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two address: x{}", rs2_bits);
-                            println!("DIV x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("DIV x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        &_ => todo!()
+                0b10000 => {       // Atomic Minimum
+                    if double {
+                        return Ok(Instruction::AmominD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                     }
+                    return Ok(Instruction::AmominW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                 }
-                "101" => {      // Shift right
-                    match funct7_slice_joined.as_str() {
-                        "0000000" => {      // Shift right logical
-                            println!("Shift Right Logical (SRL) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("SRL x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("SRL x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0000001" => {      // Divide unsigned rs1 and rs2 (rounding towards zero)
-                            println!("Divide Unsigned (DIVU) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two address: x{}", rs2_bits);
-                            println!("DIVU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("DIVU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0100000" => {      // Shift right arithmetic
-                            println!("Shift Right Arithmetic (SRA) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("SRA x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("SRA x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        &_ => todo!()
+                0b10100 => {       // Atomic Maximum
+                    if double {
+                        return Ok(Instruction::AmomaxD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                     }
+                    return Ok(Instruction::AmomaxW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                 }
-                "110" => {      
-                    match funct7_slice_joined.as_str() {
-                        "0000000" => {      // OR
-                            println!("OR instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("OR x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("OR x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0000001" => {      // Remainder signed rs1 and rs2
-                            println!("Remainder Signed (REM) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two address: x{}", rs2_bits);
-                            println!("REM x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("REM x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        &_ => todo!()
+                0b11000 => {       // Atomic Unsigned Minimum
+                    if double {
+                        return Ok(Instruction::AmominuD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                     }
+                    return Ok(Instruction::AmominuW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                 }
-                "111" => {      
-                    match funct7_slice_joined.as_str() {
-                        "0000000" => {      // AND
-                            println!("AND instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two value: {}", rs2_bits);
-                            println!("AND x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("AND x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        "0000001" => {      // Remainder unsigned rs1 and rs2
-                            println!("Remainder Unsigned (REMU) instruction decoded");
-                            println!("Destination Register address: x{}", rd_bits);
-                            println!("Register One address: x{}", rs1_bits);
-                            println!("Register Two address: x{}", rs2_bits);
-                            println!("REMU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                            println!("--------------------------------");
-                            return format!("REMU x{}, x{}, x{}", rd_bits, rs1_bits, rs2_bits);
-                        }
-                        &_ => todo!()
+                0b11100 => {       // Atomic Unsigned Maximum
+                    if double {
+                        return Ok(Instruction::AmomaxuD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                     }
+                    return Ok(Instruction::AmomaxuW { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, aq, rl });
                 }
-            &_ => todo!()
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct5", value: funct5 }),
             }
         }
 
-        "0101111" => {
-            let funct5_slice = &instr[0..5];
-            let funct5_slice_joined = funct5_slice.join("");
-            let func3_slice = &instr[17..20];
-            let func3_slice_joined = func3_slice.join("");
-            let aq_slice = &instr[5].to_string();
-            let rl_slice = &instr[6].to_string();
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-            let mut rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-
-            match funct5_slice_joined.as_str() {
-                "00010" => {      // Load Word
-                    println!("Load Word (LR.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("LR.W x{}, x{}", rd_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("LR.W x{}, x{}", rd_bits, rs1_bits);
-                }
-                "00011" =>{       // Store Word
-                    println!("Store Word (SC.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("SC.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("SC.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                "00001" =>{       // Atomic Swap
-                    println!("Atomic Swap (AMOSWAP.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("AMOSWAP.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("AMOSWAP.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                "00000" =>{       // Atomic Add
-                    println!("Atomic Add (AMOADD.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("AMOADD.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("AMOADD.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                "00100" =>{       // Atomic XOR
-                    println!("Atomic XOR (AMOXOR.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("AMOXOR.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("AMOXOR.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                "01100" =>{       // Atomic AND
-                    println!("Atomic AND (AMOAND.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("AMOAND.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("AMOAND.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                "01000" =>{       // Atomic OR
-                    println!("Atomic OR (AMOOR.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("AMOOR.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("AMOOR.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                "10000" =>{       // Atomic Minimum
-                    println!("Atomic Minimum (AMOMIN.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("AMOMIN.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("AMOMIN.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                "10100" =>{       // Atomic Maximum
-                    println!("Atomic Maximum (AMOMAX.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("AMOMAX.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("AMOMAX.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                "11000" =>{       // Atomic Unsigned Minimum
-                    println!("Atomic Unsigned Minimum (AMOMINU.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("AMOMINU.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("AMOMINU.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                "11100" =>{       // Atomic Unsigned Maximum
-                    println!("Atomic Unsigned Maximum (AMOMAXU.W) instruction decoded");
-                    println!("Destination Register address: x{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("AMOMAXU.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("AMOMAXU.W x{}, x{}, x{}",rd_bits, rs2_bits, rs1_bits);
-                }
-                &_ => todo!()
-            }            
-        }
+        0b1000011 => {
+            let rs3_bits = bits(instr, 31, 27);
+            let fmt = bits(instr, 26, 25);
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let rm = bits(instr, 14, 12);
+            let rd_bits = bits(instr, 11, 7);
 
-        "1000011" => {
-            let rs3_slice = &instr[0..5];
-            let rs3_slice_joined = rs3_slice.join("");
-            let funct3_slice = &instr[5..7];
-            let funct3_slice_joined = funct3_slice.join("");
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let rm_slice = &instr[17..20];
-            let rm_slice_joined = rm_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-            
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-            let rs3_bits = i32::from_str_radix(&rs3_slice_joined, 2).unwrap();
-            let mut rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-
-            match funct3_slice_joined.as_str() {
-                "00" => {
-                    println!("Floating Point Addition (FMADD.S) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: f{}", rs2_bits);
-                    println!("Register Three address: f{}", rs3_bits);
-                    println!("FMADD.S f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                    println!("--------------------------------");
-                    return format!("FMADD.S f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                }
-                "01" => {
-                    println!("Double Floating Point Addition (FMADD.D) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: f{}", rs2_bits);
-                    println!("Register Three address: f{}", rs3_bits);
-                    println!("FMADD.D f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                    println!("--------------------------------");
-                    return format!("FMADD.D f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                }
-                &_ => todo!()
+            match fmt {
+                0b00 => {
+                    return Ok(Instruction::FmaddS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b01 => {
+                    return Ok(Instruction::FmaddD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b10 => {
+                    return Ok(Instruction::FmaddH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b11 => {
+                    return Ok(Instruction::FmaddQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
             }
-            
+
         }
 
-        "1000111" => {
-            let rs3_slice = &instr[0..5];
-            let rs3_slice_joined = rs3_slice.join("");
-            let funct3_slice = &instr[5..7];
-            let funct3_slice_joined = funct3_slice.join("");
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let rm_slice = &instr[17..20];
-            let rm_slice_joined = rm_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-           
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-            let rs3_bits = i32::from_str_radix(&rs3_slice_joined, 2).unwrap();
-            let mut rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-
-            match funct3_slice_joined.as_str() {
-                "00" => {
-                    println!("Floating Point Subtraction (FMSUB.S) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: f{}", rs2_bits);
-                    println!("Register Three address: f{}", rs3_bits);
-                    println!("FMSUB.S f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                    println!("--------------------------------");
-                    return format!("FMSUB.S f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                }
-                "01" => {
-                    println!("Double Floating Point Subtraction (FMSUB.D) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: f{}", rs2_bits);
-                    println!("Register Three address: f{}", rs3_bits);
-                    println!("FMSUB.D f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                    println!("--------------------------------");
-                    return format!("FMSUB.D f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                }
-                &_ => todo!()
+        0b1000111 => {
+            let rs3_bits = bits(instr, 31, 27);
+            let fmt = bits(instr, 26, 25);
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let rm = bits(instr, 14, 12);
+            let rd_bits = bits(instr, 11, 7);
+
+            match fmt {
+                0b00 => {
+                    return Ok(Instruction::FmsubS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b01 => {
+                    return Ok(Instruction::FmsubD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b10 => {
+                    return Ok(Instruction::FmsubH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b11 => {
+                    return Ok(Instruction::FmsubQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
             }
         }
 
-        "1001011" => {
-            let rs3_slice = &instr[0..5];
-            let rs3_slice_joined = rs3_slice.join("");
-            let funct3_slice = &instr[5..7];
-            let funct3_slice_joined = funct3_slice.join("");
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let rm_slice = &instr[17..20];
-            let rm_slice_joined = rm_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-           
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-            let rs3_bits = i32::from_str_radix(&rs3_slice_joined, 2).unwrap();
-            let mut rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-
-            match funct3_slice_joined.as_str() {
-                "00" => {
-                    println!("Floating Point Negative Subtraction (FNMSUB.S) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: f{}", rs2_bits);
-                    println!("Register Three address: f{}", rs3_bits);
-                    println!("FNMSUB.S f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                    println!("--------------------------------");
-                    return format!("FNMSUB.S f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                }
-                "01" => {
-                    println!("Double Floating Point Negative Subtraction (FNMSUB.D) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: f{}", rs2_bits);
-                    println!("Register Three address: f{}", rs3_bits);
-                    println!("FNMSUB.D f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                    println!("--------------------------------");
-                    return format!("FNMSUB.D f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                }
-                &_ => todo!()
+        0b1001011 => {
+            let rs3_bits = bits(instr, 31, 27);
+            let fmt = bits(instr, 26, 25);
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let rm = bits(instr, 14, 12);
+            let rd_bits = bits(instr, 11, 7);
+
+            match fmt {
+                0b00 => {
+                    return Ok(Instruction::FnmsubS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b01 => {
+                    return Ok(Instruction::FnmsubD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b10 => {
+                    return Ok(Instruction::FnmsubH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b11 => {
+                    return Ok(Instruction::FnmsubQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
             }
 
         }
 
-        "1001111" => {
-            let rs3_slice = &instr[0..5];
-            let rs3_slice_joined = rs3_slice.join("");
-            let funct3_slice = &instr[5..7];
-            let funct3_slice_joined = funct3_slice.join("");
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let rm_slice = &instr[17..20];
-            let rm_slice_joined = rm_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-           
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-            let rs3_bits = i32::from_str_radix(&rs3_slice_joined, 2).unwrap();
-            let mut rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-
-            match funct3_slice_joined.as_str() {
-                "00" => {
-                    println!("Floating Point Negative Addition (FNMADD.S) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: f{}", rs2_bits);
-                    println!("Register Three address: f{}", rs3_bits);
-                    println!("FNMADD.S f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                    println!("--------------------------------");
-                    return format!("FNMADD.S f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                }
-                "01" => {
-                    println!("Double Floating Point Negative Addition (FNMADD.D) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: f{}", rs2_bits);
-                    println!("Register Three address: f{}", rs3_bits);
-                    println!("FNMADD.D f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                    println!("--------------------------------");
-                    return format!("FNMADD.D f{}, f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rs3_bits, rm_decoder(&rm_slice_joined));
-                }
-                &_ => todo!()
+        0b1001111 => {
+            let rs3_bits = bits(instr, 31, 27);
+            let fmt = bits(instr, 26, 25);
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let rm = bits(instr, 14, 12);
+            let rd_bits = bits(instr, 11, 7);
+
+            match fmt {
+                0b00 => {
+                    return Ok(Instruction::FnmaddS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b01 => {
+                    return Ok(Instruction::FnmaddD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b10 => {
+                    return Ok(Instruction::FnmaddH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                0b11 => {
+                    return Ok(Instruction::FnmaddQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rs3: rs3_bits as u8, rm: rm_decoder(instr, rm)? });
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
             }
         }
 
-        "1010011" => {
-            let rs3_slice = &instr[0..5];
-            let rs3_slice_joined = rs3_slice.join("");
-            let funct3_slice = &instr[5..7];
-            let funct3_slice_joined = funct3_slice.join("");
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let rm_slice = &instr[17..20];
-            let rm_slice_joined = rm_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-           
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-            let mut rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-
-            match rs3_slice_joined.as_str() {
-                "00000" => {
-                    match funct3_slice_joined.as_str() {
-                        "00" => {
-                            println!("Floating Point Negative Addition (FADD.S) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("Register Two address: f{}", rs2_bits);
-                            println!("FADD.S f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FADD.S f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        "01" => {
-                            println!("Double Floating Point Negative Addition (FADD.D) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("Register Two address: f{}", rs2_bits);
-                            println!("FADD.D f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FADD.D f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        &_ => todo!()
+        0b1010011 => {
+            let funct5 = bits(instr, 31, 27);
+            let fmt = bits(instr, 26, 25);
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let rm = bits(instr, 14, 12);
+            let rd_bits = bits(instr, 11, 7);
+
+            match funct5 {
+                0b00000 => {
+                    match fmt {
+                        0b00 => {
+                            return Ok(Instruction::FaddS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b01 => {
+                            return Ok(Instruction::FaddD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b10 => {
+                            return Ok(Instruction::FaddH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b11 => {
+                            return Ok(Instruction::FaddQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                     }
                 }
-                "00001" => {
-                    match funct3_slice_joined.as_str() {
-                        "00" => {
-                            println!("Floating Point Subtraction (FSUB.S) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("Register Two address: f{}", rs2_bits);
-                            println!("FSUB.S f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FSUB.S f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));  
-                        }
-                        "01" => {
-                            println!("Double Floating Point Subtraction (FSUB.D) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("Register Two address: f{}", rs2_bits);
-                            println!("FSUB.D f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FSUB.D f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));  
-                        }
-                        &_ => todo!()
+                0b00001 => {
+                    match fmt {
+                        0b00 => {
+                            return Ok(Instruction::FsubS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });  
+                        }
+                        0b01 => {
+                            return Ok(Instruction::FsubD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });  
+                        }
+                        0b10 => {
+                            return Ok(Instruction::FsubH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b11 => {
+                            return Ok(Instruction::FsubQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                     }
                 }
-                "00010" => {
-                   match funct3_slice_joined.as_str() {
-                        "00" => {
-                            println!("Floating Point Multiplication (FMUL.S) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("Register Two address: f{}", rs2_bits);
-                            println!("FMUL.S f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FMUL.S f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        "01" => {
-                            println!("Double Floating Point Multiplication (FMUL.D) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("Register Two address: f{}", rs2_bits);
-                            println!("FMUL.D f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FMUL.D f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        &_ => todo!()
+                0b00010 => {
+                   match fmt {
+                        0b00 => {
+                            return Ok(Instruction::FmulS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b01 => {
+                            return Ok(Instruction::FmulD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b10 => {
+                            return Ok(Instruction::FmulH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b11 => {
+                            return Ok(Instruction::FmulQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                    }
                 }
-                "00011" => {
-                    match funct3_slice_joined.as_str() {
-                        "00" => {
-                            println!("Floating Point Division (FDIV.S) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("Register Two address: f{}", rs2_bits);
-                            println!("FDIV.S f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FDIV.S f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        "01" => {
-                            println!("Double Floating Point Division (FDIV.D) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("Register Two address: f{}", rs2_bits); 
-                            println!("FDIV.D f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FDIV.D f{}, f{}, f{}, {}", rd_bits, rs1_bits, rs2_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        &_ => todo!()
+                0b00011 => {
+                    match fmt {
+                        0b00 => {
+                            return Ok(Instruction::FdivS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b01 => {
+                            return Ok(Instruction::FdivD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b10 => {
+                            return Ok(Instruction::FdivH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b11 => {
+                            return Ok(Instruction::FdivQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                     }
                 }
-                "01011" => {
-                    match funct3_slice_joined.as_str() {
-                        "00" => {
-                            println!("Floating Point Square Root (FSQRT.S) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("FSQRT.S f{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FSQRT.S f{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        "01" => {
-                            println!("Double Floating Point Square Root (FSQRT.D) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("FSQRT.D f{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FSQRT.D f{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        &_ => todo!()
+                0b01011 => {
+                    if rs2_bits != 0b00000 {
+                        return Err(DecodeError::ReservedFunct { word: instr, field: "rs2", value: rs2_bits });
+                    }
+                    match fmt {
+                        0b00 => {
+                            return Ok(Instruction::FsqrtS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b01 => {
+                            return Ok(Instruction::FsqrtD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b10 => {
+                            return Ok(Instruction::FsqrtH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        0b11 => {
+                            return Ok(Instruction::FsqrtQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                     }
                 }
-                "01000" => {
-                    match rs2_slice_joined.as_str() {
-                        "00000" => {
-                            println!("Double Conversion (FCVT.D.S) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("FCVT.D.S f{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FCVT.D.S f{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        "00001" => {
-                            println!("Double Conversion (FCVT.S.D) instruction decoded");
-                            println!("Destination Register address: f{}", rd_bits);
-                            println!("Register One address: f{}", rs1_bits);
-                            println!("FCVT.S.D f{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                            println!("--------------------------------");
-                            return format!("FCVT.S.D f{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                        }
-                        &_ => todo!()
+                0b01000 => {      // Conversions between formats, rs2 holds the source format
+                    match rs2_bits {
+                        0b00000 => {
+                            match fmt {
+                                0b01 => {
+                                    return Ok(Instruction::FcvtDS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtHS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtQS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b00001 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtSD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtHD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtQD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b00010 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtSH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtDH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtQH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b00011 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtSQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtDQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtHQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b00100 => {      // Zfa
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FroundS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FroundD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FroundH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FroundQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b00101 => {      // Zfa
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FroundnxS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FroundnxD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FroundnxH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FroundnxQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "rs2", value: rs2_bits }),
                     }
                 }
-                "00100" => {
-                    match rm_slice_joined.as_str() {
-                            "000" => {
-                                match funct3_slice_joined.as_str() {
-                                    "00" => {
-                                        println!("Floating Point Sign Injection (FSGNJ.S) instruction decoded");
-                                        println!("Destination Register address: f{}", rd_bits);
-                                        println!("Register One address: f{}", rs1_bits);
-                                        println!("Register Two address: f{}", rs2_bits);
-                                        println!("FSGNJ.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits); 
-                                        println!("--------------------------------");
-                                        return format!("FSGNJ.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
+                0b00100 => {
+                    match rm {
+                            0b000 => {
+                                match fmt {
+                                    0b00 => {
+                                        return Ok(Instruction::FsgnjS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                    }
+                                    0b01 => {
+                                        return Ok(Instruction::FsgnjD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                    }
+                                    0b10 => {
+                                        return Ok(Instruction::FsgnjH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
                                     }
-                                    "01" => {
-                                        println!("Double Sign Injection (FSGNJN.D) instruction decoded");
-                                        println!("Destination Register address: f{}", rd_bits);
-                                        println!("Register One address: f{}", rs1_bits);
-                                        println!("Register Two address: f{}", rs2_bits);
-                                        println!("FSGNJ.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits); 
-                                        println!("--------------------------------");
-                                        return format!("FSGNJ.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
+                                    0b11 => {
+                                        return Ok(Instruction::FsgnjQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
                                     }
-                                    &_ => todo!()
+                                    _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                                 }
                             }
-                            "001" => {
-                                match funct3_slice_joined.as_str() {
-                                    "00" => {
-                                        println!("Floating Point Sign Injection (FSGNJN.S) instruction decoded");
-                                        println!("Destination Register address: f{}", rd_bits);
-                                        println!("Register One address: f{}", rs1_bits);
-                                        println!("Register Two address: f{}", rs2_bits);
-                                        println!("FSGNJN.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits); 
-                                        println!("--------------------------------");
-                                        return format!("FSGNJN.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
+                            0b001 => {
+                                match fmt {
+                                    0b00 => {
+                                        return Ok(Instruction::FsgnjnS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
                                     }
-                                    "01" => {
-                                        println!("Double Sign Injection (FSGNJN.D) instruction decoded");
-                                        println!("Destination Register address: f{}", rd_bits);
-                                        println!("Register One address: f{}", rs1_bits);
-                                        println!("Register Two address: f{}", rs2_bits);
-                                        println!("FSGNJN.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits); 
-                                        println!("--------------------------------");
-                                        return format!("FSGNJN.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
+                                    0b01 => {
+                                        return Ok(Instruction::FsgnjnD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
                                     }
-                                    &_ => todo!()
+                                    0b10 => {
+                                        return Ok(Instruction::FsgnjnH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                    }
+                                    0b11 => {
+                                        return Ok(Instruction::FsgnjnQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                    }
+                                    _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                                 }
                             }
-                            "010" => {
-                                match funct3_slice_joined.as_str() {
-                                    "00" => {
-                                        println!("Floating Point Sign Injection (FSGNJX.S) instruction decoded");
-                                        println!("Destination Register address: f{}", rd_bits);
-                                        println!("Register One address: f{}", rs1_bits);
-                                        println!("Register Two address: f{}", rs2_bits);
-                                        println!("FSGNJX.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits); 
-                                        println!("--------------------------------");
-                                        return format!("FSGNJX.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
+                            0b010 => {
+                                match fmt {
+                                    0b00 => {
+                                        return Ok(Instruction::FsgnjxS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                    }
+                                    0b01 => {
+                                        return Ok(Instruction::FsgnjxD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                    }
+                                    0b10 => {
+                                        return Ok(Instruction::FsgnjxH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
                                     }
-                                    "01" => {
-                                        println!("Double Sign Injection (FSGNJX.D) instruction decoded");
-                                        println!("Destination Register address: f{}", rd_bits);
-                                        println!("Register One address: f{}", rs1_bits);
-                                        println!("Register Two address: f{}", rs2_bits);
-                                        println!("FSGNJX.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits); 
-                                        println!("--------------------------------");
-                                        return format!("FSGNJX.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
+                                    0b11 => {
+                                        return Ok(Instruction::FsgnjxQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
                                     }
-                                    &_ => todo!()
+                                    _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                                 }
                             }
-                            &_ => todo!()
+                            _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: rm }),
                     }
                 }
-                "00101" => {
-                    match rm_slice_joined.as_str() {
-                        "000" => {
-                            match funct3_slice_joined.as_str() {
-                                "00" => {
-                                    println!("Floating Point Minimum (FMIN.S) instruction decoded");
-                                    println!("Destination Register address: f{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FMIN.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FMIN.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                "01" => {
-                                    println!("Double Floating Point Minimum (FMIN.D) instruction decoded");
-                                    println!("Destination Register address: f{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FMIN.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FMIN.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                &_ => todo!()
+                0b00101 => {
+                    match rm {
+                        0b000 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FminS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FminD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FminH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FminQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b001 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FmaxS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FmaxD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FmaxH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FmaxQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b010 => {      // Zfa
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FminmS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FminmD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FminmH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FminmQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                             }
                         }
-                        "001" => {
-                            match funct3_slice_joined.as_str() {
-                                "00" => {
-                                    println!("Floating Point Maximum (FMAX.S) instruction decoded");
-                                    println!("Destination Register address: f{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FMAX.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FMAX.S f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                "01" => {
-                                    println!("Double Floating Point Maximum (FMAX.D) instruction decoded");
-                                    println!("Destination Register address: f{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FMAX.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FMAX.D f{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                &_ => todo!()
+                        0b011 => {      // Zfa
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FmaxmS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FmaxmD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FmaxmH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FmaxmQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                             }
                         }
-                        &_ => todo!()
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: rm }),
                     }
                 }
-                "11000" => {
-                    match rs2_slice_joined.as_str() {
-                        "00000" => {
-                            match funct3_slice_joined.as_str() {
-                                "00" => {
-                                    println!("Floating Point Conversion to Integer (FCVT.W.S) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("FCVT.W.S x{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                    println!("--------------------------------");
-                                    return format!("FCVT.W.S x{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                }
-                                "01" => {
-                                    println!("Double Conversion to Integer (FCVT.W.D) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("FCVT.W.D x{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                    println!("--------------------------------");
-                                    return format!("FCVT.W.D x{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                }
-                                &_ => todo!()
+                0b11000 => {
+                    match rs2_bits {
+                        0b00000 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtWS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtWD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtWH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtWQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b00001 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtWuS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtWuD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtWuH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtWuQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b00010 => {      // RV64 only
+                            config.require_rv64(instr)?;
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtLS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtLD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtLH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtLQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b00011 => {      // RV64 only
+                            config.require_rv64(instr)?;
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtLuS { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtLuD { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtLuH { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtLuQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                             }
                         }
-                        "00001" => {
-                            match funct3_slice_joined.as_str() {
-                                "00" => {
-                                    println!("Floating Point Conversion to Integer (FCVT.WU.S) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("FCVT.WU.S x{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                    println!("--------------------------------");
-                                    return format!("FCVT.WU.S x{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                }
-                                "01" => {
-                                    println!("Double Conversion to Integer (FCVT.WU.D) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("FCVT.WU.D x{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                    println!("--------------------------------");
-                                    return format!("FCVT.WU.D x{}, f{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                }
-                                &_ => todo!()
+                        0b01000 => {      // Zfa, only from double precision and only with RTZ
+                            if fmt != 0b01 {
+                                return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt });
                             }
+                            if rm != 0b001 {
+                                return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: rm });
+                            }
+                            return Ok(Instruction::FcvtmodWD { rd: rd_bits as u8, rs1: rs1_bits as u8 });
                         }
-                        &_ => todo!()
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "rs2", value: rs2_bits }),
                     }
                 }
-                "11100" => {
-                    match rm_slice_joined.as_str() {
-                    "000" => {
-                        println!("Floating Point Conversion to Integer (FMV.X.W) instruction decoded");
-                        println!("Destination Register address: x{}", rd_bits);
-                        println!("Register One address: f{}", rs1_bits);
-                        println!("FMV.X.W x{}, f{}", rd_bits, rs1_bits);
-                        println!("--------------------------------");
-                        return format!("FMV.X.W x{}, f{}", rd_bits, rs1_bits);
-                    }
-                    "001" => {
-                        match funct3_slice_joined.as_str() {
-                            "00" => {
-                                println!("Floating Point Class (FCLASS.S) instruction decoded");
-                                println!("Destination Register address: x{}", rd_bits);
-                                println!("Register One address: f{}", rs1_bits);
-                                println!("FCLASS.S x{}, f{}", rd_bits, rs1_bits);
-                                println!("--------------------------------");
-                                return format!("FCLASS.S x{}, f{}", rd_bits, rs1_bits);
+                0b11100 => {
+                    match rm {
+                        0b000 => {
+                            match rs2_bits {
+                                0b00000 => {
+                                    match fmt {
+                                        0b00 => {
+                                            return Ok(Instruction::FmvXW { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                        }
+                                        0b01 => {      // RV64 only
+                                            config.require_rv64(instr)?;
+                                            return Ok(Instruction::FmvXD { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                        }
+                                        0b10 => {
+                                            return Ok(Instruction::FmvXH { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                        }
+                                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                                    }
+                                }
+                                0b00001 => {      // Zfa, upper half of a double or quad
+                                    match fmt {
+                                        0b01 => {      // RV32 only
+                                            config.require_rv32(instr)?;
+                                            return Ok(Instruction::FmvhXD { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                        }
+                                        0b11 => {      // RV64 only
+                                            config.require_rv64(instr)?;
+                                            return Ok(Instruction::FmvhXQ { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                        }
+                                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                                    }
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "rs2", value: rs2_bits }),
+                            }
+                        }
+                        0b001 => {
+                            if rs2_bits != 0b00000 {
+                                return Err(DecodeError::ReservedFunct { word: instr, field: "rs2", value: rs2_bits });
                             }
-                            "01" => {
-                                println!("Double Class (FCLASS.D) instruction decoded");
-                                println!("Destination Register address: x{}", rd_bits);
-                                println!("Register One address: f{}", rs1_bits);
-                                println!("FCLASS.D x{}, f{}", rd_bits, rs1_bits);
-                                println!("--------------------------------");
-                                return format!("FCLASS.D x{}, f{}", rd_bits, rs1_bits);
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FclassS { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FclassD { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FclassH { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FclassQ { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                             }
-                            &_ => todo!()
                         }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: rm }),
                     }
-                    &_ => todo!()
+                }
+                0b10100 => {
+                    match rm {
+                        0b000 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FleS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FleD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FleH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FleQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b010 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FeqS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FeqD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FeqH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FeqQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b001 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FltS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FltD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FltH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FltQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b100 => {      // Zfa
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FleqS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FleqD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FleqH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FleqQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        0b101 => {      // Zfa
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FltqS { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FltqD { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FltqH { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FltqQ { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: rm }),
                     }
                 }
-                "10100" => {
-                    match rm_slice_joined.as_str() {
-                        "000" => {
-                            match funct3_slice_joined.as_str() {
-                                "00" => {
-                                    println!("Floating Point Conversion to Integer (FLE.S) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FLE.S x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FLE.S x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                "01" => {
-                                    println!("Double Conversion to Integer (FLE.D) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FLE.D x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FLE.D x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                &_ => todo!()
+                0b11010 => {
+                    match rs2_bits {
+                        0b00000 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtSW { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtDW { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtHW { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtQW { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                             }
                         }
-                        "010" => {
-                            match funct3_slice_joined.as_str() {
-                                "00" => {
-                                    println!("Floating Point Conversion to Integer (FEQ.S) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FEQ.S x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FEQ.S x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                "01" => {
-                                    println!("Double Conversion to Integer (FEQ.D) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FEQ.D x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FEQ.D x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                &_ => todo!()
+                        0b00001 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtSWu { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtDWu { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtHWu { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtQWu { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                             }
                         }
-                        "001" => {
-                            match funct3_slice_joined.as_str() {
-                                "00" => {
-                                    println!("Floating Point Conversion to Integer (FLT.S) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FLT.S x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FLT.S x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                "01" => {
-                                    println!("Double Conversion to Integer (FLT.D) instruction decoded");
-                                    println!("Destination Register address: x{}", rd_bits);
-                                    println!("Register One address: f{}", rs1_bits);
-                                    println!("Register Two address: f{}", rs2_bits);
-                                    println!("FLT.D x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                    println!("--------------------------------");
-                                    return format!("FLT.D x{}, f{}, f{}", rd_bits, rs1_bits, rs2_bits);
-                                }
-                                &_ => todo!()
+                        0b00010 => {      // RV64 only
+                            config.require_rv64(instr)?;
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtSL { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtDL { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtHL { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtQL { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                             }
                         }
-                        &_ => todo!()
+                        0b00011 => {      // RV64 only
+                            config.require_rv64(instr)?;
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FcvtSLu { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FcvtDLu { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FcvtHLu { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FcvtQLu { rd: rd_bits as u8, rs1: rs1_bits as u8, rm: rm_decoder(instr, rm)? });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                            }
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "rs2", value: rs2_bits }),
                     }
                 }
-                "11010" => {
-                    match rs2_slice_joined.as_str() {
-                        "00000" => {
-                            match funct3_slice_joined.as_str() {
-                                "00" => {
-                                    println!("Floating Point Conversion to Integer (FCVT.S.W) instruction decoded");
-                                    println!("Destination Register address: f{}", rd_bits);
-                                    println!("Register One address: x{}", rs1_bits);
-                                    println!("FCVT.S.W f{}, x{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                    println!("--------------------------------");
-                                    return format!("FCVT.S.W f{}, x{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                }
-                                "01" => {
-                                    println!("Double Conversion to Integer (FCVT.D.W) instruction decoded");
-                                    println!("Destination Register address: f{}", rd_bits);
-                                    println!("Register One address: x{}", rs1_bits);
-                                    println!("FCVT.D.W f{}, x{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                    println!("--------------------------------");
-                                    return format!("FCVT.D.W f{}, x{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                }
-                                &_ => todo!()
+                0b11110 => {
+                    match rs2_bits {
+                        0b00000 => {
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FmvWX { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                0b01 => {      // RV64 only
+                                    config.require_rv64(instr)?;
+                                    return Ok(Instruction::FmvDX { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FmvHX { rd: rd_bits as u8, rs1: rs1_bits as u8 });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                             }
                         }
-                        "00001" => {
-                            match funct3_slice_joined.as_str() {
-                                "00" => {
-                                    println!("Floating Point Conversion to Integer (FCVT.S.WU) instruction decoded");
-                                    println!("Destination Register address: f{}", rd_bits);
-                                    println!("Register One address: x{}", rs1_bits);
-                                    println!("FCVT.S.WU f{}, x{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                    println!("--------------------------------");
-                                    return format!("FCVT.S.WU f{}, x{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                }
-                                "01" => {
-                                    println!("Double Conversion to Integer (FCVT.D.WU) instruction decoded");
-                                    println!("Destination Register address: f{}", rd_bits);
-                                    println!("Register One address: x{}", rs1_bits);
-                                    println!("FCVT.D.WU f{}, x{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                    println!("--------------------------------");
-                                    return format!("FCVT.D.WU f{}, x{}, {}", rd_bits, rs1_bits, rm_decoder(&rm_slice_joined));
-                                }
-                                &_ => todo!()
+                        0b00001 => {      // Zfa, rs1 indexes the constant table
+                            if rm != 0b000 {
+                                return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: rm });
+                            }
+                            let constant = FliConstant::from_index(rs1_bits as u8).expect("rs1 is a 5-bit field");
+                            match fmt {
+                                0b00 => {
+                                    return Ok(Instruction::FliS { rd: rd_bits as u8, constant });
+                                }
+                                0b01 => {
+                                    return Ok(Instruction::FliD { rd: rd_bits as u8, constant });
+                                }
+                                0b10 => {
+                                    return Ok(Instruction::FliH { rd: rd_bits as u8, constant });
+                                }
+                                0b11 => {
+                                    return Ok(Instruction::FliQ { rd: rd_bits as u8, constant });
+                                }
+                                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
                             }
                         }
-                        &_ => todo!()
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "rs2", value: rs2_bits }),
                     }
                 }
-                "11110" => {
-                    println!("Floating Point Conversion to Integer (FMV.W.X) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("FMV.W.X f{}, x{}", rd_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("FMV.W.X f{}, x{}", rd_bits, rs1_bits);
+                0b10110 => {      // Zfa, rs1 and rs2 hold the lower and upper halves
+                    if rm != 0b000 {
+                        return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: rm });
+                    }
+                    match fmt {
+                        0b01 => {      // RV32 only
+                            config.require_rv32(instr)?;
+                            return Ok(Instruction::FmvpDX { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        0b11 => {      // RV64 only
+                            config.require_rv64(instr)?;
+                            return Ok(Instruction::FmvpQX { rd: rd_bits as u8, rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fmt", value: fmt }),
+                    }
                 }
-                &_ => todo!()
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct5", value: funct5 }),
             }
 
         }
-        "0000111" => {
-            let imm_slice = &instr[0..12];
-            let imm_slice_joined = imm_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let rd_slice = &instr[20..25];
-            let rd_slice_joined = rd_slice.join("");
-            let rm_slice = &instr[17..20];
-            let rm_slice_joined = rm_slice.join("");
-            
-            let mut rd_bits = i32::from_str_radix(&rd_slice_joined, 2).unwrap();
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let imm_bits = i32::from_str_radix(&imm_slice_joined, 2).unwrap();
-
-
-            match rm_slice_joined.as_str() {
-                "010" => {
-
-                    println!("Floating Point Load Word (FLW) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("FLW f{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("FLW f{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                }
-                "011" =>{
-                    println!("Load Double (FLD) instruction decoded");
-                    println!("Destination Register address: f{}", rd_bits);
-                    println!("Register One address: x{}", rs1_bits);
-                    println!("Immendiate: {}", imm_bits);
-                    println!("FLD f{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("FLD f{}, {}(x{})", rd_bits, imm_bits, rs1_bits);
-                }
-                &_ => todo!()
+        0b0000111 => {
+            let rs1_bits = bits(instr, 19, 15);
+            let width = bits(instr, 14, 12);
+            let rd_bits = bits(instr, 11, 7);
+            let imm_bits = i_imm(instr);
+
+            match width {
+                0b001 => {
+                    return Ok(Instruction::Flh { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b010 => {
+
+                    return Ok(Instruction::Flw { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b011 => {
+                    return Ok(Instruction::Fld { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b100 => {
+                    return Ok(Instruction::Flq { rd: rd_bits as u8, rs1: rs1_bits as u8, imm: imm_bits });
+                }
+                0b000 | 0b101 | 0b110 | 0b111 => {     // Vector loads, the width selects the element width
+                    return vector::decode_vector_memory(instr);
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "width", value: width }),
             }
 
         }
-        "0100111" => {
-            let rs2_slice = &instr[7..12];
-            let rs2_slice_joined = rs2_slice.join("");
-            let rs1_slice = &instr[12..17];
-            let rs1_slice_joined = rs1_slice.join("");
-            let imm1_slice = &instr[0..7];
-            let imm1_slice_joined = imm1_slice.join("");
-            let imm2_slice = &instr[20..25];
-            let imm2_slice_joined = imm2_slice.join("");
-            let imm_slice_joined = imm1_slice_joined + &imm2_slice_joined;
-            let rm_slice = &instr[17..20];
-            let rm_slice_joined = rm_slice.join("");
-            
-            let mut imm_bits = i32::from_str_radix(&imm_slice_joined, 2).unwrap();
-            let rs1_bits = i32::from_str_radix(&rs1_slice_joined, 2).unwrap();
-            let rs2_bits = i32::from_str_radix(&rs2_slice_joined, 2).unwrap();
-
-            match rm_slice_joined.as_str() {
-                "010" => {
-                    println!("Floating Point Store Word (FSW) instruction decoded");
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediates: {}", imm_bits);
-                    println!("FSW f{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("FSW f{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                }
-                "011" => {
-                    println!("Store Double (FSD) instruction decoded");
-                    println!("Register One address: f{}", rs1_bits);
-                    println!("Register Two address: x{}", rs2_bits);
-                    println!("Immediates: {}", imm_bits);
-                    println!("FSD f{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                    println!("--------------------------------");
-                    return format!("FSD f{}, {}(x{})", rs2_bits, imm_bits, rs1_bits);
-                }
-                &_ => todo!()
+        0b0100111 => {
+            let rs2_bits = bits(instr, 24, 20);
+            let rs1_bits = bits(instr, 19, 15);
+            let width = bits(instr, 14, 12);
+            let imm_bits = s_imm(instr);
+
+            match width {
+                0b001 => {
+                    return Ok(Instruction::Fsh { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b010 => {
+                    return Ok(Instruction::Fsw { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b011 => {
+                    return Ok(Instruction::Fsd { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b100 => {
+                    return Ok(Instruction::Fsq { rs1: rs1_bits as u8, rs2: rs2_bits as u8, imm: imm_bits });
+                }
+                0b000 | 0b101 | 0b110 | 0b111 => {     // Vector stores
+                    return vector::decode_vector_memory(instr);
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "width", value: width }),
+            }
+        }
+        0b0001111 => {      // MISC-MEM
+            let fm = bits(instr, 31, 28);
+            let pred = bits(instr, 27, 24);
+            let succ = bits(instr, 23, 20);
+            let funct3 = bits(instr, 14, 12);
+
+            match funct3 {
+                0b000 => {
+                    match fm {
+                        0b0000 => {
+                            if instr == 0x0100000f {        // FENCE w, 0 with rd = rs1 = x0
+                                return Ok(Instruction::Pause);
+                            }
+                            return Ok(Instruction::Fence { pred: pred as u8, succ: succ as u8 });
+                        }
+                        0b1000 => {     // Total store ordering, only defined for FENCE rw, rw
+                            if pred != 0b0011 || succ != 0b0011 {
+                                return Err(DecodeError::ReservedFunct { word: instr, field: "fm", value: fm });
+                            }
+                            return Ok(Instruction::FenceTso);
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "fm", value: fm }),
+                    }
+                }
+                0b001 => {      // Zifencei
+                    return Ok(Instruction::FenceI);
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 }),
+            }
+        }
+
+        0b1110011 => {      // SYSTEM
+            let funct12 = bits(instr, 31, 20);
+            let funct3 = bits(instr, 14, 12);
+            let rs1_bits = bits(instr, 19, 15);
+            let rd_bits = bits(instr, 11, 7);
+
+            match funct3 {
+                0b000 => {
+                    let funct7 = bits(instr, 31, 25);
+                    let rs2_bits = bits(instr, 24, 20);
+                    // Address translation fences take the virtual address in rs1 and the ASID in rs2
+                    if funct7 == 0b0001001 || funct7 == 0b0001011 {
+                        if rd_bits != 0 {
+                            return Err(DecodeError::ReservedFunct { word: instr, field: "rd", value: rd_bits });
+                        }
+                        if funct7 == 0b0001001 {
+                            return Ok(Instruction::SfenceVma { rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                        }
+                        return Ok(Instruction::SinvalVma { rs1: rs1_bits as u8, rs2: rs2_bits as u8 });
+                    }
+                    if rs1_bits != 0 {
+                        return Err(DecodeError::ReservedFunct { word: instr, field: "rs1", value: rs1_bits });
+                    }
+                    if rd_bits != 0 {
+                        return Err(DecodeError::ReservedFunct { word: instr, field: "rd", value: rd_bits });
+                    }
+                    match funct12 {
+                        0b000000000000 => {     // Environment call
+                            return Ok(Instruction::Ecall);
+                        }
+                        0b000000000001 => {     // Environment breakpoint
+                            return Ok(Instruction::Ebreak);
+                        }
+                        0b000100000010 => {     // Supervisor trap return
+                            return Ok(Instruction::Sret);
+                        }
+                        0b001100000010 => {     // Machine trap return
+                            return Ok(Instruction::Mret);
+                        }
+                        0b011100000010 => {     // Resumable NMI trap return
+                            return Ok(Instruction::Mnret);
+                        }
+                        0b011110110010 => {     // Debug mode return
+                            return Ok(Instruction::Dret);
+                        }
+                        0b000100000101 => {     // Wait for interrupt
+                            return Ok(Instruction::Wfi);
+                        }
+                        0b000110000000 => {     // Order stores before the SINVAL.VMA that follow
+                            return Ok(Instruction::SfenceWInval);
+                        }
+                        0b000110000001 => {     // Order SINVAL.VMA before the implicit references that follow
+                            return Ok(Instruction::SfenceInvalIr);
+                        }
+                        _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct12", value: funct12 }),
+                    }
+                }
+                0b001 => {      // Atomic read/write CSR
+                    return Ok(Instruction::Csrrw { rd: rd_bits as u8, rs1: rs1_bits as u8, csr: funct12 as u16 });
+                }
+                0b010 => {      // Atomic read and set bits in CSR
+                    return Ok(Instruction::Csrrs { rd: rd_bits as u8, rs1: rs1_bits as u8, csr: funct12 as u16 });
+                }
+                0b011 => {      // Atomic read and clear bits in CSR
+                    return Ok(Instruction::Csrrc { rd: rd_bits as u8, rs1: rs1_bits as u8, csr: funct12 as u16 });
+                }
+                0b101 => {      // Immediate forms, the rs1 field holds uimm[4:0]
+                    return Ok(Instruction::Csrrwi { rd: rd_bits as u8, uimm: rs1_bits as u8, csr: funct12 as u16 });
+                }
+                0b110 => {
+                    return Ok(Instruction::Csrrsi { rd: rd_bits as u8, uimm: rs1_bits as u8, csr: funct12 as u16 });
+                }
+                0b111 => {
+                    return Ok(Instruction::Csrrci { rd: rd_bits as u8, uimm: rs1_bits as u8, csr: funct12 as u16 });
+                }
+                _ => return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 }),
             }
         }
-        default => {
-            panic!("Opcode not found!");
+        0b1010111 => {      // OP-V vector arithmetic and configuration
+            return vector::decode_op_v(instr);
+        }
+        0b1110111 => {      // OP-VE vector cryptography
+            return vector::decode_op_ve(instr);
+        }
+        _ => {
+            return Err(DecodeError::UnknownOpcode { word: instr, opcode });
         }
-    &_ => todo!()
     }
 }
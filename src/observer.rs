@@ -0,0 +1,126 @@
+use std::io::{self, Write};
+
+use crate::csr::csr_text;
+use crate::instruction::fence_set;
+use crate::{DecodeError, DecoderConfig, Field, Instruction};
+
+/// Receives a structured breakdown of each instruction as it is decoded
+///
+/// All methods default to doing nothing, so an observer only implements the
+/// events it cares about. Events for one word arrive in the order
+/// `instruction`, `field` (once per operand), `decoded`, or just `error` if
+/// the word could not be decoded.
+pub trait DecodeObserver {
+    /// Called once the instruction has been identified
+    fn instruction(&mut self, word: u32, instruction: &Instruction) {}
+
+    /// Called for each operand field, in the order given by `Instruction::fields`
+    fn field(&mut self, field: Field) {}
+
+    /// Called after all fields have been reported
+    fn decoded(&mut self, instruction: &Instruction) {}
+
+    /// Called instead of the other events when the word could not be decoded
+    fn error(&mut self, error: &DecodeError) {}
+}
+
+/// Decodes a RISC-V 32-bit instruction word, reporting the breakdown to `observer`
+///
+/// Decodes for RV32, use `DecoderConfig` to select RV64.
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{decode_with_observer, VerboseObserver};
+///
+/// let mut out = Vec::new();
+/// decode_with_observer(0x00400203, &mut VerboseObserver::new(&mut out)).unwrap();
+/// let text = String::from_utf8(out).unwrap();
+/// assert!(text.contains("Load Byte (LB) instruction decoded"));
+/// assert!(text.contains("Destination Register address: x4"));
+/// ```
+pub fn decode_with_observer<O: DecodeObserver>(instr: u32, observer: &mut O) -> Result<Instruction, DecodeError> {
+    return decode_with_observer_and_config(instr, observer, &DecoderConfig::default());
+}
+
+pub(crate) fn decode_with_observer_and_config<O: DecodeObserver>(instr: u32, observer: &mut O, config: &DecoderConfig) -> Result<Instruction, DecodeError> {
+    match config.decode(instr) {
+        Ok(decoded) => {
+            observer.instruction(instr, &decoded);
+            for field in decoded.fields() {
+                observer.field(field);
+            }
+            observer.decoded(&decoded);
+            return Ok(decoded);
+        }
+        Err(err) => {
+            observer.error(&err);
+            return Err(err);
+        }
+    }
+}
+
+/// Observer that writes the teaching-style, field by field breakdown of each instruction
+pub struct VerboseObserver<W: Write> {
+    out: W,
+}
+
+impl VerboseObserver<io::Stdout> {
+    /// Creates an observer printing to standard output
+    pub fn stdout() -> Self {
+        VerboseObserver { out: io::stdout() }
+    }
+}
+
+impl<W: Write> VerboseObserver<W> {
+    /// Creates an observer writing to `out`
+    pub fn new(out: W) -> Self {
+        VerboseObserver { out }
+    }
+
+    /// Returns the underlying writer
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+// Write errors are ignored, the breakdown is best-effort diagnostics output
+impl<W: Write> DecodeObserver for VerboseObserver<W> {
+    fn instruction(&mut self, word: u32, instruction: &Instruction) {
+        let _ = writeln!(self.out, "--------------------------------");
+        let _ = writeln!(self.out, "{} ({}) instruction decoded", instruction.description(), instruction.mnemonic());
+    }
+
+    fn field(&mut self, field: Field) {
+        let _ = match field {
+            Field::Rd(reg) => writeln!(self.out, "Destination Register address: {}", reg),
+            Field::Rs1(reg) => writeln!(self.out, "Register One address: {}", reg),
+            Field::Rs2(reg) => writeln!(self.out, "Register Two address: {}", reg),
+            Field::Rs3(reg) => writeln!(self.out, "Register Three address: {}", reg),
+            Field::Imm(imm) => writeln!(self.out, "Immediate value: {}", imm),
+            Field::Rm(rm) => writeln!(self.out, "Rounding mode: {}", rm),
+            Field::Shamt(shamt) => writeln!(self.out, "Shift amount: {}", shamt),
+            Field::Pred(set) => writeln!(self.out, "Predecessor set: {}", fence_set(set)),
+            Field::Succ(set) => writeln!(self.out, "Successor set: {}", fence_set(set)),
+            Field::Aq(aq) => writeln!(self.out, "Acquire bit: {}", aq as u8),
+            Field::Rl(rl) => writeln!(self.out, "Release bit: {}", rl as u8),
+            Field::Csr(csr) => writeln!(self.out, "CSR: {} ({:#05x})", csr_text(csr), csr),
+            Field::Bs(bs) => writeln!(self.out, "Byte select: {}", bs),
+            Field::Rnum(rnum) => writeln!(self.out, "Round number: {}", rnum),
+            Field::Vm(vm) => writeln!(self.out, "Vector mask: {}", if vm { "unmasked" } else { "v0.t" }),
+            Field::Vtype(vtype) => writeln!(self.out, "Vector type: {}", vtype),
+            Field::Fli(constant) => writeln!(self.out, "FLI constant: {} (index {})", constant, constant.index()),
+        };
+    }
+
+    fn decoded(&mut self, instruction: &Instruction) {
+        let _ = writeln!(self.out, "{}", instruction);
+        let _ = writeln!(self.out, "--------------------------------");
+    }
+
+    fn error(&mut self, error: &DecodeError) {
+        let _ = writeln!(self.out, "--------------------------------");
+        let _ = writeln!(self.out, "Decoding failed: {}", error);
+        let _ = writeln!(self.out, "--------------------------------");
+    }
+}
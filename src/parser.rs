@@ -0,0 +1,946 @@
+use std::str::FromStr;
+
+use crate::csr::csr_number;
+use crate::encoder::encode_with_config;
+use crate::vector::{self, Shape};
+use crate::{DecoderConfig, EncodeError, FliConstant, Instruction, ParseError, ParseErrorKind, Register, RoundingMode};
+use crate::{Lmul, Sew, VectorAddressing, VectorOperand, VectorUnaryOp, Vtype};
+
+/*
+ * Parses the assembly syntax produced by `Display for Instruction`, e.g.
+ *
+ *   LB x4, 4(x0)
+ *   FMADD.S f1, f2, f3, f4, RNE
+ *   AMOADD.W x1, x2, x3
+ *   CSRRS x5, mstatus, x0
+ *   VADD.VI v1, v2, -3, v0.t
+ *   VSETVLI x1, x2, e32, m2, ta, ma
+ *
+ * Mnemonics, register names, rounding modes and CSR names are
+ * case-insensitive, ABI register names (`sp`, `a0`, `fs1`, ...) are accepted
+ * and immediates may be decimal or `0x` hexadecimal. CSRs may also be given
+ * by number. A trailing rounding mode may be omitted, in which case it
+ * defaults to DYN. A vtype setting needs only its element width, LMUL
+ * defaults to m1 and the tail and mask policies to undisturbed (tu, mu).
+ */
+
+const X_ABI_NAMES: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4", "a5",
+    "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4", "t5", "t6",
+];
+
+const F_ABI_NAMES: [&str; 32] = [
+    "ft0", "ft1", "ft2", "ft3", "ft4", "ft5", "ft6", "ft7", "fs0", "fs1", "fa0", "fa1", "fa2", "fa3", "fa4", "fa5",
+    "fa6", "fa7", "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9", "fs10", "fs11", "ft8", "ft9", "ft10", "ft11",
+];
+
+/// Looks up an architectural (`x5`, `f10`, `v8`) or ABI (`t0`, `fa0`) register name
+pub(crate) fn register_from_name(name: &str) -> Option<Register> {
+    let name = name.to_ascii_lowercase();
+    if name == "fp" {
+        return Some(Register::X(8));
+    }
+    if let Some(n) = X_ABI_NAMES.iter().position(|abi| *abi == name) {
+        return Some(Register::X(n as u8));
+    }
+    if let Some(n) = F_ABI_NAMES.iter().position(|abi| *abi == name) {
+        return Some(Register::F(n as u8));
+    }
+    let mut chars = name.chars();
+    let class = chars.next()?;
+    let number = chars.as_str();
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) || (number.len() > 1 && number.starts_with('0')) {
+        return None;
+    }
+    match (class, number.parse::<u8>()) {
+        ('x', Ok(n)) if n < 32 => Some(Register::X(n)),
+        ('f', Ok(n)) if n < 32 => Some(Register::F(n)),
+        ('v', Ok(n)) if n < 32 => Some(Register::V(n)),
+        _ => None,
+    }
+}
+
+fn rounding_mode_from_name(name: &str) -> Option<RoundingMode> {
+    match name.to_ascii_uppercase().as_str() {
+        "RNE" => Some(RoundingMode::Rne),
+        "RTZ" => Some(RoundingMode::Rtz),
+        "RDN" => Some(RoundingMode::Rdn),
+        "RUP" => Some(RoundingMode::Rup),
+        "RMM" => Some(RoundingMode::Rmm),
+        "DYN" => Some(RoundingMode::Dyn),
+        _ => None,
+    }
+}
+
+/// Parses a decimal or `0x` hexadecimal immediate with an optional sign
+fn immediate_from_text(text: &str) -> Option<i64> {
+    let (negative, digits) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let value = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        i64::from_str_radix(hex, 16).ok()?
+    } else if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        digits.parse::<i64>().ok()?
+    } else {
+        return None;
+    };
+    return Some(if negative { -value } else { value });
+}
+
+/// One part of a vtype setting, e.g. `e32` or `ta`
+#[derive(Debug, Clone, Copy)]
+enum VtypePart {
+    Sew(Sew),
+    Lmul(Lmul),
+    TailAgnostic(bool),
+    MaskAgnostic(bool),
+}
+
+fn vtype_part_from_name(name: &str) -> Option<VtypePart> {
+    let part = match name.to_ascii_lowercase().as_str() {
+        "e8" => VtypePart::Sew(Sew::E8),
+        "e16" => VtypePart::Sew(Sew::E16),
+        "e32" => VtypePart::Sew(Sew::E32),
+        "e64" => VtypePart::Sew(Sew::E64),
+        "mf8" => VtypePart::Lmul(Lmul::Mf8),
+        "mf4" => VtypePart::Lmul(Lmul::Mf4),
+        "mf2" => VtypePart::Lmul(Lmul::Mf2),
+        "m1" => VtypePart::Lmul(Lmul::M1),
+        "m2" => VtypePart::Lmul(Lmul::M2),
+        "m4" => VtypePart::Lmul(Lmul::M4),
+        "m8" => VtypePart::Lmul(Lmul::M8),
+        "ta" => VtypePart::TailAgnostic(true),
+        "tu" => VtypePart::TailAgnostic(false),
+        "ma" => VtypePart::MaskAgnostic(true),
+        "mu" => VtypePart::MaskAgnostic(false),
+        _ => return None,
+    };
+    return Some(part);
+}
+
+/// Parses a FENCE predecessor/successor set such as `iorw` or `rw`
+fn fence_set_from_text(text: &str) -> Option<u8> {
+    let mut set = 0;
+    for c in text.to_ascii_lowercase().chars() {
+        let bit = match c {
+            'i' => 0b1000,
+            'o' => 0b0100,
+            'r' => 0b0010,
+            'w' => 0b0001,
+            _ => return None,
+        };
+        if set & bit != 0 {
+            return None;
+        }
+        set |= bit;
+    }
+    return Some(set);
+}
+
+/// Splits the `.AQ`, `.RL` or `.AQRL` ordering suffix off an atomic mnemonic
+fn split_ordering(mnemonic: &str) -> (&str, bool, bool) {
+    let atomic = ["LR.", "SC.", "AMO"].iter().any(|prefix| mnemonic.starts_with(prefix));
+    for (suffix, aq, rl) in [(".AQRL", true, true), (".AQ", true, false), (".RL", false, true)] {
+        if atomic && mnemonic.ends_with(suffix) {
+            return (&mnemonic[..mnemonic.len() - suffix.len()], aq, rl);
+        }
+    }
+    return (mnemonic, false, false);
+}
+
+fn error(column: usize, kind: ParseErrorKind) -> ParseError {
+    ParseError { column, kind }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operand {
+    Register(Register),
+    Immediate(i64),
+    Memory { imm: i64, base: Register, base_column: usize },
+    RoundingMode(RoundingMode),
+    FenceSet(u8),
+    Csr(u16),
+    Vtype(VtypePart),
+    Mask,       // v0.t
+    Fli(FliConstant),
+}
+
+/// Parses one comma separated operand starting at `column`
+fn operand_from_text(text: &str, column: usize) -> Result<Operand, ParseError> {
+    if text.is_empty() {
+        return Err(error(column, ParseErrorKind::MissingOperand));
+    }
+    if let Some(open) = text.find('(') {
+        if !text.ends_with(')') {
+            return Err(error(column, ParseErrorKind::InvalidOperand(text.to_string())));
+        }
+        let offset = text[..open].trim_end();
+        let imm = if offset.is_empty() {
+            0
+        } else {
+            match immediate_from_text(offset) {
+                Some(imm) => imm,
+                None => return Err(error(column, ParseErrorKind::ExpectedImmediate)),
+            }
+        };
+        let inner = &text[open + 1..text.len() - 1];
+        let leading = inner.chars().count() - inner.trim_start().chars().count();
+        let base_column = column + text[..=open].chars().count() + leading;
+        return match register_from_name(inner.trim()) {
+            Some(base) => Ok(Operand::Memory { imm, base, base_column }),
+            None => Err(error(base_column, ParseErrorKind::ExpectedIntRegister)),
+        };
+    }
+    if text.eq_ignore_ascii_case("v0.t") {
+        return Ok(Operand::Mask);
+    }
+    if let Some(register) = register_from_name(text) {
+        return Ok(Operand::Register(register));
+    }
+    if let Some(rm) = rounding_mode_from_name(text) {
+        return Ok(Operand::RoundingMode(rm));
+    }
+    if let Some(imm) = immediate_from_text(text) {
+        return Ok(Operand::Immediate(imm));
+    }
+    if let Some(constant) = FliConstant::from_text(text) {
+        return Ok(Operand::Fli(constant));
+    }
+    if let Some(part) = vtype_part_from_name(text) {
+        return Ok(Operand::Vtype(part));
+    }
+    if let Some(csr) = csr_number(text) {
+        return Ok(Operand::Csr(csr));
+    }
+    if let Some(set) = fence_set_from_text(text) {
+        return Ok(Operand::FenceSet(set));
+    }
+    return Err(error(column, ParseErrorKind::InvalidOperand(text.to_string())));
+}
+
+/// Operand list of one instruction, consumed left to right by the mnemonic table
+struct Operands {
+    ops: Vec<(usize, Operand)>,
+    next: usize,
+    end_column: usize,
+    imm_column: Option<usize>,
+}
+
+impl Operands {
+    fn take(&mut self) -> Result<(usize, Operand), ParseError> {
+        match self.ops.get(self.next) {
+            Some(&op) => {
+                self.next += 1;
+                return Ok(op);
+            }
+            None => return Err(error(self.end_column, ParseErrorKind::MissingOperand)),
+        }
+    }
+
+    fn x(&mut self) -> Result<u8, ParseError> {
+        match self.take()? {
+            (_, Operand::Register(Register::X(n))) => Ok(n),
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedIntRegister)),
+        }
+    }
+
+    fn f(&mut self) -> Result<u8, ParseError> {
+        match self.take()? {
+            (_, Operand::Register(Register::F(n))) => Ok(n),
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedFloatRegister)),
+        }
+    }
+
+    fn v(&mut self) -> Result<u8, ParseError> {
+        match self.take()? {
+            (_, Operand::Register(Register::V(n))) => Ok(n),
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedVectorRegister)),
+        }
+    }
+
+    /// A trailing `v0.t` masks the operation, returns the vm bit
+    fn vm(&mut self) -> bool {
+        if let Some(&(_, Operand::Mask)) = self.ops.get(self.next) {
+            self.next += 1;
+            return false;
+        }
+        return true;
+    }
+
+    /// The v0 operand of the carry and merge forms
+    fn v0(&mut self) -> Result<(), ParseError> {
+        match self.take()? {
+            (_, Operand::Register(Register::V(0))) => Ok(()),
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedMaskRegister)),
+        }
+    }
+
+    fn imm_in_range(&mut self, column: usize, imm: i64) -> Result<i32, ParseError> {
+        self.imm_column = Some(column);
+        if imm < i32::MIN as i64 || imm > i32::MAX as i64 {
+            let err = EncodeError::ImmediateOutOfRange { imm, min: i32::MIN as i64, max: i32::MAX as i64 };
+            return Err(error(column, ParseErrorKind::Encode(err)));
+        }
+        return Ok(imm as i32);
+    }
+
+    fn imm(&mut self) -> Result<i32, ParseError> {
+        match self.take()? {
+            (column, Operand::Immediate(imm)) => self.imm_in_range(column, imm),
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedImmediate)),
+        }
+    }
+
+    /// Upper immediates may also be written as the unsigned 20-bit field value
+    fn upper_imm(&mut self) -> Result<i32, ParseError> {
+        let imm = self.imm()?;
+        if (0x80000..=0xfffff).contains(&imm) {
+            return Ok(imm - 0x100000);
+        }
+        return Ok(imm);
+    }
+
+    /// Unsigned field such as a shift amount, range checked against `max`
+    fn unsigned_imm(&mut self, max: i64) -> Result<u8, ParseError> {
+        match self.take()? {
+            (column, Operand::Immediate(imm)) => {
+                self.imm_column = Some(column);
+                if imm < 0 || imm > max {
+                    let err = EncodeError::ImmediateOutOfRange { imm, min: 0, max };
+                    return Err(error(column, ParseErrorKind::Encode(err)));
+                }
+                Ok(imm as u8)
+            }
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedImmediate)),
+        }
+    }
+
+    /// Shift amount of `width` bits
+    fn shamt(&mut self, width: u32) -> Result<u8, ParseError> {
+        return self.unsigned_imm((1 << width) - 1);
+    }
+
+    /// FENCE sets are written as `iorw` letters or as the raw 4-bit field
+    fn fence_set(&mut self) -> Result<u8, ParseError> {
+        if let Some(&(_, Operand::FenceSet(set))) = self.ops.get(self.next) {
+            self.next += 1;
+            return Ok(set);
+        }
+        return self.unsigned_imm(0b1111);
+    }
+
+    /// CSRs are written by name or as the 12-bit CSR number
+    fn csr(&mut self) -> Result<u16, ParseError> {
+        match self.take()? {
+            (_, Operand::Csr(csr)) => Ok(csr),
+            (column, Operand::Immediate(imm)) => {
+                self.imm_column = Some(column);
+                if !(0..=0xfff).contains(&imm) {
+                    let err = EncodeError::ImmediateOutOfRange { imm, min: 0, max: 0xfff };
+                    return Err(error(column, ParseErrorKind::Encode(err)));
+                }
+                Ok(imm as u16)
+            }
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedCsr)),
+        }
+    }
+
+    fn mem(&mut self) -> Result<(i32, u8), ParseError> {
+        match self.take()? {
+            (column, Operand::Memory { imm, base: Register::X(base), .. }) => Ok((self.imm_in_range(column, imm)?, base)),
+            (_, Operand::Memory { base_column, .. }) => Err(error(base_column, ParseErrorKind::ExpectedIntRegister)),
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedMemory)),
+        }
+    }
+
+    /// Vector loads and stores take a plain `(reg)` base without offset
+    fn vmem(&mut self) -> Result<u8, ParseError> {
+        match self.take()? {
+            (_, Operand::Memory { imm: 0, base: Register::X(base), .. }) => Ok(base),
+            (column, Operand::Memory { imm, base: Register::X(_), .. }) => {
+                let err = EncodeError::ImmediateOutOfRange { imm, min: 0, max: 0 };
+                Err(error(column, ParseErrorKind::Encode(err)))
+            }
+            (_, Operand::Memory { base_column, .. }) => Err(error(base_column, ParseErrorKind::ExpectedIntRegister)),
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedMemory)),
+        }
+    }
+
+    /// The immediate of the .VI forms, range checked against `min..=max`
+    fn vector_imm(&mut self, (min, max): (i64, i64)) -> Result<i8, ParseError> {
+        match self.take()? {
+            (column, Operand::Immediate(imm)) => {
+                self.imm_column = Some(column);
+                if !(min..=max).contains(&imm) {
+                    let err = EncodeError::ImmediateOutOfRange { imm, min, max };
+                    return Err(error(column, ParseErrorKind::Encode(err)));
+                }
+                Ok(imm as i8)
+            }
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedImmediate)),
+        }
+    }
+
+    /// Source operand of a vector arithmetic instruction, `form` is the
+    /// operand form letter of the mnemonic suffix
+    fn vector_source(&mut self, form: char, range: (i64, i64)) -> Result<VectorOperand, ParseError> {
+        match form {
+            'V' => Ok(VectorOperand::Vector(self.v()?)),
+            'X' => Ok(VectorOperand::Scalar(self.x()?)),
+            'F' => Ok(VectorOperand::Float(self.f()?)),
+            _ => Ok(VectorOperand::Imm(self.vector_imm(range)?)),
+        }
+    }
+
+    /// vtype settings are written as `e32, m2, ta, ma`, the parts after the
+    /// element width may be left out
+    fn vtype(&mut self) -> Result<Vtype, ParseError> {
+        let sew = match self.take()? {
+            (_, Operand::Vtype(VtypePart::Sew(sew))) => sew,
+            (column, _) => return Err(error(column, ParseErrorKind::ExpectedVtype)),
+        };
+        let mut vtype = Vtype { sew, lmul: Lmul::M1, tail_agnostic: false, mask_agnostic: false };
+        if let Some(&(_, Operand::Vtype(VtypePart::Lmul(lmul)))) = self.ops.get(self.next) {
+            self.next += 1;
+            vtype.lmul = lmul;
+        }
+        if let Some(&(_, Operand::Vtype(VtypePart::TailAgnostic(ta)))) = self.ops.get(self.next) {
+            self.next += 1;
+            vtype.tail_agnostic = ta;
+        }
+        if let Some(&(_, Operand::Vtype(VtypePart::MaskAgnostic(ma)))) = self.ops.get(self.next) {
+            self.next += 1;
+            vtype.mask_agnostic = ma;
+        }
+        return Ok(vtype);
+    }
+
+    /// A missing trailing rounding mode defaults to DYN
+    fn rm(&mut self) -> Result<RoundingMode, ParseError> {
+        if self.next == self.ops.len() {
+            return Ok(RoundingMode::Dyn);
+        }
+        match self.take()? {
+            (_, Operand::RoundingMode(rm)) => Ok(rm),
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedRoundingMode)),
+        }
+    }
+
+    /// The only rounding mode FCVTMOD.W.D takes, written out like the others
+    fn rtz(&mut self) -> Result<(), ParseError> {
+        match self.take()? {
+            (_, Operand::RoundingMode(RoundingMode::Rtz)) => Ok(()),
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedRoundingMode)),
+        }
+    }
+
+    /// FLI constant, integral ones such as `2` may also be written as integers
+    fn fli(&mut self) -> Result<FliConstant, ParseError> {
+        match self.take()? {
+            (_, Operand::Fli(constant)) => Ok(constant),
+            (column, Operand::Immediate(imm)) => {
+                FliConstant::from_text(&imm.to_string()).ok_or(error(column, ParseErrorKind::ExpectedFliConstant))
+            }
+            (column, _) => Err(error(column, ParseErrorKind::ExpectedFliConstant)),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        return self.ops.is_empty();
+    }
+
+    fn finish(&self) -> Result<(), ParseError> {
+        match self.ops.get(self.next) {
+            Some(&(column, _)) => Err(error(column, ParseErrorKind::UnexpectedOperand)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses the vector loads, stores and arithmetic, whose mnemonics spell out
+/// the element width, segment count or operand form
+fn vector_from_text(mnemonic: &str, ops: &mut Operands) -> Result<Option<Instruction>, ParseError> {
+    use Instruction::*;
+    if let Some((load, addressing, eew, nf)) = vector::memory_from_mnemonic(mnemonic) {
+        let data = ops.v()?;
+        let rs1 = ops.vmem()?;
+        let addressing = match addressing {
+            VectorAddressing::Strided { .. } => VectorAddressing::Strided { rs2: ops.x()? },
+            VectorAddressing::IndexedUnordered { .. } => VectorAddressing::IndexedUnordered { vs2: ops.v()? },
+            VectorAddressing::IndexedOrdered { .. } => VectorAddressing::IndexedOrdered { vs2: ops.v()? },
+            other => other,
+        };
+        let vm = ops.vm();
+        if load {
+            return Ok(Some(VectorLoad { vd: data, rs1, addressing, eew, nf, vm }));
+        }
+        return Ok(Some(VectorStore { vs3: data, rs1, addressing, eew, nf, vm }));
+    }
+    if let Some(op) = vector::unary_from_mnemonic(mnemonic) {
+        let vd = match op.dest() {
+            'x' => ops.x()?,
+            'f' => ops.f()?,
+            _ => ops.v()?,
+        };
+        let vs2 = if op == VectorUnaryOp::VidV { 0 } else { ops.v()? };
+        return Ok(Some(VectorUnary { op, vd, vs2, vm: ops.vm() }));
+    }
+    if let Some((op, form, carry)) = vector::arith_from_mnemonic(mnemonic) {
+        let vd = ops.v()?;
+        let range = op.imm_range();
+        let (vs2, src) = match op.shape() {
+            Shape::MulAdd => {
+                let src = ops.vector_source(form, range)?;
+                (ops.v()?, src)
+            }
+            Shape::Move | Shape::MoveScalar => (0, ops.vector_source(form, range)?),
+            _ => {
+                let vs2 = ops.v()?;
+                (vs2, ops.vector_source(form, range)?)
+            }
+        };
+        let vm = if carry {
+            ops.v0()?;
+            false
+        } else if op.shape() == Shape::CarryOut {
+            true
+        } else {
+            ops.vm()
+        };
+        return Ok(Some(VectorArith { op, vd, vs2, src, vm }));
+    }
+    return Ok(None);
+}
+
+/// Splits `line` into its mnemonic and operand list, tracking 1-based columns
+fn split_line(line: &str) -> Result<(usize, &str, Operands), ParseError> {
+    let column_of = |byte: usize| line[..byte].chars().count() + 1;
+    let start = line.len() - line.trim_start().len();
+    let rest = &line[start..];
+    let mnemonic_len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    let mnemonic = &rest[..mnemonic_len];
+    if mnemonic.is_empty() {
+        return Err(error(column_of(start), ParseErrorKind::Empty));
+    }
+
+    let operands_start = start + mnemonic_len;
+    let operands_text = &line[operands_start..];
+    let mut ops = Vec::new();
+    if !operands_text.trim().is_empty() {
+        let mut offset = operands_start;
+        for piece in operands_text.split(',') {
+            let leading = piece.len() - piece.trim_start().len();
+            let column = column_of(offset + leading);
+            ops.push((column, operand_from_text(piece.trim(), column)?));
+            offset += piece.len() + 1;
+        }
+    }
+    let end_column = column_of(line.trim_end().len());
+    let operands = Operands { ops, next: 0, end_column, imm_column: None };
+    return Ok((column_of(start), mnemonic, operands));
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    /// Parses one line of assembly text into an `Instruction`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use rv_decoder::{Instruction, RoundingMode};
+    ///
+    /// let instr: Instruction = "sw a4, 0x8(sp)".parse().unwrap();
+    /// assert_eq!(instr, Instruction::Sw { rs1: 2, rs2: 14, imm: 8 });
+    ///
+    /// let instr: Instruction = "FMADD.S f1, f2, f3, f4, RNE".parse().unwrap();
+    /// assert_eq!(instr.to_string(), "FMADD.S f1, f2, f3, f4, RNE");
+    ///
+    /// let err = "ADDI x1, x2, f3".parse::<Instruction>().unwrap_err();
+    /// assert_eq!(err.column, 14);
+    /// ```
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        return parse_with_config(line, &DecoderConfig::default());
+    }
+}
+
+pub(crate) fn parse_with_config(line: &str, config: &DecoderConfig) -> Result<Instruction, ParseError> {
+    use Instruction::*;
+    let (mnemonic_column, mnemonic, mut ops) = split_line(line)?;
+    let upper = mnemonic.to_ascii_uppercase();
+    let (base, aq, rl) = split_ordering(&upper);
+    let instruction = match base {
+        "LB" => { let rd = ops.x()?; let (imm, rs1) = ops.mem()?; Lb { rd, rs1, imm } }
+        "LH" => { let rd = ops.x()?; let (imm, rs1) = ops.mem()?; Lh { rd, rs1, imm } }
+        "LW" => { let rd = ops.x()?; let (imm, rs1) = ops.mem()?; Lw { rd, rs1, imm } }
+        "LBU" => { let rd = ops.x()?; let (imm, rs1) = ops.mem()?; Lbu { rd, rs1, imm } }
+        "LHU" => { let rd = ops.x()?; let (imm, rs1) = ops.mem()?; Lhu { rd, rs1, imm } }
+        "LWU" => { let rd = ops.x()?; let (imm, rs1) = ops.mem()?; Lwu { rd, rs1, imm } }
+        "LD" => { let rd = ops.x()?; let (imm, rs1) = ops.mem()?; Ld { rd, rs1, imm } }
+        "SB" => { let rs2 = ops.x()?; let (imm, rs1) = ops.mem()?; Sb { rs1, rs2, imm } }
+        "SH" => { let rs2 = ops.x()?; let (imm, rs1) = ops.mem()?; Sh { rs1, rs2, imm } }
+        "SW" => { let rs2 = ops.x()?; let (imm, rs1) = ops.mem()?; Sw { rs1, rs2, imm } }
+        "SD" => { let rs2 = ops.x()?; let (imm, rs1) = ops.mem()?; Sd { rs1, rs2, imm } }
+        "BEQ" => Beq { rs1: ops.x()?, rs2: ops.x()?, imm: ops.imm()? },
+        "BNE" => Bne { rs1: ops.x()?, rs2: ops.x()?, imm: ops.imm()? },
+        "BLT" => Blt { rs1: ops.x()?, rs2: ops.x()?, imm: ops.imm()? },
+        "BGE" => Bge { rs1: ops.x()?, rs2: ops.x()?, imm: ops.imm()? },
+        "BLTU" => Bltu { rs1: ops.x()?, rs2: ops.x()?, imm: ops.imm()? },
+        "BGEU" => Bgeu { rs1: ops.x()?, rs2: ops.x()?, imm: ops.imm()? },
+        "ADDI" => Addi { rd: ops.x()?, rs1: ops.x()?, imm: ops.imm()? },
+        "SLTI" => Slti { rd: ops.x()?, rs1: ops.x()?, imm: ops.imm()? },
+        "SLTIU" => Sltiu { rd: ops.x()?, rs1: ops.x()?, imm: ops.imm()? },
+        "XORI" => Xori { rd: ops.x()?, rs1: ops.x()?, imm: ops.imm()? },
+        "ORI" => Ori { rd: ops.x()?, rs1: ops.x()?, imm: ops.imm()? },
+        "ANDI" => Andi { rd: ops.x()?, rs1: ops.x()?, imm: ops.imm()? },
+        "SLLI" => Slli { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(config.shamt_width())? },
+        "SRLI" => Srli { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(config.shamt_width())? },
+        "SRAI" => Srai { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(config.shamt_width())? },
+        "ADDIW" => Addiw { rd: ops.x()?, rs1: ops.x()?, imm: ops.imm()? },
+        "SLLIW" => Slliw { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(5)? },
+        "SRLIW" => Srliw { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(5)? },
+        "SRAIW" => Sraiw { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(5)? },
+        "LUI" => Lui { rd: ops.x()?, imm: ops.upper_imm()? },
+        "AUIPC" => Auipc { rd: ops.x()?, imm: ops.upper_imm()? },
+        "JAL" => Jal { rd: ops.x()?, imm: ops.imm()? },
+        "JALR" => Jalr { rd: ops.x()?, rs1: ops.x()?, imm: ops.imm()? },
+        "ADD" => Add { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SUB" => Sub { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SLL" => Sll { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SLT" => Slt { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SLTU" => Sltu { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "XOR" => Xor { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SRL" => Srl { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SRA" => Sra { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "OR" => Or { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "AND" => And { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        // A bare FENCE orders everything
+        "FENCE" if ops.is_empty() => Fence { pred: 0b1111, succ: 0b1111 },
+        "FENCE" => Fence { pred: ops.fence_set()?, succ: ops.fence_set()? },
+        "FENCE.TSO" => FenceTso,
+        "PAUSE" => Pause,
+        "FENCE.I" => FenceI,
+        "ECALL" => Ecall,
+        "EBREAK" => Ebreak,
+        "SRET" => Sret,
+        "MRET" => Mret,
+        "MNRET" => Mnret,
+        "DRET" => Dret,
+        "WFI" => Wfi,
+        // A bare SFENCE.VMA orders all address spaces and addresses
+        "SFENCE.VMA" if ops.is_empty() => SfenceVma { rs1: 0, rs2: 0 },
+        "SFENCE.VMA" => SfenceVma { rs1: ops.x()?, rs2: ops.x()? },
+        "SINVAL.VMA" => SinvalVma { rs1: ops.x()?, rs2: ops.x()? },
+        "SFENCE.W.INVAL" => SfenceWInval,
+        "SFENCE.INVAL.IR" => SfenceInvalIr,
+        "CSRRW" => Csrrw { rd: ops.x()?, csr: ops.csr()?, rs1: ops.x()? },
+        "CSRRS" => Csrrs { rd: ops.x()?, csr: ops.csr()?, rs1: ops.x()? },
+        "CSRRC" => Csrrc { rd: ops.x()?, csr: ops.csr()?, rs1: ops.x()? },
+        "CSRRWI" => Csrrwi { rd: ops.x()?, csr: ops.csr()?, uimm: ops.unsigned_imm(31)? },
+        "CSRRSI" => Csrrsi { rd: ops.x()?, csr: ops.csr()?, uimm: ops.unsigned_imm(31)? },
+        "CSRRCI" => Csrrci { rd: ops.x()?, csr: ops.csr()?, uimm: ops.unsigned_imm(31)? },
+        "ADDW" => Addw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SUBW" => Subw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SLLW" => Sllw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SRLW" => Srlw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SRAW" => Sraw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "MULW" => Mulw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "DIVW" => Divw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "DIVUW" => Divuw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "REMW" => Remw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "REMUW" => Remuw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SH1ADD" => Sh1add { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SH2ADD" => Sh2add { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SH3ADD" => Sh3add { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "ANDN" => Andn { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "ORN" => Orn { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "XNOR" => Xnor { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "MAX" => Max { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "MAXU" => Maxu { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "MIN" => Min { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "MINU" => Minu { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "ROL" => Rol { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "ROR" => Ror { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "CLMUL" => Clmul { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "CLMULH" => Clmulh { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "CLMULR" => Clmulr { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "BCLR" => Bclr { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "BEXT" => Bext { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "BINV" => Binv { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "BSET" => Bset { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "ADD.UW" => AddUw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SH1ADD.UW" => Sh1addUw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SH2ADD.UW" => Sh2addUw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SH3ADD.UW" => Sh3addUw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "ROLW" => Rolw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "RORW" => Rorw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "CLZ" => Clz { rd: ops.x()?, rs1: ops.x()? },
+        "CTZ" => Ctz { rd: ops.x()?, rs1: ops.x()? },
+        "CPOP" => Cpop { rd: ops.x()?, rs1: ops.x()? },
+        "SEXT.B" => SextB { rd: ops.x()?, rs1: ops.x()? },
+        "SEXT.H" => SextH { rd: ops.x()?, rs1: ops.x()? },
+        "ZEXT.H" => ZextH { rd: ops.x()?, rs1: ops.x()? },
+        "ORC.B" => OrcB { rd: ops.x()?, rs1: ops.x()? },
+        "REV8" => Rev8 { rd: ops.x()?, rs1: ops.x()? },
+        "CLZW" => Clzw { rd: ops.x()?, rs1: ops.x()? },
+        "CTZW" => Ctzw { rd: ops.x()?, rs1: ops.x()? },
+        "CPOPW" => Cpopw { rd: ops.x()?, rs1: ops.x()? },
+        "RORI" => Rori { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(config.shamt_width())? },
+        "BCLRI" => Bclri { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(config.shamt_width())? },
+        "BEXTI" => Bexti { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(config.shamt_width())? },
+        "BINVI" => Binvi { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(config.shamt_width())? },
+        "BSETI" => Bseti { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(config.shamt_width())? },
+        "SLLI.UW" => SlliUw { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(6)? },
+        "RORIW" => Roriw { rd: ops.x()?, rs1: ops.x()?, shamt: ops.shamt(5)? },
+        "PACK" => Pack { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "PACKH" => Packh { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "PACKW" => Packw { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "XPERM4" => Xperm4 { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "XPERM8" => Xperm8 { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "AES64ES" => Aes64es { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "AES64ESM" => Aes64esm { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "AES64DS" => Aes64ds { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "AES64DSM" => Aes64dsm { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "AES64KS2" => Aes64ks2 { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SHA512SIG0H" => Sha512sig0h { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SHA512SIG0L" => Sha512sig0l { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SHA512SIG1H" => Sha512sig1h { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SHA512SIG1L" => Sha512sig1l { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SHA512SUM0R" => Sha512sum0r { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "SHA512SUM1R" => Sha512sum1r { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "BREV8" => Brev8 { rd: ops.x()?, rs1: ops.x()? },
+        "ZIP" => Zip { rd: ops.x()?, rs1: ops.x()? },
+        "UNZIP" => Unzip { rd: ops.x()?, rs1: ops.x()? },
+        "AES64IM" => Aes64im { rd: ops.x()?, rs1: ops.x()? },
+        "SHA256SIG0" => Sha256sig0 { rd: ops.x()?, rs1: ops.x()? },
+        "SHA256SIG1" => Sha256sig1 { rd: ops.x()?, rs1: ops.x()? },
+        "SHA256SUM0" => Sha256sum0 { rd: ops.x()?, rs1: ops.x()? },
+        "SHA256SUM1" => Sha256sum1 { rd: ops.x()?, rs1: ops.x()? },
+        "SHA512SIG0" => Sha512sig0 { rd: ops.x()?, rs1: ops.x()? },
+        "SHA512SIG1" => Sha512sig1 { rd: ops.x()?, rs1: ops.x()? },
+        "SHA512SUM0" => Sha512sum0 { rd: ops.x()?, rs1: ops.x()? },
+        "SHA512SUM1" => Sha512sum1 { rd: ops.x()?, rs1: ops.x()? },
+        "SM3P0" => Sm3p0 { rd: ops.x()?, rs1: ops.x()? },
+        "SM3P1" => Sm3p1 { rd: ops.x()?, rs1: ops.x()? },
+        "AES32ESI" => Aes32esi { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()?, bs: ops.unsigned_imm(3)? },
+        "AES32ESMI" => Aes32esmi { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()?, bs: ops.unsigned_imm(3)? },
+        "AES32DSI" => Aes32dsi { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()?, bs: ops.unsigned_imm(3)? },
+        "AES32DSMI" => Aes32dsmi { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()?, bs: ops.unsigned_imm(3)? },
+        "SM4ED" => Sm4ed { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()?, bs: ops.unsigned_imm(3)? },
+        "SM4KS" => Sm4ks { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()?, bs: ops.unsigned_imm(3)? },
+        "AES64KS1I" => Aes64ks1i { rd: ops.x()?, rs1: ops.x()?, rnum: ops.unsigned_imm(0xa)? },
+        "MUL" => Mul { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "MULH" => Mulh { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "MULHSU" => Mulhsu { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "MULHU" => Mulhu { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "DIV" => Div { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "DIVU" => Divu { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "REM" => Rem { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "REMU" => Remu { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        "LR.W" => LrW { rd: ops.x()?, rs1: ops.x()?, aq, rl },
+        "SC.W" => ScW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOSWAP.W" => AmoswapW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOADD.W" => AmoaddW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOXOR.W" => AmoxorW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOAND.W" => AmoandW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOOR.W" => AmoorW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOMIN.W" => AmominW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOMAX.W" => AmomaxW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOMINU.W" => AmominuW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOMAXU.W" => AmomaxuW { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "LR.D" => LrD { rd: ops.x()?, rs1: ops.x()?, aq, rl },
+        "SC.D" => ScD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOSWAP.D" => AmoswapD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOADD.D" => AmoaddD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOXOR.D" => AmoxorD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOAND.D" => AmoandD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOOR.D" => AmoorD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOMIN.D" => AmominD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOMAX.D" => AmomaxD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOMINU.D" => AmominuD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "AMOMAXU.D" => AmomaxuD { rd: ops.x()?, rs2: ops.x()?, rs1: ops.x()?, aq, rl },
+        "FMADD.S" => FmaddS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FMADD.D" => FmaddD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FMSUB.S" => FmsubS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FMSUB.D" => FmsubD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FNMSUB.S" => FnmsubS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FNMSUB.D" => FnmsubD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FNMADD.S" => FnmaddS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FNMADD.D" => FnmaddD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FADD.S" => FaddS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FADD.D" => FaddD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FSUB.S" => FsubS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FSUB.D" => FsubD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FMUL.S" => FmulS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FMUL.D" => FmulD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FDIV.S" => FdivS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FDIV.D" => FdivD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FSQRT.S" => FsqrtS { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FSQRT.D" => FsqrtD { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FSGNJ.S" => FsgnjS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FSGNJ.D" => FsgnjD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FSGNJN.S" => FsgnjnS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FSGNJN.D" => FsgnjnD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FSGNJX.S" => FsgnjxS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FSGNJX.D" => FsgnjxD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMIN.S" => FminS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMIN.D" => FminD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMAX.S" => FmaxS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMAX.D" => FmaxD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FCVT.D.S" => FcvtDS { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.S.D" => FcvtSD { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.W.S" => FcvtWS { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.W.D" => FcvtWD { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.WU.S" => FcvtWuS { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.WU.D" => FcvtWuD { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.S.W" => FcvtSW { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.D.W" => FcvtDW { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.S.WU" => FcvtSWu { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.D.WU" => FcvtDWu { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FMV.X.W" => FmvXW { rd: ops.x()?, rs1: ops.f()? },
+        "FMV.W.X" => FmvWX { rd: ops.f()?, rs1: ops.x()? },
+        "FCVT.L.S" => FcvtLS { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.LU.S" => FcvtLuS { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.S.L" => FcvtSL { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.S.LU" => FcvtSLu { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.L.D" => FcvtLD { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.LU.D" => FcvtLuD { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.D.L" => FcvtDL { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.D.LU" => FcvtDLu { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FMV.X.D" => FmvXD { rd: ops.x()?, rs1: ops.f()? },
+        "FMV.D.X" => FmvDX { rd: ops.f()?, rs1: ops.x()? },
+        "FEQ.S" => FeqS { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FEQ.D" => FeqD { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLT.S" => FltS { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLT.D" => FltD { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLE.S" => FleS { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLE.D" => FleD { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FCLASS.S" => FclassS { rd: ops.x()?, rs1: ops.f()? },
+        "FCLASS.D" => FclassD { rd: ops.x()?, rs1: ops.f()? },
+        "FLW" => { let rd = ops.f()?; let (imm, rs1) = ops.mem()?; Flw { rd, rs1, imm } }
+        "FLD" => { let rd = ops.f()?; let (imm, rs1) = ops.mem()?; Fld { rd, rs1, imm } }
+        "FSW" => { let rs2 = ops.f()?; let (imm, rs1) = ops.mem()?; Fsw { rs1, rs2, imm } }
+        "FSD" => { let rs2 = ops.f()?; let (imm, rs1) = ops.mem()?; Fsd { rs1, rs2, imm } }
+        "FMADD.H" => FmaddH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FMSUB.H" => FmsubH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FNMSUB.H" => FnmsubH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FNMADD.H" => FnmaddH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FADD.H" => FaddH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FSUB.H" => FsubH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FMUL.H" => FmulH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FDIV.H" => FdivH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FSQRT.H" => FsqrtH { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FSGNJ.H" => FsgnjH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FSGNJN.H" => FsgnjnH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FSGNJX.H" => FsgnjxH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMIN.H" => FminH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMAX.H" => FmaxH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FCVT.W.H" => FcvtWH { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.WU.H" => FcvtWuH { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.H.W" => FcvtHW { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.H.WU" => FcvtHWu { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.L.H" => FcvtLH { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.LU.H" => FcvtLuH { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.H.L" => FcvtHL { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.H.LU" => FcvtHLu { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FMV.X.H" => FmvXH { rd: ops.x()?, rs1: ops.f()? },
+        "FMV.H.X" => FmvHX { rd: ops.f()?, rs1: ops.x()? },
+        "FEQ.H" => FeqH { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLT.H" => FltH { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLE.H" => FleH { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FCLASS.H" => FclassH { rd: ops.x()?, rs1: ops.f()? },
+        "FCVT.S.H" => FcvtSH { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.H.S" => FcvtHS { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.D.H" => FcvtDH { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.H.D" => FcvtHD { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FLH" => { let rd = ops.f()?; let (imm, rs1) = ops.mem()?; Flh { rd, rs1, imm } }
+        "FSH" => { let rs2 = ops.f()?; let (imm, rs1) = ops.mem()?; Fsh { rs1, rs2, imm } }
+        "FMADD.Q" => FmaddQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FMSUB.Q" => FmsubQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FNMSUB.Q" => FnmsubQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FNMADD.Q" => FnmaddQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rs3: ops.f()?, rm: ops.rm()? },
+        "FADD.Q" => FaddQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FSUB.Q" => FsubQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FMUL.Q" => FmulQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FDIV.Q" => FdivQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()?, rm: ops.rm()? },
+        "FSQRT.Q" => FsqrtQ { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FSGNJ.Q" => FsgnjQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FSGNJN.Q" => FsgnjnQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FSGNJX.Q" => FsgnjxQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMIN.Q" => FminQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMAX.Q" => FmaxQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FCVT.W.Q" => FcvtWQ { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.WU.Q" => FcvtWuQ { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.Q.W" => FcvtQW { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.Q.WU" => FcvtQWu { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.L.Q" => FcvtLQ { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.LU.Q" => FcvtLuQ { rd: ops.x()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.Q.L" => FcvtQL { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FCVT.Q.LU" => FcvtQLu { rd: ops.f()?, rs1: ops.x()?, rm: ops.rm()? },
+        "FEQ.Q" => FeqQ { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLT.Q" => FltQ { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLE.Q" => FleQ { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FCLASS.Q" => FclassQ { rd: ops.x()?, rs1: ops.f()? },
+        "FCVT.S.Q" => FcvtSQ { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.Q.S" => FcvtQS { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.D.Q" => FcvtDQ { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.Q.D" => FcvtQD { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.H.Q" => FcvtHQ { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FCVT.Q.H" => FcvtQH { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FLQ" => { let rd = ops.f()?; let (imm, rs1) = ops.mem()?; Flq { rd, rs1, imm } }
+        "FSQ" => { let rs2 = ops.f()?; let (imm, rs1) = ops.mem()?; Fsq { rs1, rs2, imm } }
+        "FLI.S" => FliS { rd: ops.f()?, constant: ops.fli()? },
+        "FLI.D" => FliD { rd: ops.f()?, constant: ops.fli()? },
+        "FLI.H" => FliH { rd: ops.f()?, constant: ops.fli()? },
+        "FLI.Q" => FliQ { rd: ops.f()?, constant: ops.fli()? },
+        "FMINM.S" => FminmS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMAXM.S" => FmaxmS { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMINM.D" => FminmD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMAXM.D" => FmaxmD { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMINM.H" => FminmH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMAXM.H" => FmaxmH { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMINM.Q" => FminmQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FMAXM.Q" => FmaxmQ { rd: ops.f()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FROUND.S" => FroundS { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FROUNDNX.S" => FroundnxS { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FROUND.D" => FroundD { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FROUNDNX.D" => FroundnxD { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FROUND.H" => FroundH { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FROUNDNX.H" => FroundnxH { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FROUND.Q" => FroundQ { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FROUNDNX.Q" => FroundnxQ { rd: ops.f()?, rs1: ops.f()?, rm: ops.rm()? },
+        "FLEQ.S" => FleqS { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLTQ.S" => FltqS { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLEQ.D" => FleqD { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLTQ.D" => FltqD { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLEQ.H" => FleqH { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLTQ.H" => FltqH { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLEQ.Q" => FleqQ { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FLTQ.Q" => FltqQ { rd: ops.x()?, rs1: ops.f()?, rs2: ops.f()? },
+        "FCVTMOD.W.D" => { let rd = ops.x()?; let rs1 = ops.f()?; ops.rtz()?; FcvtmodWD { rd, rs1 } },
+        "FMVH.X.D" => FmvhXD { rd: ops.x()?, rs1: ops.f()? },
+        "FMVH.X.Q" => FmvhXQ { rd: ops.x()?, rs1: ops.f()? },
+        "FMVP.D.X" => FmvpDX { rd: ops.f()?, rs1: ops.x()?, rs2: ops.x()? },
+        "FMVP.Q.X" => FmvpQX { rd: ops.f()?, rs1: ops.x()?, rs2: ops.x()? },
+        "VSETVLI" => Vsetvli { rd: ops.x()?, rs1: ops.x()?, vtype: ops.vtype()? },
+        "VSETIVLI" => Vsetivli { rd: ops.x()?, uimm: ops.unsigned_imm(31)?, vtype: ops.vtype()? },
+        "VSETVL" => Vsetvl { rd: ops.x()?, rs1: ops.x()?, rs2: ops.x()? },
+        _ => match vector_from_text(base, &mut ops)? {
+            Some(instruction) => instruction,
+            None => return Err(error(mnemonic_column, ParseErrorKind::UnknownMnemonic(mnemonic.to_string()))),
+        },
+    };
+    ops.finish()?;
+
+    // Range, alignment and XLEN checks are shared with the encoder
+    if let Err(err) = encode_with_config(&instruction, config) {
+        let column = match err {
+            EncodeError::UnsupportedXlen { .. } => mnemonic_column,
+            _ => ops.imm_column.unwrap_or(mnemonic_column),
+        };
+        return Err(error(column, ParseErrorKind::Encode(err)));
+    }
+    return Ok(instruction);
+}
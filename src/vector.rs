@@ -0,0 +1,1202 @@
+use std::fmt;
+
+use crate::{bits, sign_extend, DecodeError, Field, Instruction, Register};
+
+/*
+ * RVV 1.0 instruction breakdown
+ *
+ * OP-V (1010111) holds the arithmetic and configuration-setting instructions,
+ * funct3 selects the operand category. vm is clear when the operation is
+ * masked by v0, which the assembler writes as a trailing `v0.t`.
+ *
+ * OPIVV/OPFVV/OPMVV  31----26 25 24---20 19---15 14----12 11---7 6-------0
+ *                    /funct6/ vm  /vs2/    /vs1/  /funct3/   /vd/  /opcode/
+ * OPIVX/OPFVF/OPMVX  /funct6/ vm  /vs2/    /rs1/  /funct3/   /vd/  /opcode/
+ * OPIVI              /funct6/ vm  /vs2/   /simm5/ /funct3/   /vd/  /opcode/
+ *
+ * vsetvli   31 = 0, zimm[10:0] = 30:20,  rs1,  funct3 = 111, rd
+ * vsetivli  31:30 = 11, zimm[9:0] = 29:20, uimm[4:0] = 19:15, funct3 = 111, rd
+ * vsetvl    31:25 = 1000000, rs2, rs1, funct3 = 111, rd
+ *
+ * Loads and stores share LOAD-FP (0000111) and STORE-FP (0100111), widths
+ * 000/101/110/111 select an element width of 8/16/32/64 bits while the
+ * scalar floating point loads and stores use the others.
+ *
+ *   31-29 28  27-26 25    24-----20      19---15 14----12 11-----7 6-------0
+ *   /nf/ /mew/ /mop/ vm /lumop/rs2/vs2/   /rs1/   /width/  /vd/vs3/ /opcode/
+ *
+ * nf holds the number of fields of a segment access minus one.
+ *
+ * The vector crypto extensions (Zvkg, Zvkned, Zvknh, Zvksed, Zvksh) use
+ * OP-VE (1110111) with the OPMVV layout, funct3 = 010 and vm = 1. Their .vi
+ * forms keep the funct3 and hold uimm[4:0] in the vs1 field. They work on
+ * element groups: four 32-bit elements for AES, GHASH, SM4 and SHA-256,
+ * four 64-bit elements for SHA-512 and eight 32-bit elements for SM3.
+ */
+
+/// Element width, the SEW held in `vtype` or the EEW of a vector load or store
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sew {
+    E8,
+    E16,
+    E32,
+    E64,
+}
+
+impl Sew {
+    /// Returns the element width in bits
+    pub fn bits(&self) -> u32 {
+        match self {
+            Sew::E8 => 8,
+            Sew::E16 => 16,
+            Sew::E32 => 32,
+            Sew::E64 => 64,
+        }
+    }
+
+    fn from_vsew(vsew: u32) -> Option<Sew> {
+        match vsew {
+            0b000 => Some(Sew::E8),
+            0b001 => Some(Sew::E16),
+            0b010 => Some(Sew::E32),
+            0b011 => Some(Sew::E64),
+            _ => None,
+        }
+    }
+
+    fn vsew(&self) -> u32 {
+        match self {
+            Sew::E8 => 0b000,
+            Sew::E16 => 0b001,
+            Sew::E32 => 0b010,
+            Sew::E64 => 0b011,
+        }
+    }
+
+    /// Maps the width field of a vector load or store to its element width
+    pub(crate) fn from_width(width: u32) -> Option<Sew> {
+        match width {
+            0b000 => Some(Sew::E8),
+            0b101 => Some(Sew::E16),
+            0b110 => Some(Sew::E32),
+            0b111 => Some(Sew::E64),
+            _ => None,
+        }
+    }
+
+    /// Returns the width field of a vector load or store
+    pub(crate) fn width(&self) -> u32 {
+        match self {
+            Sew::E8 => 0b000,
+            Sew::E16 => 0b101,
+            Sew::E32 => 0b110,
+            Sew::E64 => 0b111,
+        }
+    }
+}
+
+impl fmt::Display for Sew {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "e{}", self.bits())
+    }
+}
+
+/// Vector register group multiplier, the LMUL held in `vtype`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lmul {
+    Mf8,
+    Mf4,
+    Mf2,
+    M1,
+    M2,
+    M4,
+    M8,
+}
+
+impl Lmul {
+    fn from_vlmul(vlmul: u32) -> Option<Lmul> {
+        match vlmul {
+            0b000 => Some(Lmul::M1),
+            0b001 => Some(Lmul::M2),
+            0b010 => Some(Lmul::M4),
+            0b011 => Some(Lmul::M8),
+            0b101 => Some(Lmul::Mf8),
+            0b110 => Some(Lmul::Mf4),
+            0b111 => Some(Lmul::Mf2),
+            _ => None,
+        }
+    }
+
+    fn vlmul(&self) -> u32 {
+        match self {
+            Lmul::M1 => 0b000,
+            Lmul::M2 => 0b001,
+            Lmul::M4 => 0b010,
+            Lmul::M8 => 0b011,
+            Lmul::Mf8 => 0b101,
+            Lmul::Mf4 => 0b110,
+            Lmul::Mf2 => 0b111,
+        }
+    }
+}
+
+impl fmt::Display for Lmul {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Lmul::Mf8 => "mf8",
+            Lmul::Mf4 => "mf4",
+            Lmul::Mf2 => "mf2",
+            Lmul::M1 => "m1",
+            Lmul::M2 => "m2",
+            Lmul::M4 => "m4",
+            Lmul::M8 => "m8",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The vector type setting written by VSETVLI and VSETIVLI
+///
+/// `Display` writes it the way the assembler takes it, e.g. "e32, m2, ta, ma".
+///
+/// # Examples:
+///
+/// ```
+/// use rv_decoder::{Lmul, Sew, Vtype};
+///
+/// let vtype = Vtype::from_bits(0xd1).unwrap();
+/// assert_eq!(vtype, Vtype { sew: Sew::E32, lmul: Lmul::M2, tail_agnostic: true, mask_agnostic: true });
+/// assert_eq!(vtype.to_string(), "e32, m2, ta, ma");
+/// assert_eq!(vtype.bits(), 0xd1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vtype {
+    pub sew: Sew,
+    pub lmul: Lmul,
+    /// Tail agnostic (ta) rather than tail undisturbed (tu)
+    pub tail_agnostic: bool,
+    /// Mask agnostic (ma) rather than mask undisturbed (mu)
+    pub mask_agnostic: bool,
+}
+
+impl Vtype {
+    /// Decodes a vtype immediate, reserved SEW or LMUL values and set upper bits give `None`
+    pub fn from_bits(vtype: u32) -> Option<Vtype> {
+        if vtype >> 8 != 0 {
+            return None;
+        }
+        return Some(Vtype {
+            sew: Sew::from_vsew(bits(vtype, 5, 3))?,
+            lmul: Lmul::from_vlmul(bits(vtype, 2, 0))?,
+            tail_agnostic: bits(vtype, 6, 6) == 1,
+            mask_agnostic: bits(vtype, 7, 7) == 1,
+        });
+    }
+
+    /// Returns the vtype immediate
+    pub fn bits(&self) -> u32 {
+        return (self.mask_agnostic as u32) << 7 | (self.tail_agnostic as u32) << 6 | self.sew.vsew() << 3 | self.lmul.vlmul();
+    }
+}
+
+impl fmt::Display for Vtype {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tail = if self.tail_agnostic { "ta" } else { "tu" };
+        let mask = if self.mask_agnostic { "ma" } else { "mu" };
+        write!(f, "{}, {}, {}, {}", self.sew, self.lmul, tail, mask)
+    }
+}
+
+/// How a vector load or store walks memory
+///
+/// Unit-stride, strided and indexed accesses move `nf` fields per element
+/// (segment accesses when `nf` is above one). Whole register accesses move
+/// `nf` registers, which must be 1, 2, 4 or 8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VectorAddressing {
+    UnitStride,
+    /// Unit-stride load that only traps on the first element, loads only
+    FaultOnlyFirst,
+    /// VLM.V/VSM.V, one mask bit per element with an EEW of 8
+    Mask,
+    WholeRegister,
+    /// Byte stride taken from `rs2`
+    Strided { rs2: u8 },
+    /// Byte offsets taken from the elements of `vs2`
+    IndexedUnordered { vs2: u8 },
+    IndexedOrdered { vs2: u8 },
+}
+
+/// The vs1/rs1/imm source operand of a vector arithmetic instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VectorOperand {
+    /// A vector register, the .VV/.WV/.VS/.MM forms
+    Vector(u8),
+    /// An integer register, the .VX/.WX forms
+    Scalar(u8),
+    /// A floating point register, the .VF/.WF forms
+    Float(u8),
+    /// A 5-bit immediate, the .VI/.WI forms, sign- or zero-extended as the operation defines
+    Imm(i8),
+}
+
+impl fmt::Display for VectorOperand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            VectorOperand::Vector(n) => write!(f, "v{}", n),
+            VectorOperand::Scalar(n) => write!(f, "x{}", n),
+            VectorOperand::Float(n) => write!(f, "f{}", n),
+            VectorOperand::Imm(imm) => write!(f, "{}", imm),
+        }
+    }
+}
+
+/// A vector arithmetic operation with a vs1/rs1/imm source
+///
+/// The operand form (.VV, .VX, .VI, .VF, ...) is not part of the operation,
+/// it follows from the `VectorOperand` the instruction carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VectorOp {
+    // OPIVV/OPIVX/OPIVI
+    Vadd, Vsub, Vrsub, Vminu, Vmin, Vmaxu, Vmax, Vand, Vor, Vxor,
+    Vrgather, Vrgatherei16, Vslideup, Vslidedown,
+    Vadc, Vmadc, Vsbc, Vmsbc, Vmerge, VmvV,
+    Vmseq, Vmsne, Vmsltu, Vmslt, Vmsleu, Vmsle, Vmsgtu, Vmsgt,
+    Vsaddu, Vsadd, Vssubu, Vssub, Vsll, Vsmul, Vsrl, Vsra, Vssrl, Vssra,
+    Vnsrl, Vnsra, Vnclipu, Vnclip, Vwredsumu, Vwredsum,
+
+    // Zvbb/Zvbc, VROR.VI takes a 6-bit rotate amount
+    Vandn, Vrol, Vror, Vwsll, Vclmul, Vclmulh,
+
+    // OPMVV/OPMVX
+    Vredsum, Vredand, Vredor, Vredxor, Vredminu, Vredmin, Vredmaxu, Vredmax,
+    Vaaddu, Vaadd, Vasubu, Vasub, Vslide1up, Vslide1down, VmvS, Vcompress,
+    Vmandn, Vmand, Vmor, Vmxor, Vmorn, Vmnand, Vmnor, Vmxnor,
+    Vdivu, Vdiv, Vremu, Vrem, Vmulhu, Vmul, Vmulhsu, Vmulh,
+    Vmadd, Vnmsub, Vmacc, Vnmsac,
+    Vwaddu, Vwadd, Vwsubu, Vwsub, VwadduW, VwaddW, VwsubuW, VwsubW,
+    Vwmulu, Vwmulsu, Vwmul, Vwmaccu, Vwmacc, Vwmaccus, Vwmaccsu,
+
+    // OPFVV/OPFVF
+    Vfadd, Vfredusum, Vfsub, Vfredosum, Vfmin, Vfredmin, Vfmax, Vfredmax,
+    Vfsgnj, Vfsgnjn, Vfsgnjx, Vfslide1up, Vfslide1down, VfmvS, Vfmerge, VfmvV,
+    Vmfeq, Vmfle, Vmflt, Vmfne, Vmfgt, Vmfge,
+    Vfdiv, Vfrdiv, Vfmul, Vfrsub,
+    Vfmadd, Vfnmadd, Vfmsub, Vfnmsub, Vfmacc, Vfnmacc, Vfmsac, Vfnmsac,
+    Vfwadd, Vfwredusum, Vfwsub, Vfwredosum, VfwaddW, VfwsubW, Vfwmul,
+    Vfwmacc, Vfwnmacc, Vfwmsac, Vfwnmsac,
+
+    // Zvkg/Zvkned/Zvknh/Zvksed/Zvksh element group operations (OP-VE)
+    Vghsh, Vsha2ms, Vsha2ch, Vsha2cl, Vsm3me, Vaeskf1, Vaeskf2, Vsm4k, Vsm3c,
+}
+
+/// A vector operation whose vs1 field holds a sub-opcode instead of a source
+///
+/// The destination is a vector register except for VMV.X.S, VCPOP.M and
+/// VFIRST.M (an `x` register) and VFMV.F.S (an `f` register).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VectorUnaryOp {
+    // VWXUNARY0, VXUNARY0 and VMUNARY0
+    VmvXS, VcpopM, VfirstM,
+    VzextVf8, VsextVf8, VzextVf4, VsextVf4, VzextVf2, VsextVf2,
+    VmsbfM, VmsofM, VmsifM, ViotaM, VidV,
+
+    // VWFUNARY0, VFUNARY0 and VFUNARY1
+    VfmvFS,
+    VfcvtXuFV, VfcvtXFV, VfcvtFXuV, VfcvtFXV, VfcvtRtzXuFV, VfcvtRtzXFV,
+    VfwcvtXuFV, VfwcvtXFV, VfwcvtFXuV, VfwcvtFXV, VfwcvtFFV, VfwcvtRtzXuFV, VfwcvtRtzXFV,
+    VfncvtXuFW, VfncvtXFW, VfncvtFXuW, VfncvtFXW, VfncvtFFW, VfncvtRodFFW, VfncvtRtzXuFW, VfncvtRtzXFW,
+    VfsqrtV, Vfrsqrt7V, Vfrec7V, VfclassV,
+
+    // Whole register moves, the immediate field holds the register count minus one
+    Vmv1rV, Vmv2rV, Vmv4rV, Vmv8rV,
+
+    // Zvbb bit manipulation, in VXUNARY0
+    VbrevV, Vbrev8V, Vrev8V, VclzV, VctzV, VcpopV,
+
+    // Zvkg/Zvkned/Zvksed element group operations (OP-VE), the .VS forms
+    // apply element group 0 of vs2 to every element group of vd
+    VaesdfVv, VaesdfVs, VaesdmVv, VaesdmVs, VaesefVv, VaesefVs, VaesemVv, VaesemVs, VaeszVs,
+    VgmulVv, Vsm4rVv, Vsm4rVs,
+}
+
+/// Operand category of an arithmetic operation, which maps each form to a funct3
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Group {
+    Int,        // OPIVV, OPIVX, OPIVI
+    Mul,        // OPMVV, OPMVX
+    Float,      // OPFVV, OPFVF
+    Crypto,     // OP-VE, OPMVV for both the .vv and .vi forms
+}
+
+/// How an arithmetic operation spells its suffix and orders its operands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Shape {
+    Plain,          // .VV/.VX/.VI/.VF  vd, vs2, src
+    Wide,           // .WV/.WX/.WI/.WF  vs2 holds 2*SEW elements
+    Reduction,      // .VS
+    MulAdd,         // .VV/.VX/.VF      vd, src, vs2
+    Carry,          // .VVM/.VXM/...    vd, vs2, src, v0 with vm clear
+    CarryOut,       // .VVM with v0 when vm is clear, .VV without
+    MaskLogical,    // .MM, unmasked
+    Compress,       // .VM, unmasked
+    Move,           // .V.V/.V.X/.V.I/.V.F  vd, src, unmasked with vs2 = 0
+    MoveScalar,     // .S.X/.S.F            vd, src, unmasked with vs2 = 0
+    ElementGroup,   // .VV/.VI              vd, vs2, src, unmasked
+}
+
+impl Shape {
+    /// Whether the vm bit may hold `vm`
+    fn allows_vm(&self, vm: bool) -> bool {
+        match self {
+            Shape::Carry => !vm,
+            Shape::MaskLogical | Shape::Compress | Shape::Move | Shape::MoveScalar | Shape::ElementGroup => vm,
+            _ => true,
+        }
+    }
+}
+
+// Operand forms an operation accepts, one bit per form
+const VV: u8 = 0b0001;
+const VX: u8 = 0b0010;
+const VI: u8 = 0b0100;
+const VF: u8 = 0b1000;
+
+struct OpEntry {
+    op: VectorOp,
+    name: &'static str,
+    description: &'static str,
+    group: Group,
+    funct6: u32,
+    forms: u8,
+    shape: Shape,
+}
+
+const fn op(op: VectorOp, name: &'static str, description: &'static str, group: Group, funct6: u32, forms: u8, shape: Shape) -> OpEntry {
+    OpEntry { op, name, description, group, funct6, forms, shape }
+}
+
+use Group::{Crypto, Float, Int, Mul};
+use Shape::*;
+
+const VECTOR_OPS: &[OpEntry] = &[
+    op(VectorOp::Vadd, "VADD", "Vector Add", Int, 0b000000, VV | VX | VI, Plain),
+    op(VectorOp::Vsub, "VSUB", "Vector Subtract", Int, 0b000010, VV | VX, Plain),
+    op(VectorOp::Vrsub, "VRSUB", "Vector Reverse Subtract", Int, 0b000011, VX | VI, Plain),
+    op(VectorOp::Vminu, "VMINU", "Vector Unsigned Minimum", Int, 0b000100, VV | VX, Plain),
+    op(VectorOp::Vmin, "VMIN", "Vector Signed Minimum", Int, 0b000101, VV | VX, Plain),
+    op(VectorOp::Vmaxu, "VMAXU", "Vector Unsigned Maximum", Int, 0b000110, VV | VX, Plain),
+    op(VectorOp::Vmax, "VMAX", "Vector Signed Maximum", Int, 0b000111, VV | VX, Plain),
+    op(VectorOp::Vand, "VAND", "Vector Bitwise AND", Int, 0b001001, VV | VX | VI, Plain),
+    op(VectorOp::Vor, "VOR", "Vector Bitwise OR", Int, 0b001010, VV | VX | VI, Plain),
+    op(VectorOp::Vxor, "VXOR", "Vector Bitwise XOR", Int, 0b001011, VV | VX | VI, Plain),
+    op(VectorOp::Vrgather, "VRGATHER", "Vector Register Gather", Int, 0b001100, VV | VX | VI, Plain),
+    op(VectorOp::Vrgatherei16, "VRGATHEREI16", "Vector Register Gather with 16-bit Indices", Int, 0b001110, VV, Plain),
+    op(VectorOp::Vslideup, "VSLIDEUP", "Vector Slide Up", Int, 0b001110, VX | VI, Plain),
+    op(VectorOp::Vslidedown, "VSLIDEDOWN", "Vector Slide Down", Int, 0b001111, VX | VI, Plain),
+    op(VectorOp::Vadc, "VADC", "Vector Add with Carry", Int, 0b010000, VV | VX | VI, Carry),
+    op(VectorOp::Vmadc, "VMADC", "Vector Add Carry-Out Mask", Int, 0b010001, VV | VX | VI, CarryOut),
+    op(VectorOp::Vsbc, "VSBC", "Vector Subtract with Borrow", Int, 0b010010, VV | VX, Carry),
+    op(VectorOp::Vmsbc, "VMSBC", "Vector Subtract Borrow-Out Mask", Int, 0b010011, VV | VX, CarryOut),
+    op(VectorOp::Vmerge, "VMERGE", "Vector Merge", Int, 0b010111, VV | VX | VI, Carry),
+    op(VectorOp::VmvV, "VMV", "Vector Move", Int, 0b010111, VV | VX | VI, Move),
+    op(VectorOp::Vmseq, "VMSEQ", "Vector Set Mask if Equal", Int, 0b011000, VV | VX | VI, Plain),
+    op(VectorOp::Vmsne, "VMSNE", "Vector Set Mask if Not Equal", Int, 0b011001, VV | VX | VI, Plain),
+    op(VectorOp::Vmsltu, "VMSLTU", "Vector Set Mask if Less Than Unsigned", Int, 0b011010, VV | VX, Plain),
+    op(VectorOp::Vmslt, "VMSLT", "Vector Set Mask if Less Than", Int, 0b011011, VV | VX, Plain),
+    op(VectorOp::Vmsleu, "VMSLEU", "Vector Set Mask if Less Than or Equal Unsigned", Int, 0b011100, VV | VX | VI, Plain),
+    op(VectorOp::Vmsle, "VMSLE", "Vector Set Mask if Less Than or Equal", Int, 0b011101, VV | VX | VI, Plain),
+    op(VectorOp::Vmsgtu, "VMSGTU", "Vector Set Mask if Greater Than Unsigned", Int, 0b011110, VX | VI, Plain),
+    op(VectorOp::Vmsgt, "VMSGT", "Vector Set Mask if Greater Than", Int, 0b011111, VX | VI, Plain),
+    op(VectorOp::Vsaddu, "VSADDU", "Vector Saturating Add Unsigned", Int, 0b100000, VV | VX | VI, Plain),
+    op(VectorOp::Vsadd, "VSADD", "Vector Saturating Add", Int, 0b100001, VV | VX | VI, Plain),
+    op(VectorOp::Vssubu, "VSSUBU", "Vector Saturating Subtract Unsigned", Int, 0b100010, VV | VX, Plain),
+    op(VectorOp::Vssub, "VSSUB", "Vector Saturating Subtract", Int, 0b100011, VV | VX, Plain),
+    op(VectorOp::Vsll, "VSLL", "Vector Shift Left Logical", Int, 0b100101, VV | VX | VI, Plain),
+    op(VectorOp::Vsmul, "VSMUL", "Vector Fractional Multiply with Rounding and Saturation", Int, 0b100111, VV | VX, Plain),
+    op(VectorOp::Vsrl, "VSRL", "Vector Shift Right Logical", Int, 0b101000, VV | VX | VI, Plain),
+    op(VectorOp::Vsra, "VSRA", "Vector Shift Right Arithmetic", Int, 0b101001, VV | VX | VI, Plain),
+    op(VectorOp::Vssrl, "VSSRL", "Vector Scaling Shift Right Logical", Int, 0b101010, VV | VX | VI, Plain),
+    op(VectorOp::Vssra, "VSSRA", "Vector Scaling Shift Right Arithmetic", Int, 0b101011, VV | VX | VI, Plain),
+    op(VectorOp::Vnsrl, "VNSRL", "Vector Narrowing Shift Right Logical", Int, 0b101100, VV | VX | VI, Wide),
+    op(VectorOp::Vnsra, "VNSRA", "Vector Narrowing Shift Right Arithmetic", Int, 0b101101, VV | VX | VI, Wide),
+    op(VectorOp::Vnclipu, "VNCLIPU", "Vector Narrowing Fixed-Point Clip Unsigned", Int, 0b101110, VV | VX | VI, Wide),
+    op(VectorOp::Vnclip, "VNCLIP", "Vector Narrowing Fixed-Point Clip", Int, 0b101111, VV | VX | VI, Wide),
+    op(VectorOp::Vwredsumu, "VWREDSUMU", "Vector Widening Unsigned Sum Reduction", Int, 0b110000, VV, Reduction),
+    op(VectorOp::Vwredsum, "VWREDSUM", "Vector Widening Signed Sum Reduction", Int, 0b110001, VV, Reduction),
+    op(VectorOp::Vandn, "VANDN", "Vector Bitwise AND-NOT", Int, 0b000001, VV | VX, Plain),
+    op(VectorOp::Vrol, "VROL", "Vector Rotate Left", Int, 0b010101, VV | VX, Plain),
+    op(VectorOp::Vror, "VROR", "Vector Rotate Right", Int, 0b010100, VV | VX | VI, Plain),
+    op(VectorOp::Vwsll, "VWSLL", "Vector Widening Shift Left Logical", Int, 0b110101, VV | VX | VI, Plain),
+
+    op(VectorOp::Vredsum, "VREDSUM", "Vector Sum Reduction", Mul, 0b000000, VV, Reduction),
+    op(VectorOp::Vredand, "VREDAND", "Vector AND Reduction", Mul, 0b000001, VV, Reduction),
+    op(VectorOp::Vredor, "VREDOR", "Vector OR Reduction", Mul, 0b000010, VV, Reduction),
+    op(VectorOp::Vredxor, "VREDXOR", "Vector XOR Reduction", Mul, 0b000011, VV, Reduction),
+    op(VectorOp::Vredminu, "VREDMINU", "Vector Unsigned Minimum Reduction", Mul, 0b000100, VV, Reduction),
+    op(VectorOp::Vredmin, "VREDMIN", "Vector Signed Minimum Reduction", Mul, 0b000101, VV, Reduction),
+    op(VectorOp::Vredmaxu, "VREDMAXU", "Vector Unsigned Maximum Reduction", Mul, 0b000110, VV, Reduction),
+    op(VectorOp::Vredmax, "VREDMAX", "Vector Signed Maximum Reduction", Mul, 0b000111, VV, Reduction),
+    op(VectorOp::Vaaddu, "VAADDU", "Vector Averaging Add Unsigned", Mul, 0b001000, VV | VX, Plain),
+    op(VectorOp::Vaadd, "VAADD", "Vector Averaging Add", Mul, 0b001001, VV | VX, Plain),
+    op(VectorOp::Vasubu, "VASUBU", "Vector Averaging Subtract Unsigned", Mul, 0b001010, VV | VX, Plain),
+    op(VectorOp::Vasub, "VASUB", "Vector Averaging Subtract", Mul, 0b001011, VV | VX, Plain),
+    op(VectorOp::Vslide1up, "VSLIDE1UP", "Vector Slide Up by One", Mul, 0b001110, VX, Plain),
+    op(VectorOp::Vslide1down, "VSLIDE1DOWN", "Vector Slide Down by One", Mul, 0b001111, VX, Plain),
+    op(VectorOp::VmvS, "VMV", "Vector Move Scalar to Element 0", Mul, 0b010000, VX, MoveScalar),
+    op(VectorOp::Vcompress, "VCOMPRESS", "Vector Compress", Mul, 0b010111, VV, Compress),
+    op(VectorOp::Vmandn, "VMANDN", "Vector Mask AND-NOT", Mul, 0b011000, VV, MaskLogical),
+    op(VectorOp::Vmand, "VMAND", "Vector Mask AND", Mul, 0b011001, VV, MaskLogical),
+    op(VectorOp::Vmor, "VMOR", "Vector Mask OR", Mul, 0b011010, VV, MaskLogical),
+    op(VectorOp::Vmxor, "VMXOR", "Vector Mask XOR", Mul, 0b011011, VV, MaskLogical),
+    op(VectorOp::Vmorn, "VMORN", "Vector Mask OR-NOT", Mul, 0b011100, VV, MaskLogical),
+    op(VectorOp::Vmnand, "VMNAND", "Vector Mask NAND", Mul, 0b011101, VV, MaskLogical),
+    op(VectorOp::Vmnor, "VMNOR", "Vector Mask NOR", Mul, 0b011110, VV, MaskLogical),
+    op(VectorOp::Vmxnor, "VMXNOR", "Vector Mask XNOR", Mul, 0b011111, VV, MaskLogical),
+    op(VectorOp::Vdivu, "VDIVU", "Vector Unsigned Divide", Mul, 0b100000, VV | VX, Plain),
+    op(VectorOp::Vdiv, "VDIV", "Vector Signed Divide", Mul, 0b100001, VV | VX, Plain),
+    op(VectorOp::Vremu, "VREMU", "Vector Unsigned Remainder", Mul, 0b100010, VV | VX, Plain),
+    op(VectorOp::Vrem, "VREM", "Vector Signed Remainder", Mul, 0b100011, VV | VX, Plain),
+    op(VectorOp::Vmulhu, "VMULHU", "Vector Multiply High Unsigned", Mul, 0b100100, VV | VX, Plain),
+    op(VectorOp::Vmul, "VMUL", "Vector Multiply", Mul, 0b100101, VV | VX, Plain),
+    op(VectorOp::Vmulhsu, "VMULHSU", "Vector Multiply High Signed-Unsigned", Mul, 0b100110, VV | VX, Plain),
+    op(VectorOp::Vmulh, "VMULH", "Vector Multiply High", Mul, 0b100111, VV | VX, Plain),
+    op(VectorOp::Vmadd, "VMADD", "Vector Multiply-Add", Mul, 0b101001, VV | VX, MulAdd),
+    op(VectorOp::Vnmsub, "VNMSUB", "Vector Negated Multiply-Subtract", Mul, 0b101011, VV | VX, MulAdd),
+    op(VectorOp::Vmacc, "VMACC", "Vector Multiply-Accumulate", Mul, 0b101101, VV | VX, MulAdd),
+    op(VectorOp::Vnmsac, "VNMSAC", "Vector Negated Multiply-Subtract Accumulator", Mul, 0b101111, VV | VX, MulAdd),
+    op(VectorOp::Vwaddu, "VWADDU", "Vector Widening Unsigned Add", Mul, 0b110000, VV | VX, Plain),
+    op(VectorOp::Vwadd, "VWADD", "Vector Widening Signed Add", Mul, 0b110001, VV | VX, Plain),
+    op(VectorOp::Vwsubu, "VWSUBU", "Vector Widening Unsigned Subtract", Mul, 0b110010, VV | VX, Plain),
+    op(VectorOp::Vwsub, "VWSUB", "Vector Widening Signed Subtract", Mul, 0b110011, VV | VX, Plain),
+    op(VectorOp::VwadduW, "VWADDU", "Vector Widening Unsigned Add to Wide Operand", Mul, 0b110100, VV | VX, Wide),
+    op(VectorOp::VwaddW, "VWADD", "Vector Widening Signed Add to Wide Operand", Mul, 0b110101, VV | VX, Wide),
+    op(VectorOp::VwsubuW, "VWSUBU", "Vector Widening Unsigned Subtract from Wide Operand", Mul, 0b110110, VV | VX, Wide),
+    op(VectorOp::VwsubW, "VWSUB", "Vector Widening Signed Subtract from Wide Operand", Mul, 0b110111, VV | VX, Wide),
+    op(VectorOp::Vwmulu, "VWMULU", "Vector Widening Unsigned Multiply", Mul, 0b111000, VV | VX, Plain),
+    op(VectorOp::Vwmulsu, "VWMULSU", "Vector Widening Signed-Unsigned Multiply", Mul, 0b111010, VV | VX, Plain),
+    op(VectorOp::Vwmul, "VWMUL", "Vector Widening Signed Multiply", Mul, 0b111011, VV | VX, Plain),
+    op(VectorOp::Vwmaccu, "VWMACCU", "Vector Widening Unsigned Multiply-Accumulate", Mul, 0b111100, VV | VX, MulAdd),
+    op(VectorOp::Vwmacc, "VWMACC", "Vector Widening Signed Multiply-Accumulate", Mul, 0b111101, VV | VX, MulAdd),
+    op(VectorOp::Vwmaccus, "VWMACCUS", "Vector Widening Unsigned-Signed Multiply-Accumulate", Mul, 0b111110, VX, MulAdd),
+    op(VectorOp::Vwmaccsu, "VWMACCSU", "Vector Widening Signed-Unsigned Multiply-Accumulate", Mul, 0b111111, VV | VX, MulAdd),
+    op(VectorOp::Vclmul, "VCLMUL", "Vector Carry-Less Multiply", Mul, 0b001100, VV | VX, Plain),
+    op(VectorOp::Vclmulh, "VCLMULH", "Vector Carry-Less Multiply High", Mul, 0b001101, VV | VX, Plain),
+
+    op(VectorOp::Vfadd, "VFADD", "Vector Floating-Point Add", Float, 0b000000, VV | VF, Plain),
+    op(VectorOp::Vfredusum, "VFREDUSUM", "Vector Floating-Point Unordered Sum Reduction", Float, 0b000001, VV, Reduction),
+    op(VectorOp::Vfsub, "VFSUB", "Vector Floating-Point Subtract", Float, 0b000010, VV | VF, Plain),
+    op(VectorOp::Vfredosum, "VFREDOSUM", "Vector Floating-Point Ordered Sum Reduction", Float, 0b000011, VV, Reduction),
+    op(VectorOp::Vfmin, "VFMIN", "Vector Floating-Point Minimum", Float, 0b000100, VV | VF, Plain),
+    op(VectorOp::Vfredmin, "VFREDMIN", "Vector Floating-Point Minimum Reduction", Float, 0b000101, VV, Reduction),
+    op(VectorOp::Vfmax, "VFMAX", "Vector Floating-Point Maximum", Float, 0b000110, VV | VF, Plain),
+    op(VectorOp::Vfredmax, "VFREDMAX", "Vector Floating-Point Maximum Reduction", Float, 0b000111, VV, Reduction),
+    op(VectorOp::Vfsgnj, "VFSGNJ", "Vector Floating-Point Sign Injection", Float, 0b001000, VV | VF, Plain),
+    op(VectorOp::Vfsgnjn, "VFSGNJN", "Vector Floating-Point Negated Sign Injection", Float, 0b001001, VV | VF, Plain),
+    op(VectorOp::Vfsgnjx, "VFSGNJX", "Vector Floating-Point XOR Sign Injection", Float, 0b001010, VV | VF, Plain),
+    op(VectorOp::Vfslide1up, "VFSLIDE1UP", "Vector Floating-Point Slide Up by One", Float, 0b001110, VF, Plain),
+    op(VectorOp::Vfslide1down, "VFSLIDE1DOWN", "Vector Floating-Point Slide Down by One", Float, 0b001111, VF, Plain),
+    op(VectorOp::VfmvS, "VFMV", "Vector Floating-Point Move Scalar to Element 0", Float, 0b010000, VF, MoveScalar),
+    op(VectorOp::Vfmerge, "VFMERGE", "Vector Floating-Point Merge", Float, 0b010111, VF, Carry),
+    op(VectorOp::VfmvV, "VFMV", "Vector Floating-Point Move", Float, 0b010111, VF, Move),
+    op(VectorOp::Vmfeq, "VMFEQ", "Vector Floating-Point Set Mask if Equal", Float, 0b011000, VV | VF, Plain),
+    op(VectorOp::Vmfle, "VMFLE", "Vector Floating-Point Set Mask if Less Than or Equal", Float, 0b011001, VV | VF, Plain),
+    op(VectorOp::Vmflt, "VMFLT", "Vector Floating-Point Set Mask if Less Than", Float, 0b011011, VV | VF, Plain),
+    op(VectorOp::Vmfne, "VMFNE", "Vector Floating-Point Set Mask if Not Equal", Float, 0b011100, VV | VF, Plain),
+    op(VectorOp::Vmfgt, "VMFGT", "Vector Floating-Point Set Mask if Greater Than", Float, 0b011101, VF, Plain),
+    op(VectorOp::Vmfge, "VMFGE", "Vector Floating-Point Set Mask if Greater Than or Equal", Float, 0b011111, VF, Plain),
+    op(VectorOp::Vfdiv, "VFDIV", "Vector Floating-Point Divide", Float, 0b100000, VV | VF, Plain),
+    op(VectorOp::Vfrdiv, "VFRDIV", "Vector Floating-Point Reverse Divide", Float, 0b100001, VF, Plain),
+    op(VectorOp::Vfmul, "VFMUL", "Vector Floating-Point Multiply", Float, 0b100100, VV | VF, Plain),
+    op(VectorOp::Vfrsub, "VFRSUB", "Vector Floating-Point Reverse Subtract", Float, 0b100111, VF, Plain),
+    op(VectorOp::Vfmadd, "VFMADD", "Vector Floating-Point Multiply-Add", Float, 0b101000, VV | VF, MulAdd),
+    op(VectorOp::Vfnmadd, "VFNMADD", "Vector Floating-Point Negated Multiply-Add", Float, 0b101001, VV | VF, MulAdd),
+    op(VectorOp::Vfmsub, "VFMSUB", "Vector Floating-Point Multiply-Subtract", Float, 0b101010, VV | VF, MulAdd),
+    op(VectorOp::Vfnmsub, "VFNMSUB", "Vector Floating-Point Negated Multiply-Subtract", Float, 0b101011, VV | VF, MulAdd),
+    op(VectorOp::Vfmacc, "VFMACC", "Vector Floating-Point Multiply-Accumulate", Float, 0b101100, VV | VF, MulAdd),
+    op(VectorOp::Vfnmacc, "VFNMACC", "Vector Floating-Point Negated Multiply-Accumulate", Float, 0b101101, VV | VF, MulAdd),
+    op(VectorOp::Vfmsac, "VFMSAC", "Vector Floating-Point Multiply-Subtract Accumulator", Float, 0b101110, VV | VF, MulAdd),
+    op(VectorOp::Vfnmsac, "VFNMSAC", "Vector Floating-Point Negated Multiply-Subtract Accumulator", Float, 0b101111, VV | VF, MulAdd),
+    op(VectorOp::Vfwadd, "VFWADD", "Vector Floating-Point Widening Add", Float, 0b110000, VV | VF, Plain),
+    op(VectorOp::Vfwredusum, "VFWREDUSUM", "Vector Floating-Point Widening Unordered Sum Reduction", Float, 0b110001, VV, Reduction),
+    op(VectorOp::Vfwsub, "VFWSUB", "Vector Floating-Point Widening Subtract", Float, 0b110010, VV | VF, Plain),
+    op(VectorOp::Vfwredosum, "VFWREDOSUM", "Vector Floating-Point Widening Ordered Sum Reduction", Float, 0b110011, VV, Reduction),
+    op(VectorOp::VfwaddW, "VFWADD", "Vector Floating-Point Widening Add to Wide Operand", Float, 0b110100, VV | VF, Wide),
+    op(VectorOp::VfwsubW, "VFWSUB", "Vector Floating-Point Widening Subtract from Wide Operand", Float, 0b110110, VV | VF, Wide),
+    op(VectorOp::Vfwmul, "VFWMUL", "Vector Floating-Point Widening Multiply", Float, 0b111000, VV | VF, Plain),
+    op(VectorOp::Vfwmacc, "VFWMACC", "Vector Floating-Point Widening Multiply-Accumulate", Float, 0b111100, VV | VF, MulAdd),
+    op(VectorOp::Vfwnmacc, "VFWNMACC", "Vector Floating-Point Widening Negated Multiply-Accumulate", Float, 0b111101, VV | VF, MulAdd),
+    op(VectorOp::Vfwmsac, "VFWMSAC", "Vector Floating-Point Widening Multiply-Subtract Accumulator", Float, 0b111110, VV | VF, MulAdd),
+    op(VectorOp::Vfwnmsac, "VFWNMSAC", "Vector Floating-Point Widening Negated Multiply-Subtract Accumulator", Float, 0b111111, VV | VF, MulAdd),
+
+    op(VectorOp::Vghsh, "VGHSH", "Vector GHASH Add-Multiply over 128-bit Element Groups", Crypto, 0b101100, VV, ElementGroup),
+    op(VectorOp::Vsha2ms, "VSHA2MS", "Vector SHA-2 Message Schedule over 4-Element Groups", Crypto, 0b101101, VV, ElementGroup),
+    op(VectorOp::Vsha2ch, "VSHA2CH", "Vector SHA-2 Two Compression Rounds (High Message Words) over 4-Element Groups", Crypto, 0b101110, VV, ElementGroup),
+    op(VectorOp::Vsha2cl, "VSHA2CL", "Vector SHA-2 Two Compression Rounds (Low Message Words) over 4-Element Groups", Crypto, 0b101111, VV, ElementGroup),
+    op(VectorOp::Vsm3me, "VSM3ME", "Vector SM3 Message Expansion over 256-bit Element Groups", Crypto, 0b100000, VV, ElementGroup),
+    op(VectorOp::Vaeskf1, "VAESKF1", "Vector AES-128 Forward Key Schedule over 128-bit Element Groups", Crypto, 0b100010, VI, ElementGroup),
+    op(VectorOp::Vaeskf2, "VAESKF2", "Vector AES-256 Forward Key Schedule over 128-bit Element Groups", Crypto, 0b101010, VI, ElementGroup),
+    op(VectorOp::Vsm4k, "VSM4K", "Vector SM4 Key Expansion over 128-bit Element Groups", Crypto, 0b100001, VI, ElementGroup),
+    op(VectorOp::Vsm3c, "VSM3C", "Vector SM3 Two Compression Rounds over 256-bit Element Groups", Crypto, 0b101011, VI, ElementGroup),
+];
+
+/// Register file of a unary operation's destination
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dest {
+    V,
+    X,
+    F,
+}
+
+struct UnaryEntry {
+    opcode: u32,
+    op: VectorUnaryOp,
+    name: &'static str,
+    description: &'static str,
+    funct3: u32,
+    funct6: u32,
+    code: u32,
+    dest: Dest,
+    maskable: bool,
+}
+
+const fn unary(op: VectorUnaryOp, name: &'static str, description: &'static str, funct3: u32, funct6: u32, code: u32, dest: Dest, maskable: bool) -> UnaryEntry {
+    UnaryEntry { opcode: OP_V, op, name, description, funct3, funct6, code, dest, maskable }
+}
+
+/// An unmasked OP-VE operation on vd and vs2
+const fn crypto(op: VectorUnaryOp, name: &'static str, description: &'static str, funct6: u32, code: u32) -> UnaryEntry {
+    UnaryEntry { opcode: OP_VE, op, name, description, funct3: OPMVV, funct6, code, dest: Dest::V, maskable: false }
+}
+
+const OP_V: u32 = 0b1010111;
+const OP_VE: u32 = 0b1110111;
+
+// funct3 of the categories holding unary operations
+const OPMVV: u32 = 0b010;
+const OPFVV: u32 = 0b001;
+const OPIVI: u32 = 0b011;
+
+const VECTOR_UNARY_OPS: &[UnaryEntry] = &[
+    unary(VectorUnaryOp::VmvXS, "VMV.X.S", "Vector Move Element 0 to Scalar", OPMVV, 0b010000, 0b00000, Dest::X, false),
+    unary(VectorUnaryOp::VcpopM, "VCPOP.M", "Vector Mask Population Count", OPMVV, 0b010000, 0b10000, Dest::X, true),
+    unary(VectorUnaryOp::VfirstM, "VFIRST.M", "Vector Find First Set Mask Bit", OPMVV, 0b010000, 0b10001, Dest::X, true),
+    unary(VectorUnaryOp::VzextVf8, "VZEXT.VF8", "Vector Zero-Extend from Eighth Width", OPMVV, 0b010010, 0b00010, Dest::V, true),
+    unary(VectorUnaryOp::VsextVf8, "VSEXT.VF8", "Vector Sign-Extend from Eighth Width", OPMVV, 0b010010, 0b00011, Dest::V, true),
+    unary(VectorUnaryOp::VzextVf4, "VZEXT.VF4", "Vector Zero-Extend from Quarter Width", OPMVV, 0b010010, 0b00100, Dest::V, true),
+    unary(VectorUnaryOp::VsextVf4, "VSEXT.VF4", "Vector Sign-Extend from Quarter Width", OPMVV, 0b010010, 0b00101, Dest::V, true),
+    unary(VectorUnaryOp::VzextVf2, "VZEXT.VF2", "Vector Zero-Extend from Half Width", OPMVV, 0b010010, 0b00110, Dest::V, true),
+    unary(VectorUnaryOp::VsextVf2, "VSEXT.VF2", "Vector Sign-Extend from Half Width", OPMVV, 0b010010, 0b00111, Dest::V, true),
+    unary(VectorUnaryOp::VmsbfM, "VMSBF.M", "Vector Set Mask Before First", OPMVV, 0b010100, 0b00001, Dest::V, true),
+    unary(VectorUnaryOp::VmsofM, "VMSOF.M", "Vector Set Mask Only First", OPMVV, 0b010100, 0b00010, Dest::V, true),
+    unary(VectorUnaryOp::VmsifM, "VMSIF.M", "Vector Set Mask Including First", OPMVV, 0b010100, 0b00011, Dest::V, true),
+    unary(VectorUnaryOp::ViotaM, "VIOTA.M", "Vector Iota", OPMVV, 0b010100, 0b10000, Dest::V, true),
+    unary(VectorUnaryOp::VidV, "VID.V", "Vector Element Index", OPMVV, 0b010100, 0b10001, Dest::V, true),
+
+    unary(VectorUnaryOp::VfmvFS, "VFMV.F.S", "Vector Floating-Point Move Element 0 to Scalar", OPFVV, 0b010000, 0b00000, Dest::F, false),
+    unary(VectorUnaryOp::VfcvtXuFV, "VFCVT.XU.F.V", "Vector Convert Float to Unsigned Integer", OPFVV, 0b010010, 0b00000, Dest::V, true),
+    unary(VectorUnaryOp::VfcvtXFV, "VFCVT.X.F.V", "Vector Convert Float to Signed Integer", OPFVV, 0b010010, 0b00001, Dest::V, true),
+    unary(VectorUnaryOp::VfcvtFXuV, "VFCVT.F.XU.V", "Vector Convert Unsigned Integer to Float", OPFVV, 0b010010, 0b00010, Dest::V, true),
+    unary(VectorUnaryOp::VfcvtFXV, "VFCVT.F.X.V", "Vector Convert Signed Integer to Float", OPFVV, 0b010010, 0b00011, Dest::V, true),
+    unary(VectorUnaryOp::VfcvtRtzXuFV, "VFCVT.RTZ.XU.F.V", "Vector Convert Float to Unsigned Integer, Truncating", OPFVV, 0b010010, 0b00110, Dest::V, true),
+    unary(VectorUnaryOp::VfcvtRtzXFV, "VFCVT.RTZ.X.F.V", "Vector Convert Float to Signed Integer, Truncating", OPFVV, 0b010010, 0b00111, Dest::V, true),
+    unary(VectorUnaryOp::VfwcvtXuFV, "VFWCVT.XU.F.V", "Vector Widening Convert Float to Unsigned Integer", OPFVV, 0b010010, 0b01000, Dest::V, true),
+    unary(VectorUnaryOp::VfwcvtXFV, "VFWCVT.X.F.V", "Vector Widening Convert Float to Signed Integer", OPFVV, 0b010010, 0b01001, Dest::V, true),
+    unary(VectorUnaryOp::VfwcvtFXuV, "VFWCVT.F.XU.V", "Vector Widening Convert Unsigned Integer to Float", OPFVV, 0b010010, 0b01010, Dest::V, true),
+    unary(VectorUnaryOp::VfwcvtFXV, "VFWCVT.F.X.V", "Vector Widening Convert Signed Integer to Float", OPFVV, 0b010010, 0b01011, Dest::V, true),
+    unary(VectorUnaryOp::VfwcvtFFV, "VFWCVT.F.F.V", "Vector Widening Convert Float to Double-Width Float", OPFVV, 0b010010, 0b01100, Dest::V, true),
+    unary(VectorUnaryOp::VfwcvtRtzXuFV, "VFWCVT.RTZ.XU.F.V", "Vector Widening Convert Float to Unsigned Integer, Truncating", OPFVV, 0b010010, 0b01110, Dest::V, true),
+    unary(VectorUnaryOp::VfwcvtRtzXFV, "VFWCVT.RTZ.X.F.V", "Vector Widening Convert Float to Signed Integer, Truncating", OPFVV, 0b010010, 0b01111, Dest::V, true),
+    unary(VectorUnaryOp::VfncvtXuFW, "VFNCVT.XU.F.W", "Vector Narrowing Convert Float to Unsigned Integer", OPFVV, 0b010010, 0b10000, Dest::V, true),
+    unary(VectorUnaryOp::VfncvtXFW, "VFNCVT.X.F.W", "Vector Narrowing Convert Float to Signed Integer", OPFVV, 0b010010, 0b10001, Dest::V, true),
+    unary(VectorUnaryOp::VfncvtFXuW, "VFNCVT.F.XU.W", "Vector Narrowing Convert Unsigned Integer to Float", OPFVV, 0b010010, 0b10010, Dest::V, true),
+    unary(VectorUnaryOp::VfncvtFXW, "VFNCVT.F.X.W", "Vector Narrowing Convert Signed Integer to Float", OPFVV, 0b010010, 0b10011, Dest::V, true),
+    unary(VectorUnaryOp::VfncvtFFW, "VFNCVT.F.F.W", "Vector Narrowing Convert Double-Width Float to Float", OPFVV, 0b010010, 0b10100, Dest::V, true),
+    unary(VectorUnaryOp::VfncvtRodFFW, "VFNCVT.ROD.F.F.W", "Vector Narrowing Convert Double-Width Float to Float, Rounding Towards Odd", OPFVV, 0b010010, 0b10101, Dest::V, true),
+    unary(VectorUnaryOp::VfncvtRtzXuFW, "VFNCVT.RTZ.XU.F.W", "Vector Narrowing Convert Float to Unsigned Integer, Truncating", OPFVV, 0b010010, 0b10110, Dest::V, true),
+    unary(VectorUnaryOp::VfncvtRtzXFW, "VFNCVT.RTZ.X.F.W", "Vector Narrowing Convert Float to Signed Integer, Truncating", OPFVV, 0b010010, 0b10111, Dest::V, true),
+    unary(VectorUnaryOp::VfsqrtV, "VFSQRT.V", "Vector Floating-Point Square Root", OPFVV, 0b010011, 0b00000, Dest::V, true),
+    unary(VectorUnaryOp::Vfrsqrt7V, "VFRSQRT7.V", "Vector Floating-Point Reciprocal Square Root Estimate", OPFVV, 0b010011, 0b00100, Dest::V, true),
+    unary(VectorUnaryOp::Vfrec7V, "VFREC7.V", "Vector Floating-Point Reciprocal Estimate", OPFVV, 0b010011, 0b00101, Dest::V, true),
+    unary(VectorUnaryOp::VfclassV, "VFCLASS.V", "Vector Floating-Point Classify", OPFVV, 0b010011, 0b10000, Dest::V, true),
+
+    unary(VectorUnaryOp::Vmv1rV, "VMV1R.V", "Vector Whole Register Move", OPIVI, 0b100111, 0b00000, Dest::V, false),
+    unary(VectorUnaryOp::Vmv2rV, "VMV2R.V", "Vector Whole Register Move (2 Registers)", OPIVI, 0b100111, 0b00001, Dest::V, false),
+    unary(VectorUnaryOp::Vmv4rV, "VMV4R.V", "Vector Whole Register Move (4 Registers)", OPIVI, 0b100111, 0b00011, Dest::V, false),
+    unary(VectorUnaryOp::Vmv8rV, "VMV8R.V", "Vector Whole Register Move (8 Registers)", OPIVI, 0b100111, 0b00111, Dest::V, false),
+
+    unary(VectorUnaryOp::Vbrev8V, "VBREV8.V", "Vector Reverse Bits in Bytes", OPMVV, 0b010010, 0b01000, Dest::V, true),
+    unary(VectorUnaryOp::Vrev8V, "VREV8.V", "Vector Reverse Bytes", OPMVV, 0b010010, 0b01001, Dest::V, true),
+    unary(VectorUnaryOp::VbrevV, "VBREV.V", "Vector Reverse Bits in Elements", OPMVV, 0b010010, 0b01010, Dest::V, true),
+    unary(VectorUnaryOp::VclzV, "VCLZ.V", "Vector Count Leading Zeros", OPMVV, 0b010010, 0b01100, Dest::V, true),
+    unary(VectorUnaryOp::VctzV, "VCTZ.V", "Vector Count Trailing Zeros", OPMVV, 0b010010, 0b01101, Dest::V, true),
+    unary(VectorUnaryOp::VcpopV, "VCPOP.V", "Vector Population Count", OPMVV, 0b010010, 0b01110, Dest::V, true),
+
+    crypto(VectorUnaryOp::VaesdmVv, "VAESDM.VV", "Vector AES Middle-Round Decryption, Round Key per Element Group", 0b101000, 0b00000),
+    crypto(VectorUnaryOp::VaesdfVv, "VAESDF.VV", "Vector AES Final-Round Decryption, Round Key per Element Group", 0b101000, 0b00001),
+    crypto(VectorUnaryOp::VaesemVv, "VAESEM.VV", "Vector AES Middle-Round Encryption, Round Key per Element Group", 0b101000, 0b00010),
+    crypto(VectorUnaryOp::VaesefVv, "VAESEF.VV", "Vector AES Final-Round Encryption, Round Key per Element Group", 0b101000, 0b00011),
+    crypto(VectorUnaryOp::Vsm4rVv, "VSM4R.VV", "Vector SM4 Rounds, Round Keys per Element Group", 0b101000, 0b10000),
+    crypto(VectorUnaryOp::VgmulVv, "VGMUL.VV", "Vector GCM Multiply over 128-bit Element Groups", 0b101000, 0b10001),
+    crypto(VectorUnaryOp::VaesdmVs, "VAESDM.VS", "Vector AES Middle-Round Decryption, Round Key from Element Group 0", 0b101001, 0b00000),
+    crypto(VectorUnaryOp::VaesdfVs, "VAESDF.VS", "Vector AES Final-Round Decryption, Round Key from Element Group 0", 0b101001, 0b00001),
+    crypto(VectorUnaryOp::VaesemVs, "VAESEM.VS", "Vector AES Middle-Round Encryption, Round Key from Element Group 0", 0b101001, 0b00010),
+    crypto(VectorUnaryOp::VaesefVs, "VAESEF.VS", "Vector AES Final-Round Encryption, Round Key from Element Group 0", 0b101001, 0b00011),
+    crypto(VectorUnaryOp::VaeszVs, "VAESZ.VS", "Vector AES Round Zero, Round Key from Element Group 0", 0b101001, 0b00111),
+    crypto(VectorUnaryOp::Vsm4rVs, "VSM4R.VS", "Vector SM4 Rounds, Round Keys from Element Group 0", 0b101001, 0b10000),
+];
+
+fn op_entry(op: VectorOp) -> &'static OpEntry {
+    VECTOR_OPS.iter().find(|entry| entry.op == op).expect("every vector operation has a table entry")
+}
+
+fn unary_entry(op: VectorUnaryOp) -> &'static UnaryEntry {
+    VECTOR_UNARY_OPS.iter().find(|entry| entry.op == op).expect("every unary vector operation has a table entry")
+}
+
+impl VectorOp {
+    /// Returns the mnemonic without its operand form suffix, e.g. "VADD"
+    pub fn name(&self) -> &'static str {
+        op_entry(*self).name
+    }
+
+    /// Returns a description of the operation, e.g. "Vector Add"
+    pub fn description(&self) -> &'static str {
+        op_entry(*self).description
+    }
+
+    pub(crate) fn shape(&self) -> Shape {
+        op_entry(*self).shape
+    }
+
+    /// Range of the immediate of the .VI form, shifts, rotates, gathers,
+    /// slides and the crypto round numbers take it zero-extended
+    pub(crate) fn imm_range(&self) -> (i64, i64) {
+        use VectorOp::*;
+        match self {
+            Vror => (0, 63),
+            Vsll | Vsrl | Vsra | Vssrl | Vssra | Vnsrl | Vnsra | Vnclipu | Vnclip | Vrgather | Vslideup | Vslidedown
+            | Vwsll | Vaeskf1 | Vaeskf2 | Vsm4k | Vsm3c => (0, 31),
+            _ => (-16, 15),
+        }
+    }
+}
+
+impl VectorUnaryOp {
+    /// Returns the full mnemonic, e.g. "VZEXT.VF2"
+    pub fn name(&self) -> &'static str {
+        unary_entry(*self).name
+    }
+
+    /// Returns a description of the operation, e.g. "Vector Zero-Extend from Half Width"
+    pub fn description(&self) -> &'static str {
+        unary_entry(*self).description
+    }
+
+    /// Register class letter of the destination, `v`, `x` or `f`
+    pub(crate) fn dest(&self) -> char {
+        match unary_entry(*self).dest {
+            Dest::V => 'v',
+            Dest::X => 'x',
+            Dest::F => 'f',
+        }
+    }
+}
+
+/// The form letter of a source operand as the mnemonic suffix spells it
+fn form_letter(src: VectorOperand) -> char {
+    match src {
+        VectorOperand::Vector(_) => 'V',
+        VectorOperand::Scalar(_) => 'X',
+        VectorOperand::Float(_) => 'F',
+        VectorOperand::Imm(_) => 'I',
+    }
+}
+
+fn form_bit(letter: char) -> u8 {
+    match letter {
+        'V' => VV,
+        'X' => VX,
+        'I' => VI,
+        _ => VF,
+    }
+}
+
+/// Builds the full mnemonic of an arithmetic instruction, e.g. "VMADC.VIM"
+fn arith_mnemonic(entry: &OpEntry, letter: char, vm: bool) -> String {
+    let suffix = match entry.shape {
+        Plain | MulAdd | ElementGroup => format!("V{}", letter),
+        Wide => format!("W{}", letter),
+        Reduction => "VS".to_string(),
+        Carry => format!("V{}M", letter),
+        CarryOut if vm => format!("V{}", letter),
+        CarryOut => format!("V{}M", letter),
+        MaskLogical => "MM".to_string(),
+        Compress => "VM".to_string(),
+        Move => format!("V.{}", letter),
+        MoveScalar => format!("S.{}", letter),
+    };
+    return format!("{}.{}", entry.name, suffix);
+}
+
+/// Builds the full mnemonic of a load or store, e.g. "VLSSEG2E32.V"
+fn memory_mnemonic(load: bool, addressing: VectorAddressing, eew: Sew, nf: u8) -> String {
+    let dir = if load { 'L' } else { 'S' };
+    let seg = if nf > 1 { format!("SEG{}", nf) } else { String::new() };
+    let width = eew.bits();
+    match addressing {
+        VectorAddressing::UnitStride => format!("V{}{}E{}.V", dir, seg, width),
+        VectorAddressing::FaultOnlyFirst => format!("V{}{}E{}FF.V", dir, seg, width),
+        VectorAddressing::Mask => format!("V{}M.V", dir),
+        VectorAddressing::WholeRegister if load => format!("VL{}RE{}.V", nf, width),
+        VectorAddressing::WholeRegister => format!("VS{}R.V", nf),
+        VectorAddressing::Strided { .. } => format!("V{}S{}E{}.V", dir, seg, width),
+        VectorAddressing::IndexedUnordered { .. } => format!("V{}UX{}EI{}.V", dir, seg, width),
+        VectorAddressing::IndexedOrdered { .. } => format!("V{}OX{}EI{}.V", dir, seg, width),
+    }
+}
+
+/// Mnemonic family of a load or store, without element width or segment count
+pub(crate) fn memory_family(load: bool, addressing: VectorAddressing) -> &'static str {
+    match (load, addressing) {
+        (true, VectorAddressing::UnitStride) => "VLE",
+        (true, VectorAddressing::FaultOnlyFirst) => "VLEFF",
+        (true, VectorAddressing::Mask) => "VLM.V",
+        (true, VectorAddressing::WholeRegister) => "VLRE",
+        (true, VectorAddressing::Strided { .. }) => "VLSE",
+        (true, VectorAddressing::IndexedUnordered { .. }) => "VLUXEI",
+        (true, VectorAddressing::IndexedOrdered { .. }) => "VLOXEI",
+        (false, VectorAddressing::UnitStride | VectorAddressing::FaultOnlyFirst) => "VSE",
+        (false, VectorAddressing::Mask) => "VSM.V",
+        (false, VectorAddressing::WholeRegister) => "VSR",
+        (false, VectorAddressing::Strided { .. }) => "VSSE",
+        (false, VectorAddressing::IndexedUnordered { .. }) => "VSUXEI",
+        (false, VectorAddressing::IndexedOrdered { .. }) => "VSOXEI",
+    }
+}
+
+pub(crate) fn memory_description(load: bool, addressing: VectorAddressing, nf: u8) -> &'static str {
+    let segment = nf > 1;
+    match (load, addressing, segment) {
+        (true, VectorAddressing::UnitStride, false) => "Vector Unit-Stride Load",
+        (true, VectorAddressing::UnitStride, true) => "Vector Unit-Stride Segment Load",
+        (true, VectorAddressing::FaultOnlyFirst, false) => "Vector Fault-Only-First Load",
+        (true, VectorAddressing::FaultOnlyFirst, true) => "Vector Fault-Only-First Segment Load",
+        (true, VectorAddressing::Mask, _) => "Vector Mask Load",
+        (true, VectorAddressing::WholeRegister, _) => "Vector Whole Register Load",
+        (true, VectorAddressing::Strided { .. }, false) => "Vector Strided Load",
+        (true, VectorAddressing::Strided { .. }, true) => "Vector Strided Segment Load",
+        (true, VectorAddressing::IndexedUnordered { .. }, false) => "Vector Indexed-Unordered Load",
+        (true, VectorAddressing::IndexedUnordered { .. }, true) => "Vector Indexed-Unordered Segment Load",
+        (true, VectorAddressing::IndexedOrdered { .. }, false) => "Vector Indexed-Ordered Load",
+        (true, VectorAddressing::IndexedOrdered { .. }, true) => "Vector Indexed-Ordered Segment Load",
+        (false, VectorAddressing::UnitStride | VectorAddressing::FaultOnlyFirst, false) => "Vector Unit-Stride Store",
+        (false, VectorAddressing::UnitStride | VectorAddressing::FaultOnlyFirst, true) => "Vector Unit-Stride Segment Store",
+        (false, VectorAddressing::Mask, _) => "Vector Mask Store",
+        (false, VectorAddressing::WholeRegister, _) => "Vector Whole Register Store",
+        (false, VectorAddressing::Strided { .. }, false) => "Vector Strided Store",
+        (false, VectorAddressing::Strided { .. }, true) => "Vector Strided Segment Store",
+        (false, VectorAddressing::IndexedUnordered { .. }, false) => "Vector Indexed-Unordered Store",
+        (false, VectorAddressing::IndexedUnordered { .. }, true) => "Vector Indexed-Unordered Segment Store",
+        (false, VectorAddressing::IndexedOrdered { .. }, false) => "Vector Indexed-Ordered Store",
+        (false, VectorAddressing::IndexedOrdered { .. }, true) => "Vector Indexed-Ordered Segment Store",
+    }
+}
+
+/*
+ * Formatting and field breakdown, shared by `Display` and `fields` for
+ * `Instruction`. A clear vm bit is written as a trailing `v0.t`, except for
+ * the carry forms where v0 is an ordinary operand.
+ */
+
+fn write_mask(f: &mut fmt::Formatter<'_>, vm: bool) -> fmt::Result {
+    if vm {
+        return Ok(());
+    }
+    f.write_str(", v0.t")
+}
+
+pub(crate) fn fmt_memory(f: &mut fmt::Formatter<'_>, load: bool, data: u8, rs1: u8, addressing: VectorAddressing, eew: Sew, nf: u8, vm: bool) -> fmt::Result {
+    write!(f, "{} v{}, (x{})", memory_mnemonic(load, addressing, eew, nf), data, rs1)?;
+    match addressing {
+        VectorAddressing::Strided { rs2 } => write!(f, ", x{}", rs2)?,
+        VectorAddressing::IndexedUnordered { vs2 } | VectorAddressing::IndexedOrdered { vs2 } => write!(f, ", v{}", vs2)?,
+        _ => {}
+    }
+    write_mask(f, vm)
+}
+
+pub(crate) fn fmt_arith(f: &mut fmt::Formatter<'_>, op: VectorOp, vd: u8, vs2: u8, src: VectorOperand, vm: bool) -> fmt::Result {
+    let entry = op_entry(op);
+    f.write_str(&arith_mnemonic(entry, form_letter(src), vm))?;
+    match entry.shape {
+        MulAdd => write!(f, " v{}, {}, v{}", vd, src, vs2)?,
+        Move | MoveScalar => write!(f, " v{}, {}", vd, src)?,
+        _ => write!(f, " v{}, v{}, {}", vd, vs2, src)?,
+    }
+    match entry.shape {
+        Carry | CarryOut if !vm => f.write_str(", v0"),
+        _ => write_mask(f, vm),
+    }
+}
+
+pub(crate) fn fmt_unary(f: &mut fmt::Formatter<'_>, op: VectorUnaryOp, vd: u8, vs2: u8, vm: bool) -> fmt::Result {
+    write!(f, "{} {}{}", op.name(), op.dest(), vd)?;
+    if op != VectorUnaryOp::VidV {
+        write!(f, ", v{}", vs2)?;
+    }
+    write_mask(f, vm)
+}
+
+pub(crate) fn memory_fields(load: bool, data: u8, rs1: u8, addressing: VectorAddressing, vm: bool) -> Vec<Field> {
+    let mut fields = Vec::new();
+    if load {
+        fields.push(Field::Rd(Register::V(data)));
+    }
+    fields.push(Field::Rs1(Register::X(rs1)));
+    match addressing {
+        VectorAddressing::Strided { rs2 } => fields.push(Field::Rs2(Register::X(rs2))),
+        VectorAddressing::IndexedUnordered { vs2 } | VectorAddressing::IndexedOrdered { vs2 } => {
+            fields.push(Field::Rs2(Register::V(vs2)));
+        }
+        _ => {}
+    }
+    if !load {
+        fields.push(Field::Rs3(Register::V(data)));
+    }
+    fields.push(Field::Vm(vm));
+    return fields;
+}
+
+pub(crate) fn arith_fields(op: VectorOp, vd: u8, vs2: u8, src: VectorOperand, vm: bool) -> Vec<Field> {
+    let mut fields = vec![Field::Rd(Register::V(vd))];
+    fields.push(match src {
+        VectorOperand::Vector(n) => Field::Rs1(Register::V(n)),
+        VectorOperand::Scalar(n) => Field::Rs1(Register::X(n)),
+        VectorOperand::Float(n) => Field::Rs1(Register::F(n)),
+        VectorOperand::Imm(imm) => Field::Imm(imm as i32),
+    });
+    if !matches!(op.shape(), Move | MoveScalar) {
+        fields.push(Field::Rs2(Register::V(vs2)));
+    }
+    fields.push(Field::Vm(vm));
+    return fields;
+}
+
+pub(crate) fn unary_fields(op: VectorUnaryOp, vd: u8, vs2: u8, vm: bool) -> Vec<Field> {
+    let rd = match op.dest() {
+        'x' => Register::X(vd),
+        'f' => Register::F(vd),
+        _ => Register::V(vd),
+    };
+    let mut fields = vec![Field::Rd(rd)];
+    if op != VectorUnaryOp::VidV {
+        fields.push(Field::Rs2(Register::V(vs2)));
+    }
+    fields.push(Field::Vm(vm));
+    return fields;
+}
+
+/*
+ * Mnemonic lookup for the parser. The vector mnemonics carry their operand
+ * form, element width or segment count, so they are matched against the
+ * names the formatter builds rather than listed one by one.
+ */
+
+/// Splits a load or store mnemonic such as "VLSEG2E32.V" into its parts, the
+/// stride or index register of the addressing mode is left as 0
+pub(crate) fn memory_from_mnemonic(mnemonic: &str) -> Option<(bool, VectorAddressing, Sew, u8)> {
+    let modes = [
+        VectorAddressing::UnitStride,
+        VectorAddressing::FaultOnlyFirst,
+        VectorAddressing::Mask,
+        VectorAddressing::WholeRegister,
+        VectorAddressing::Strided { rs2: 0 },
+        VectorAddressing::IndexedUnordered { vs2: 0 },
+        VectorAddressing::IndexedOrdered { vs2: 0 },
+    ];
+    for load in [true, false] {
+        for addressing in modes {
+            for eew in [Sew::E8, Sew::E16, Sew::E32, Sew::E64] {
+                for nf in 1..=8 {
+                    let valid = match addressing {
+                        VectorAddressing::FaultOnlyFirst => load,
+                        VectorAddressing::Mask => eew == Sew::E8 && nf == 1,
+                        VectorAddressing::WholeRegister => matches!(nf, 1 | 2 | 4 | 8) && (load || eew == Sew::E8),
+                        _ => true,
+                    };
+                    if valid && memory_mnemonic(load, addressing, eew, nf) == mnemonic {
+                        return Some((load, addressing, eew, nf));
+                    }
+                }
+            }
+        }
+    }
+    return None;
+}
+
+pub(crate) fn unary_from_mnemonic(mnemonic: &str) -> Option<VectorUnaryOp> {
+    VECTOR_UNARY_OPS.iter().find(|entry| entry.name == mnemonic).map(|entry| entry.op)
+}
+
+/// Finds the operation behind an arithmetic mnemonic such as "VADD.VI",
+/// returning it with its form letter and whether v0 follows as a carry operand
+pub(crate) fn arith_from_mnemonic(mnemonic: &str) -> Option<(VectorOp, char, bool)> {
+    let (name, _) = mnemonic.split_once('.')?;
+    for entry in VECTOR_OPS.iter().filter(|entry| entry.name == name) {
+        for letter in ['V', 'X', 'I', 'F'] {
+            if entry.forms & form_bit(letter) == 0 {
+                continue;
+            }
+            for vm in [true, false] {
+                if entry.shape.allows_vm(vm) && arith_mnemonic(entry, letter, vm) == mnemonic {
+                    let carry = matches!(entry.shape, Carry | CarryOut) && !vm;
+                    return Some((entry.op, letter, carry));
+                }
+            }
+        }
+    }
+    return None;
+}
+
+/*
+ * Encoding support, the encoder assembles the words from these fields.
+ */
+
+/// Returns the opcode, funct3 and funct6 of an arithmetic instruction,
+/// rejecting forms and vm settings the operation does not have
+pub(crate) fn arith_encoding(op: VectorOp, vs2: u8, src: VectorOperand, vm: bool) -> Option<(u32, u32, u32)> {
+    let entry = op_entry(op);
+    let letter = form_letter(src);
+    if entry.forms & form_bit(letter) == 0 || !entry.shape.allows_vm(vm) {
+        return None;
+    }
+    if matches!(entry.shape, Move | MoveScalar) && vs2 != 0 {
+        return None;
+    }
+    let funct3 = match (entry.group, letter) {
+        (Int, 'V') => 0b000,
+        (Int, 'X') => 0b100,
+        (Int, _) => 0b011,
+        (Mul, 'V') => 0b010,
+        (Mul, _) => 0b110,
+        (Float, 'V') => 0b001,
+        (Float, _) => 0b101,
+        (Crypto, _) => OPMVV,
+    };
+    let opcode = if entry.group == Crypto { OP_VE } else { OP_V };
+    return Some((opcode, funct3, entry.funct6));
+}
+
+/// Returns the opcode, funct3, funct6 and the vs1 sub-opcode of a unary instruction
+pub(crate) fn unary_encoding(op: VectorUnaryOp, vd: u8, vs2: u8, vm: bool) -> Option<(u32, u32, u32, u32)> {
+    let entry = unary_entry(op);
+    if (!vm && !entry.maskable) || (op == VectorUnaryOp::VidV && vs2 != 0) || misaligned_move(entry, vd, vs2).is_some() {
+        return None;
+    }
+    return Some((entry.opcode, entry.funct3, entry.funct6, entry.code));
+}
+
+/*
+ * Decoding
+ */
+
+/// Whole register moves need register numbers aligned to the group size,
+/// returns the misaligned field
+fn misaligned_move(entry: &UnaryEntry, vd: u8, vs2: u8) -> Option<(&'static str, u8)> {
+    if entry.funct3 != OPIVI {
+        return None;
+    }
+    let count = entry.code as u8 + 1;
+    if vd % count != 0 {
+        return Some(("vd", vd));
+    }
+    if vs2 % count != 0 {
+        return Some(("vs2", vs2));
+    }
+    return None;
+}
+
+/// Decodes an OP-V (1010111) instruction
+pub(crate) fn decode_op_v(instr: u32) -> Result<Instruction, DecodeError> {
+    let funct6 = bits(instr, 31, 26);
+    let vm = bits(instr, 25, 25) == 1;
+    let vs2 = bits(instr, 24, 20) as u8;
+    let vs1 = bits(instr, 19, 15);
+    let funct3 = bits(instr, 14, 12);
+    let vd = bits(instr, 11, 7) as u8;
+
+    if funct3 == 0b111 {
+        return decode_vset(instr);
+    }
+
+    // Unary operations hold a sub-opcode in the vs1 field
+    if let Some(entry) = VECTOR_UNARY_OPS.iter().find(|e| e.opcode == OP_V && e.funct3 == funct3 && e.funct6 == funct6 && e.code == vs1) {
+        if !vm && !entry.maskable {
+            return Err(DecodeError::ReservedFunct { word: instr, field: "vm", value: 0 });
+        }
+        if entry.op == VectorUnaryOp::VidV && vs2 != 0 {
+            return Err(DecodeError::ReservedFunct { word: instr, field: "vs2", value: vs2 as u32 });
+        }
+        if let Some((field, register)) = misaligned_move(entry, vd, vs2) {
+            return Err(DecodeError::ReservedFunct { word: instr, field, value: register as u32 });
+        }
+        return Ok(Instruction::VectorUnary { op: entry.op, vd, vs2, vm });
+    }
+    if VECTOR_UNARY_OPS.iter().any(|e| e.opcode == OP_V && e.funct3 == funct3 && e.funct6 == funct6) {
+        return Err(DecodeError::ReservedFunct { word: instr, field: "vs1", value: vs1 });
+    }
+
+    // VROR.VI holds bit 5 of its rotate amount in the low bit of funct6
+    let (funct6, imm_high) = match (funct3, funct6) {
+        (OPIVI, 0b010101) => (0b010100, 32),
+        _ => (funct6, 0),
+    };
+    let (group, form) = match funct3 {
+        0b000 => (Int, VV),
+        0b001 => (Float, VV),
+        0b010 => (Mul, VV),
+        0b011 => (Int, VI),
+        0b100 => (Int, VX),
+        0b101 => (Float, VF),
+        _ => (Mul, VX),
+    };
+    let mut candidates = VECTOR_OPS.iter().filter(|e| e.group == group && e.funct6 == funct6 && e.forms & form != 0).peekable();
+    if candidates.peek().is_none() {
+        return Err(DecodeError::ReservedFunct { word: instr, field: "funct6", value: funct6 });
+    }
+    // VMERGE/VMV.V and VFMERGE/VFMV.V share a funct6 and differ in vm
+    let entry = match candidates.find(|e| e.shape.allows_vm(vm)) {
+        Some(entry) => entry,
+        None => return Err(DecodeError::ReservedFunct { word: instr, field: "vm", value: vm as u32 }),
+    };
+    if matches!(entry.shape, Move | MoveScalar) && vs2 != 0 {
+        return Err(DecodeError::ReservedFunct { word: instr, field: "vs2", value: vs2 as u32 });
+    }
+
+    let src = match form {
+        VV => VectorOperand::Vector(vs1 as u8),
+        VX => VectorOperand::Scalar(vs1 as u8),
+        VF => VectorOperand::Float(vs1 as u8),
+        _ if entry.op.imm_range().0 == 0 => VectorOperand::Imm((vs1 | imm_high) as i8),
+        _ => VectorOperand::Imm(sign_extend(vs1, 5) as i8),
+    };
+    return Ok(Instruction::VectorArith { op: entry.op, vd, vs2, src, vm });
+}
+
+/// Decodes the OP-VE vector crypto instructions, which are all unmasked
+pub(crate) fn decode_op_ve(instr: u32) -> Result<Instruction, DecodeError> {
+    let funct6 = bits(instr, 31, 26);
+    let vm = bits(instr, 25, 25) == 1;
+    let vs2 = bits(instr, 24, 20) as u8;
+    let vs1 = bits(instr, 19, 15);
+    let funct3 = bits(instr, 14, 12);
+    let vd = bits(instr, 11, 7) as u8;
+
+    if funct3 != OPMVV {
+        return Err(DecodeError::ReservedFunct { word: instr, field: "funct3", value: funct3 });
+    }
+    if !vm {
+        return Err(DecodeError::ReservedFunct { word: instr, field: "vm", value: 0 });
+    }
+
+    if let Some(entry) = VECTOR_UNARY_OPS.iter().find(|e| e.opcode == OP_VE && e.funct6 == funct6 && e.code == vs1) {
+        return Ok(Instruction::VectorUnary { op: entry.op, vd, vs2, vm });
+    }
+    if VECTOR_UNARY_OPS.iter().any(|e| e.opcode == OP_VE && e.funct6 == funct6) {
+        return Err(DecodeError::ReservedFunct { word: instr, field: "vs1", value: vs1 });
+    }
+
+    let entry = match VECTOR_OPS.iter().find(|e| e.group == Crypto && e.funct6 == funct6) {
+        Some(entry) => entry,
+        None => return Err(DecodeError::ReservedFunct { word: instr, field: "funct6", value: funct6 }),
+    };
+    // The round number or constant of the .VI forms sits in the vs1 field
+    let src = match entry.forms {
+        VI => VectorOperand::Imm(vs1 as i8),
+        _ => VectorOperand::Vector(vs1 as u8),
+    };
+    return Ok(Instruction::VectorArith { op: entry.op, vd, vs2, src, vm });
+}
+
+/// Decodes VSETVLI, VSETIVLI and VSETVL
+fn decode_vset(instr: u32) -> Result<Instruction, DecodeError> {
+    let rd = bits(instr, 11, 7) as u8;
+    let rs1 = bits(instr, 19, 15) as u8;
+
+    let vtype = |zimm: u32| Vtype::from_bits(zimm).ok_or(DecodeError::ReservedFunct { word: instr, field: "vtype", value: zimm });
+    if bits(instr, 31, 31) == 0 {
+        return Ok(Instruction::Vsetvli { rd, rs1, vtype: vtype(bits(instr, 30, 20))? });
+    }
+    if bits(instr, 31, 30) == 0b11 {
+        return Ok(Instruction::Vsetivli { rd, uimm: rs1, vtype: vtype(bits(instr, 29, 20))? });
+    }
+    let funct7 = bits(instr, 31, 25);
+    if funct7 != 0b1000000 {
+        return Err(DecodeError::ReservedFunct { word: instr, field: "funct7", value: funct7 });
+    }
+    return Ok(Instruction::Vsetvl { rd, rs1, rs2: bits(instr, 24, 20) as u8 });
+}
+
+/// Decodes a vector load (LOAD-FP) or store (STORE-FP), the caller has
+/// checked that the width field names a vector element width
+pub(crate) fn decode_vector_memory(instr: u32) -> Result<Instruction, DecodeError> {
+    let nf = bits(instr, 31, 29) as u8 + 1;
+    let mew = bits(instr, 28, 28);
+    let mop = bits(instr, 27, 26);
+    let vm = bits(instr, 25, 25) == 1;
+    let field = bits(instr, 24, 20);        // lumop/sumop, rs2 or vs2
+    let rs1 = bits(instr, 19, 15) as u8;
+    let width = bits(instr, 14, 12);
+    let data = bits(instr, 11, 7) as u8;    // vd for loads, vs3 for stores
+    let load = bits(instr, 6, 0) == 0b0000111;
+    let eew = match Sew::from_width(width) {
+        Some(eew) => eew,
+        None => return Err(DecodeError::ReservedFunct { word: instr, field: "width", value: width }),
+    };
+
+    // mew is reserved for element widths above 64 bits
+    if mew != 0 {
+        return Err(DecodeError::ReservedFunct { word: instr, field: "mew", value: mew });
+    }
+    let addressing = match mop {
+        0b00 => match field {
+            0b00000 => VectorAddressing::UnitStride,
+            0b01000 => VectorAddressing::WholeRegister,
+            0b01011 => VectorAddressing::Mask,
+            0b10000 if load => VectorAddressing::FaultOnlyFirst,
+            _ => return Err(DecodeError::ReservedFunct { word: instr, field: if load { "lumop" } else { "sumop" }, value: field }),
+        },
+        0b01 => VectorAddressing::IndexedUnordered { vs2: field as u8 },
+        0b10 => VectorAddressing::Strided { rs2: field as u8 },
+        _ => VectorAddressing::IndexedOrdered { vs2: field as u8 },
+    };
+    match addressing {
+        VectorAddressing::WholeRegister | VectorAddressing::Mask if !vm => {
+            return Err(DecodeError::ReservedFunct { word: instr, field: "vm", value: 0 });
+        }
+        VectorAddressing::WholeRegister if !matches!(nf, 1 | 2 | 4 | 8) => {
+            return Err(DecodeError::ReservedFunct { word: instr, field: "nf", value: nf as u32 - 1 });
+        }
+        // The register group must start at a multiple of its size
+        VectorAddressing::WholeRegister if data % nf != 0 => {
+            return Err(DecodeError::ReservedFunct { word: instr, field: if load { "vd" } else { "vs3" }, value: data as u32 });
+        }
+        VectorAddressing::Mask if nf != 1 => {
+            return Err(DecodeError::ReservedFunct { word: instr, field: "nf", value: nf as u32 - 1 });
+        }
+        // Mask accesses and whole register stores move bytes
+        VectorAddressing::Mask if eew != Sew::E8 => {
+            return Err(DecodeError::ReservedFunct { word: instr, field: "width", value: width });
+        }
+        VectorAddressing::WholeRegister if !load && eew != Sew::E8 => {
+            return Err(DecodeError::ReservedFunct { word: instr, field: "width", value: width });
+        }
+        _ => {}
+    }
+
+    if load {
+        return Ok(Instruction::VectorLoad { vd: data, rs1, addressing, eew, nf, vm });
+    }
+    return Ok(Instruction::VectorStore { vs3: data, rs1, addressing, eew, nf, vm });
+}
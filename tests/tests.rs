@@ -1,11 +1,27 @@
 use rv_decoder::convert_binary_string_to_vector;
 use rv_decoder::instruction_decoder;
+use rv_decoder::{decode, decode_bytes, decode_instruction, DecodeError, Instruction, RoundingMode};
+use rv_decoder::{encode, EncodeError, ParseError, ParseErrorKind};
+use rv_decoder::{decode_with_observer, DecodeObserver, Field, FliConstant, Register, VerboseObserver};
+use rv_decoder::{abstract_command_decoder, decode_abstract_command, AbstractCommand};
+use rv_decoder::{csr_name, csr_number};
+use rv_decoder::{compress, compression_summary, decode_compressed, expand_compressed, CompressionSummary, DecoderConfig, Extensions, Xlen};
+use rv_decoder::{Lmul, Sew, VectorAddressing, VectorOp, VectorOperand, VectorUnaryOp, Vtype};
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
 
+    /// Checks that `word` decodes to `expected` under `config`, that it is
+    /// written as `text`, and that encoding and parsing give both back
+    fn assert_round_trip(config: &DecoderConfig, word: u32, expected: Instruction, text: &str) {
+        assert_eq!(config.decode(word), Ok(expected), "{:#010x}", word);
+        assert_eq!(expected.to_string(), text);
+        assert_eq!(config.encode(&expected), Ok(word), "{}", text);
+        assert_eq!(config.parse(text), Ok(expected), "{}", text);
+    }
+
     #[test]
     fn load_instructions() {
         //Load Byte
@@ -328,6 +344,55 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn multiplication_extension_rv64() {
+        let rv64 = DecoderConfig::rv64();
+        // Multiply word
+        let binary_instruction = "00000010001100010000000010111011";
+        let word = u32::from_str_radix(binary_instruction, 2).unwrap();
+        let expected = "MULW x1, x2, x3";
+        let result = rv64.decode(word).unwrap().to_string();
+        assert_eq!(result, expected);
+
+        // Divide signed word
+        let binary_instruction = "00000010001100010100000010111011";
+        let word = u32::from_str_radix(binary_instruction, 2).unwrap();
+        let expected = "DIVW x1, x2, x3";
+        let result = rv64.decode(word).unwrap().to_string();
+        assert_eq!(result, expected);
+
+        // Divide unsigned word
+        let binary_instruction = "00000010001100010101000010111011";
+        let word = u32::from_str_radix(binary_instruction, 2).unwrap();
+        let expected = "DIVUW x1, x2, x3";
+        let result = rv64.decode(word).unwrap().to_string();
+        assert_eq!(result, expected);
+
+        // Remainder signed word
+        let binary_instruction = "00000010001100010110000010111011";
+        let word = u32::from_str_radix(binary_instruction, 2).unwrap();
+        let expected = "REMW x1, x2, x3";
+        let result = rv64.decode(word).unwrap().to_string();
+        assert_eq!(result, expected);
+
+        // Remainder unsigned word
+        let binary_instruction = "00000010001100010111000010111011";
+        let word = u32::from_str_radix(binary_instruction, 2).unwrap();
+        let expected = "REMUW x1, x2, x3";
+        let result = rv64.decode(word).unwrap().to_string();
+        assert_eq!(result, expected);
+
+        // The W forms are RV64 only
+        let result = decode(0x023100bb);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x023100bb, xlen: Xlen::Rv32 }));
+
+        // Encoding and parsing round trip
+        for (word, text) in [(0x023100bb, "MULW x1, x2, x3"), (0x023170bb, "REMUW x1, x2, x3")] {
+            let instruction = rv64.parse(text).unwrap();
+            assert_eq!(rv64.encode(&instruction), Ok(word));
+        }
+    }
+
     #[test]
     fn atomic_instructions() {
         // Load Reserved
@@ -408,6 +473,45 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn atomic_ordering_and_doublewords() {
+        let rv64 = DecoderConfig::rv64();
+        let cases = [
+            // aq and rl bits render as mnemonic suffixes
+            (0x1401a0af, Instruction::LrW { rd: 1, rs1: 3, aq: true, rl: false }, "LR.W.AQ x1, x3"),
+            (0x1a11a0af, Instruction::ScW { rd: 1, rs1: 3, rs2: 1, aq: false, rl: true }, "SC.W.RL x1, x1, x3"),
+            (0x0621a0af, Instruction::AmoaddW { rd: 1, rs1: 3, rs2: 2, aq: true, rl: true }, "AMOADD.W.AQRL x1, x2, x3"),
+            // Double-word forms use funct3 011
+            (0x100532af, Instruction::LrD { rd: 5, rs1: 10, aq: false, rl: false }, "LR.D x5, x10"),
+            (0x1e6532af, Instruction::ScD { rd: 5, rs1: 10, rs2: 6, aq: true, rl: true }, "SC.D.AQRL x5, x6, x10"),
+            (0x0821b0af, Instruction::AmoswapD { rd: 1, rs1: 3, rs2: 2, aq: false, rl: false }, "AMOSWAP.D x1, x2, x3"),
+            (0xe221b0af, Instruction::AmomaxuD { rd: 1, rs1: 3, rs2: 2, aq: false, rl: true }, "AMOMAXU.D.RL x1, x2, x3"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&rv64, word, expected, text);
+        }
+
+        // Word forms keep their ordering bits under RV32
+        let result = decode(0x0621a0af).unwrap().to_string();
+        assert_eq!(result, "AMOADD.W.AQRL x1, x2, x3");
+
+        // Double-word atomics are RV64 only
+        let result = decode(0x100532af);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x100532af, xlen: Xlen::Rv32 }));
+
+        // LR has no rs2 operand, a nonzero rs2 field is reserved
+        let result = decode(0x1041a0af);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x1041a0af, field: "rs2", value: 4 }));
+
+        // Only the word and double-word widths exist
+        let result = decode(0x002180af);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x002180af, field: "funct3", value: 0b000 }));
+
+        // Ordering suffixes only apply to atomics
+        let result = "ADD.AQ x1, x2, x3".parse::<Instruction>();
+        assert_eq!(result, Err(ParseError { column: 1, kind: ParseErrorKind::UnknownMnemonic("ADD.AQ".to_string()) }));
+    }
+
     #[test]
     fn floating_point_instructions() {
         // Fused multiply addition
@@ -467,7 +571,7 @@ mod tests {
         assert_eq!(result, expected);
 
         // Floating point square root
-        let binary_instruction = "01011001000001000001000111010011";
+        let binary_instruction = "01011000000001000001000111010011";
         let instr = convert_binary_string_to_vector(binary_instruction);
         let expected = "FSQRT.S f3, f8, RTZ";
         let result = instruction_decoder(instr);
@@ -652,7 +756,7 @@ mod tests {
         assert_eq!(result, expected);
 
         // FSQRT.D
-        let binary_instruction = "01011011001011000001001111010011";
+        let binary_instruction = "01011010000011000001001111010011";
         let instr = convert_binary_string_to_vector(binary_instruction);
         let expected = "FSQRT.D f7, f24, RTZ";
         let result = instruction_decoder(instr);
@@ -777,4 +881,1392 @@ mod tests {
         let result = instruction_decoder(instr);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn floating_point_rv64_conversions() {
+        let rv64 = DecoderConfig::rv64();
+        let cases = [
+            // Single precision to and from 64-bit integers
+            (0xc0211553, Instruction::FcvtLS { rd: 10, rs1: 2, rm: RoundingMode::Rtz }, "FCVT.L.S x10, f2, RTZ"),
+            (0xc0317553, Instruction::FcvtLuS { rd: 10, rs1: 2, rm: RoundingMode::Dyn }, "FCVT.LU.S x10, f2, DYN"),
+            (0xd02580d3, Instruction::FcvtSL { rd: 1, rs1: 11, rm: RoundingMode::Rne }, "FCVT.S.L f1, x11, RNE"),
+            (0xd035f0d3, Instruction::FcvtSLu { rd: 1, rs1: 11, rm: RoundingMode::Dyn }, "FCVT.S.LU f1, x11, DYN"),
+            // Double precision to and from 64-bit integers
+            (0xc2211553, Instruction::FcvtLD { rd: 10, rs1: 2, rm: RoundingMode::Rtz }, "FCVT.L.D x10, f2, RTZ"),
+            (0xc2317553, Instruction::FcvtLuD { rd: 10, rs1: 2, rm: RoundingMode::Dyn }, "FCVT.LU.D x10, f2, DYN"),
+            (0xd225f0d3, Instruction::FcvtDL { rd: 1, rs1: 11, rm: RoundingMode::Dyn }, "FCVT.D.L f1, x11, DYN"),
+            (0xd235a0d3, Instruction::FcvtDLu { rd: 1, rs1: 11, rm: RoundingMode::Rdn }, "FCVT.D.LU f1, x11, RDN"),
+            // Raw double moves between register files
+            (0xe20182d3, Instruction::FmvXD { rd: 5, rs1: 3 }, "FMV.X.D x5, f3"),
+            (0xf20281d3, Instruction::FmvDX { rd: 3, rs1: 5 }, "FMV.D.X f3, x5"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&rv64, word, expected, text);
+
+            // None of these exist under RV32
+            let result = decode(word);
+            assert_eq!(result, Err(DecodeError::UnsupportedXlen { word, xlen: Xlen::Rv32 }), "{}", text);
+        }
+
+        // FMV.X.* does not exist for the quad format
+        let result = rv64.decode(0xe60182d3);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0xe60182d3, field: "fmt", value: 0b11 }));
+    }
+
+    #[test]
+    fn typed_instructions() {
+        // Load Byte
+        let binary_instruction = "00000000010000000000001000000011";
+        let instr = convert_binary_string_to_vector(binary_instruction);
+        let expected = Instruction::Lb { rd: 4, rs1: 0, imm: 4 };
+        let result = decode_instruction(instr).unwrap();
+        assert_eq!(result, expected);
+        assert_eq!(result.mnemonic(), "LB");
+
+        // Add immediate
+        let binary_instruction = "11111100111000001000011110010011";
+        let instr = convert_binary_string_to_vector(binary_instruction);
+        let expected = Instruction::Addi { rd: 15, rs1: 1, imm: -50 };
+        let result = decode_instruction(instr).unwrap();
+        assert_eq!(result, expected);
+
+        // Atomic Add
+        let binary_instruction = "00000000001000011010000010101111";
+        let instr = convert_binary_string_to_vector(binary_instruction);
+        let expected = Instruction::AmoaddW { rd: 1, rs1: 3, rs2: 2, aq: false, rl: false };
+        let result = decode_instruction(instr).unwrap();
+        assert_eq!(result, expected);
+        assert_eq!(result.to_string(), "AMOADD.W x1, x2, x3");
+
+        // Fused multiply addition
+        let binary_instruction = "00100001000001000001000111000011";
+        let instr = convert_binary_string_to_vector(binary_instruction);
+        let expected = Instruction::FmaddS { rd: 3, rs1: 8, rs2: 16, rs3: 4, rm: RoundingMode::Rtz };
+        let result = decode_instruction(instr).unwrap();
+        assert_eq!(result, expected);
+        assert_eq!(result.to_string(), "FMADD.S f3, f8, f16, f4, RTZ");
+    }
+
+    #[test]
+    fn word_and_byte_decoding() {
+        // Store Word
+        let expected = "SW x14, 8(x2)";
+        let result = decode(0b00000000111000010010010000100011).unwrap();
+        assert_eq!(result.to_string(), expected);
+
+        // Same word as little-endian bytes
+        let result = decode_bytes(&[0x23, 0x24, 0xe1, 0x00]).unwrap();
+        assert_eq!(result.to_string(), expected);
+
+        // Branch Equal with a negative offset
+        let expected = Instruction::Beq { rs1: 1, rs2: 2, imm: -4 };
+        let result = decode(0xfe208ee3).unwrap();
+        assert_eq!(result, expected);
+
+        // The string and word APIs agree
+        let binary_instruction = "00000000001100010000000010110011";
+        let instr = convert_binary_string_to_vector(binary_instruction);
+        assert_eq!(decode_instruction(instr).unwrap(), decode(0x003100b3).unwrap());
+    }
+
+    #[test]
+    fn decode_errors() {
+        // Unknown opcode
+        let result = decode(0xffffffff);
+        assert_eq!(result, Err(DecodeError::UnknownOpcode { word: 0xffffffff, opcode: 0b1111111 }));
+
+        // Reserved funct3 in the load opcode
+        let result = decode(0b00000000010000000111001000000011);
+        let expected = DecodeError::ReservedFunct { word: 0b00000000010000000111001000000011, field: "funct3", value: 0b111 };
+        assert_eq!(result, Err(expected));
+
+        // JALR only defines funct3 = 000
+        let result = decode(0x79766967);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x79766967, field: "funct3", value: 0b110 }));
+
+        // Reserved funct7 in the arithmetic opcode
+        let result = decode(0b01000010001100010000000010110011);
+        let expected = DecodeError::ReservedFunct { word: 0b01000010001100010000000010110011, field: "funct7", value: 0b0100001 };
+        assert_eq!(result, Err(expected));
+
+        // Reserved rounding mode 101
+        let result = decode(0b00100001000001000101000111000011);
+        let expected = DecodeError::ReservedRoundingMode { word: 0b00100001000001000101000111000011, rm: 0b101 };
+        assert_eq!(result, Err(expected));
+
+        // Too short inputs
+        let instr = convert_binary_string_to_vector("0000011");
+        assert_eq!(decode_instruction(instr), Err(DecodeError::TooShort { len: 7, needed: 32 }));
+        assert_eq!(decode_bytes(&[0x03, 0x02]), Err(DecodeError::TooShort { len: 2, needed: 4 }));
+
+        // Bit strings may only hold "0" and "1" entries
+        let instr = convert_binary_string_to_vector("0000000000110001000000001011001x");
+        assert_eq!(decode_instruction(instr), Err(DecodeError::InvalidBit { index: 31 }));
+        let instr = vec!["10".to_string(); 32];
+        assert_eq!(decode_instruction(instr), Err(DecodeError::InvalidBit { index: 0 }));
+    }
+
+    #[derive(Default)]
+    struct FieldCollector {
+        descriptions: Vec<&'static str>,
+        fields: Vec<Field>,
+        errors: usize,
+    }
+
+    impl DecodeObserver for FieldCollector {
+        fn instruction(&mut self, _word: u32, instruction: &Instruction) {
+            self.descriptions.push(instruction.description());
+        }
+
+        fn field(&mut self, field: Field) {
+            self.fields.push(field);
+        }
+
+        fn error(&mut self, _error: &DecodeError) {
+            self.errors += 1;
+        }
+    }
+
+    #[test]
+    fn observer_events() {
+        // Structured fields of a fused multiply addition
+        let mut collector = FieldCollector::default();
+        let result = decode_with_observer(0b00100001000001000001000111000011, &mut collector);
+        assert_eq!(result.unwrap().mnemonic(), "FMADD.S");
+        assert_eq!(collector.descriptions, vec!["Floating Point Fused Multiply-Add"]);
+        let expected = vec![
+            Field::Rd(Register::F(3)),
+            Field::Rs1(Register::F(8)),
+            Field::Rs2(Register::F(16)),
+            Field::Rs3(Register::F(4)),
+            Field::Rm(RoundingMode::Rtz),
+        ];
+        assert_eq!(collector.fields, expected);
+
+        // Errors are reported to the observer too
+        assert!(decode_with_observer(0xffffffff, &mut collector).is_err());
+        assert_eq!(collector.errors, 1);
+
+        // RV64 words are only reported as instructions under an RV64 configuration
+        assert!(decode_with_observer(0x00813183, &mut collector).is_err());
+        assert_eq!(collector.errors, 2);
+        let result = DecoderConfig::rv64().decode_with_observer(0x00813183, &mut collector);
+        assert_eq!(result, Ok(Instruction::Ld { rd: 3, rs1: 2, imm: 8 }));
+        assert_eq!(collector.descriptions.last(), Some(&"Load Double-word"));
+
+        // Teaching-style breakdown of a store
+        let mut observer = VerboseObserver::new(Vec::new());
+        decode_with_observer(0b00000000111000010010010000100011, &mut observer).unwrap();
+        let text = String::from_utf8(observer.into_inner()).unwrap();
+        let expected = "--------------------------------\n\
+                        Store Word (SW) instruction decoded\n\
+                        Register One address: x2\n\
+                        Register Two address: x14\n\
+                        Immediate value: 8\n\
+                        SW x14, 8(x2)\n\
+                        --------------------------------\n";
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn encoder_round_trip() {
+        let binary_instructions = [
+            "00000000010000000000001000000011", // LB
+            "00000000111000010000010000100011", // SB
+            "11111100111000001000011110010011", // ADDI
+            "10000111011001010100000110110111", // LUI
+            "00001000010000000000000101100111", // JALR
+            "01000000001100010000000010110011", // SUB
+            "00000010001100010111000010110011", // REMU
+            "00010000000000011010000010101111", // LR.W
+            "00011000000100011010000010101111", // SC.W
+            "00100001000001000001000111000011", // FMADD.S
+            "00001011001011000001001111000011", // FMADD.D
+            "00000110110101000011000110100111", // FSD
+        ];
+        for binary_instruction in binary_instructions {
+            let word = u32::from_str_radix(binary_instruction, 2).unwrap();
+            let decoded = decode(word).unwrap();
+            assert_eq!(encode(&decoded), Ok(word), "{}", decoded);
+        }
+
+        // Branch and jump offsets use the scrambled B/J immediate layouts
+        assert_eq!(encode(&Instruction::Beq { rs1: 1, rs2: 2, imm: -4 }), Ok(0xfe208ee3));
+        assert_eq!(encode(&Instruction::Jal { rd: 1, imm: 8 }), Ok(0x008000ef));
+        assert_eq!(encode(&Instruction::Jal { rd: 0, imm: -4 }), Ok(0xffdff06f));
+    }
+
+    #[test]
+    fn encoder_errors() {
+        // Immediate out of range
+        let result = encode(&Instruction::Addi { rd: 1, rs1: 1, imm: 2048 });
+        assert_eq!(result, Err(EncodeError::ImmediateOutOfRange { imm: 2048, min: -2048, max: 2047 }));
+
+        // Branch offsets must be even and within +-4 KiB
+        let result = encode(&Instruction::Bne { rs1: 1, rs2: 2, imm: 7 });
+        assert_eq!(result, Err(EncodeError::MisalignedImmediate { imm: 7, alignment: 2 }));
+        let result = encode(&Instruction::Bne { rs1: 1, rs2: 2, imm: 4096 });
+        assert_eq!(result, Err(EncodeError::ImmediateOutOfRange { imm: 4096, min: -4096, max: 4094 }));
+
+        // Register numbers must fit in five bits
+        let result = encode(&Instruction::Add { rd: 32, rs1: 1, rs2: 2 });
+        assert_eq!(result, Err(EncodeError::InvalidRegister { register: 32 }));
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        // Every instruction the decoder prints parses back to itself
+        let binary_instructions = [
+            "00000000010000000000001000000011", // LB
+            "00000000111000010010010000100011", // SW
+            "11111100111000001011011110010011", // SLTIU
+            "10000111011001010100000110010111", // AUIPC
+            "00000010001100010010000010110011", // MULHSU
+            "00010000000000011010000010101111", // LR.W
+            "00011000000100011010000010101111", // SC.W
+            "00100001000001000001000111001011", // FNMSUB.S
+            "00000110110101000011000110100111", // FSD
+        ];
+        for binary_instruction in binary_instructions {
+            let instr = convert_binary_string_to_vector(binary_instruction);
+            let decoded = decode_instruction(instr).unwrap();
+            let parsed: Instruction = decoded.to_string().parse().unwrap();
+            assert_eq!(parsed, decoded);
+        }
+
+        // Case-insensitive with ABI register names and hex immediates
+        let parsed: Instruction = "addi sp, sp, -0x10".parse().unwrap();
+        assert_eq!(parsed, Instruction::Addi { rd: 2, rs1: 2, imm: -16 });
+        let parsed: Instruction = "  lw a0,12( s0 )".parse().unwrap();
+        assert_eq!(parsed, Instruction::Lw { rd: 10, rs1: 8, imm: 12 });
+        let parsed: Instruction = "fadd.d fa0, fa1, ft0, rtz".parse().unwrap();
+        assert_eq!(parsed, Instruction::FaddD { rd: 10, rs1: 11, rs2: 0, rm: RoundingMode::Rtz });
+        let parsed: Instruction = "LUI x3, 0x87654".parse().unwrap();
+        assert_eq!(parsed.to_string(), "LUI x3, -493996");
+
+        // Omitted rounding mode defaults to DYN
+        let parsed: Instruction = "FSQRT.S f1, f2".parse().unwrap();
+        assert_eq!(parsed, Instruction::FsqrtS { rd: 1, rs1: 2, rm: RoundingMode::Dyn });
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |line: &str| line.parse::<Instruction>().unwrap_err();
+
+        let expected = ParseError { column: 3, kind: ParseErrorKind::UnknownMnemonic("FOO".to_string()) };
+        assert_eq!(error("  FOO x1"), expected);
+
+        let expected = ParseError { column: 13, kind: ParseErrorKind::ExpectedIntRegister };
+        assert_eq!(error("ADD x1, x2, f3"), expected);
+
+        let expected = ParseError { column: 9, kind: ParseErrorKind::ExpectedMemory };
+        assert_eq!(error("LW x10, x8"), expected);
+
+        let expected = ParseError { column: 12, kind: ParseErrorKind::ExpectedIntRegister };
+        assert_eq!(error("LW x10, 12(f8)"), expected);
+
+        let expected = ParseError { column: 11, kind: ParseErrorKind::MissingOperand };
+        assert_eq!(error("ADD x1, x2"), expected);
+
+        let expected = ParseError { column: 14, kind: ParseErrorKind::UnexpectedOperand };
+        assert_eq!(error("LR.W x1, x2, x3"), expected);
+
+        let expected = ParseError { column: 9, kind: ParseErrorKind::InvalidOperand("x32".to_string()) };
+        assert_eq!(error("ADD x1, x32, x2"), expected);
+
+        let kind = ParseErrorKind::Encode(EncodeError::ImmediateOutOfRange { imm: 4096, min: -2048, max: 2047 });
+        assert_eq!(error("ADDI x1, x2, 4096"), ParseError { column: 14, kind });
+
+        let kind = ParseErrorKind::Encode(EncodeError::MisalignedImmediate { imm: 3, alignment: 2 });
+        assert_eq!(error("BEQ x1, x2, 3"), ParseError { column: 13, kind });
+
+        // Non-ASCII operands are rejected, with columns counted in characters
+        let expected = ParseError { column: 14, kind: ParseErrorKind::InvalidOperand("0é".to_string()) };
+        assert_eq!(error("ADDI x1, x2, 0é"), expected);
+        let expected = ParseError { column: 6, kind: ParseErrorKind::InvalidOperand("é1".to_string()) };
+        assert_eq!(error("ADDI é1, x2, 3"), expected);
+        let expected = ParseError { column: 14, kind: ParseErrorKind::InvalidOperand("ü".to_string()) };
+        assert_eq!(error("ADDI x1, x2, ü"), expected);
+        let expected = ParseError { column: 11, kind: ParseErrorKind::ExpectedIntRegister };
+        assert_eq!(error("ADDI x1, (é)"), expected);
+        let expected = ParseError { column: 11, kind: ParseErrorKind::ExpectedIntRegister };
+        assert_eq!(error("LW x1, 0(\u{3000}é)"), expected);
+    }
+
+    #[test]
+    fn immediate_formats() {
+        let cases = [
+            // I-type minimum and maximum
+            (0x80000093, Instruction::Addi { rd: 1, rs1: 0, imm: -2048 }),
+            (0x7ff00093, Instruction::Addi { rd: 1, rs1: 0, imm: 2047 }),
+            (0x800080e7, Instruction::Jalr { rd: 1, rs1: 1, imm: -2048 }),
+            // S-type minimum and maximum
+            (0x8020a023, Instruction::Sw { rs1: 1, rs2: 2, imm: -2048 }),
+            (0x7e20afa3, Instruction::Sw { rs1: 1, rs2: 2, imm: 2047 }),
+            // B-type minimum and maximum
+            (0x80000063, Instruction::Beq { rs1: 0, rs2: 0, imm: -4096 }),
+            (0x7e001fe3, Instruction::Bne { rs1: 0, rs2: 0, imm: 4094 }),
+            // U-type minimum and maximum field values
+            (0x800000b7, Instruction::Lui { rd: 1, imm: -524288 }),
+            (0x7ffff0b7, Instruction::Lui { rd: 1, imm: 524287 }),
+            (0xfffff097, Instruction::Auipc { rd: 1, imm: -1 }),
+            // J-type minimum and maximum
+            (0x8000006f, Instruction::Jal { rd: 0, imm: -1048576 }),
+            (0x7ffff0ef, Instruction::Jal { rd: 1, imm: 1048574 }),
+            (0xffdff06f, Instruction::Jal { rd: 0, imm: -4 }),
+            // Floating point loads and stores use the I and S formats
+            (0xffc12087, Instruction::Flw { rd: 1, rs1: 2, imm: -4 }),
+            (0x80013007, Instruction::Fld { rd: 0, rs1: 2, imm: -2048 }),
+            (0xfe313c27, Instruction::Fsd { rs1: 2, rs2: 3, imm: -8 }),
+            (0x7e312fa7, Instruction::Fsw { rs1: 2, rs2: 3, imm: 2047 }),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(decode(word), Ok(expected), "{:#010x}", word);
+            assert_eq!(encode(&expected), Ok(word), "{}", expected);
+        }
+    }
+
+    #[test]
+    fn shift_fence_system_instructions() {
+        let cases = [
+            // Shift immediates carry a 5-bit shamt, SRAI sets funct7 0100000
+            (0x00311093, Instruction::Slli { rd: 1, rs1: 2, shamt: 3 }, "SLLI x1, x2, 3"),
+            (0x01f15093, Instruction::Srli { rd: 1, rs1: 2, shamt: 31 }, "SRLI x1, x2, 31"),
+            (0x40315093, Instruction::Srai { rd: 1, rs1: 2, shamt: 3 }, "SRAI x1, x2, 3"),
+            // Memory ordering
+            (0x0ff0000f, Instruction::Fence { pred: 0b1111, succ: 0b1111 }, "FENCE iorw, iorw"),
+            (0x0230000f, Instruction::Fence { pred: 0b0010, succ: 0b0011 }, "FENCE r, rw"),
+            (0x8330000f, Instruction::FenceTso, "FENCE.TSO"),
+            (0x0100000f, Instruction::Pause, "PAUSE"),
+            (0x0000100f, Instruction::FenceI, "FENCE.I"),
+            // Environment calls
+            (0x00000073, Instruction::Ecall, "ECALL"),
+            (0x00100073, Instruction::Ebreak, "EBREAK"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        // A bare FENCE orders all accesses
+        let result = "fence".parse::<Instruction>();
+        assert_eq!(result, Ok(Instruction::Fence { pred: 0b1111, succ: 0b1111 }));
+
+        // SLLI with funct6 000001 is reserved
+        let result = decode(0x04311093);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x04311093, field: "funct6", value: 0b000001 }));
+
+        // A shamt above 31 is an RV64-only encoding
+        let result = decode(0x02311093);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x02311093, xlen: Xlen::Rv32 }));
+
+        // FENCE.TSO only exists with pred = succ = rw
+        let result = decode(0x8ff0000f);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x8ff0000f, field: "fm", value: 0b1000 }));
+
+        // ECALL and EBREAK require rs1 and rd to be zero
+        let result = decode(0x000000f3);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x000000f3, field: "rd", value: 1 }));
+        let result = decode(0x00200073);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x00200073, field: "funct12", value: 2 }));
+
+        // Shift amounts above 31 do not fit RV32I
+        let kind = ParseErrorKind::Encode(EncodeError::ImmediateOutOfRange { imm: 32, min: 0, max: 31 });
+        assert_eq!("SLLI x1, x2, 32".parse::<Instruction>(), Err(ParseError { column: 14, kind }));
+        let result = encode(&Instruction::Slli { rd: 1, rs1: 2, shamt: 40 });
+        assert_eq!(result, Err(EncodeError::ImmediateOutOfRange { imm: 40, min: 0, max: 31 }));
+
+        // Nor do shift amounts above 63 fit RV64I
+        let kind = ParseErrorKind::Encode(EncodeError::ImmediateOutOfRange { imm: 64, min: 0, max: 63 });
+        assert_eq!(DecoderConfig::rv64().parse("SLLI x1, x2, 64"), Err(ParseError { column: 14, kind }));
+    }
+
+    #[test]
+    fn rv64i_instructions() {
+        let rv64 = DecoderConfig::rv64();
+        let cases = [
+            // Loads and stores
+            (0x00813183, Instruction::Ld { rd: 3, rs1: 2, imm: 8 }, "LD x3, 8(x2)"),
+            (0xffc36283, Instruction::Lwu { rd: 5, rs1: 6, imm: -4 }, "LWU x5, -4(x6)"),
+            (0x00813823, Instruction::Sd { rs1: 2, rs2: 8, imm: 16 }, "SD x8, 16(x2)"),
+            // Shift immediates use a 6-bit shamt under RV64
+            (0x03f11093, Instruction::Slli { rd: 1, rs1: 2, shamt: 63 }, "SLLI x1, x2, 63"),
+            (0x42015093, Instruction::Srai { rd: 1, rs1: 2, shamt: 32 }, "SRAI x1, x2, 32"),
+            // Word operations
+            (0xfff5051b, Instruction::Addiw { rd: 10, rs1: 10, imm: -1 }, "ADDIW x10, x10, -1"),
+            (0x01f1109b, Instruction::Slliw { rd: 1, rs1: 2, shamt: 31 }, "SLLIW x1, x2, 31"),
+            (0x0071509b, Instruction::Srliw { rd: 1, rs1: 2, shamt: 7 }, "SRLIW x1, x2, 7"),
+            (0x4071509b, Instruction::Sraiw { rd: 1, rs1: 2, shamt: 7 }, "SRAIW x1, x2, 7"),
+            (0x005201bb, Instruction::Addw { rd: 3, rs1: 4, rs2: 5 }, "ADDW x3, x4, x5"),
+            (0x405201bb, Instruction::Subw { rd: 3, rs1: 4, rs2: 5 }, "SUBW x3, x4, x5"),
+            (0x005211bb, Instruction::Sllw { rd: 3, rs1: 4, rs2: 5 }, "SLLW x3, x4, x5"),
+            (0x005251bb, Instruction::Srlw { rd: 3, rs1: 4, rs2: 5 }, "SRLW x3, x4, x5"),
+            (0x405251bb, Instruction::Sraw { rd: 3, rs1: 4, rs2: 5 }, "SRAW x3, x4, x5"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&rv64, word, expected, text);
+
+            // None of these are valid RV32 encodings
+            let result = decode(word);
+            assert_eq!(result, Err(DecodeError::UnsupportedXlen { word, xlen: Xlen::Rv32 }), "{}", text);
+        }
+
+        // The encoder and parser reject them under RV32 too
+        assert_eq!(encode(&Instruction::Ld { rd: 3, rs1: 2, imm: 8 }), Err(EncodeError::UnsupportedXlen { xlen: Xlen::Rv32 }));
+        let kind = ParseErrorKind::Encode(EncodeError::UnsupportedXlen { xlen: Xlen::Rv32 });
+        assert_eq!("ADDIW x10, x10, -1".parse::<Instruction>(), Err(ParseError { column: 1, kind }));
+
+        // RV32 instructions decode the same under RV64
+        let result = rv64.decode_bytes(&[0x03, 0x02, 0x40, 0x00]);
+        assert_eq!(result, Ok(Instruction::Lb { rd: 4, rs1: 0, imm: 4 }));
+
+        // SLLIW keeps a 5-bit shamt, bit 25 set is reserved
+        let result = rv64.decode(0x0201109b);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x0201109b, field: "funct7", value: 0b0000001 }));
+
+        // SLLIW shift amounts above 31 do not fit
+        let kind = ParseErrorKind::Encode(EncodeError::ImmediateOutOfRange { imm: 32, min: 0, max: 31 });
+        assert_eq!(rv64.parse("SLLIW x1, x2, 32"), Err(ParseError { column: 15, kind }));
+    }
+
+    #[test]
+    fn compressed_instructions() {
+        // Compressed add immediate, as a 16-bit binary instruction
+        let binary_instruction = "0000000010010001";
+        let instr = convert_binary_string_to_vector(binary_instruction);
+        let expected = "C.ADDI x1, 4 (ADDI x1, x1, 4)";
+        let result = instruction_decoder(instr);
+        assert_eq!(result, expected);
+
+        let cases = [
+            // Quadrant 0
+            (0x0800, "C.ADDI4SPN x8, x2, 16 (ADDI x8, x2, 16)"),
+            (0x40c8, "C.LW x10, 4(x9) (LW x10, 4(x9))"),
+            (0x6488, "C.FLW f10, 8(x9) (FLW f10, 8(x9))"),
+            (0x2b8c, "C.FLD f11, 16(x15) (FLD f11, 16(x15))"),
+            (0xde6c, "C.SW x11, 124(x12) (SW x11, 124(x12))"),
+            (0xe048, "C.FSW f10, 4(x8) (FSW f10, 4(x8))"),
+            (0xbd64, "C.FSD f9, 248(x10) (FSD f9, 248(x10))"),
+            // Quadrant 1
+            (0x0001, "C.NOP (ADDI x0, x0, 0)"),
+            (0x1141, "C.ADDI x2, -16 (ADDI x2, x2, -16)"),
+            (0x2ffd, "C.JAL 2046 (JAL x1, 2046)"),
+            (0x5501, "C.LI x10, -32 (ADDI x10, x0, -32)"),
+            (0x7139, "C.ADDI16SP x2, -64 (ADDI x2, x2, -64)"),
+            (0x6785, "C.LUI x15, 1 (LUI x15, 1)"),
+            (0x72fd, "C.LUI x5, -1 (LUI x5, -1)"),
+            (0x810d, "C.SRLI x10, 3 (SRLI x10, x10, 3)"),
+            (0x85fd, "C.SRAI x11, 31 (SRAI x11, x11, 31)"),
+            (0x9a7d, "C.ANDI x12, -1 (ANDI x12, x12, -1)"),
+            (0x8c05, "C.SUB x8, x9 (SUB x8, x8, x9)"),
+            (0x8eb9, "C.XOR x13, x14 (XOR x13, x13, x14)"),
+            (0x8fc9, "C.OR x15, x10 (OR x15, x15, x10)"),
+            (0x8ce1, "C.AND x9, x8 (AND x9, x9, x8)"),
+            (0xb001, "C.J -2048 (JAL x0, -2048)"),
+            (0xd101, "C.BEQZ x10, -256 (BEQ x10, x0, -256)"),
+            (0xecfd, "C.BNEZ x9, 254 (BNE x9, x0, 254)"),
+            // Quadrant 2
+            (0x0086, "C.SLLI x1, 1 (SLLI x1, x1, 1)"),
+            (0x307e, "C.FLDSP f0, 504(x2) (FLD f0, 504(x2))"),
+            (0x40b2, "C.LWSP x1, 12(x2) (LW x1, 12(x2))"),
+            (0x757e, "C.FLWSP f10, 252(x2) (FLW f10, 252(x2))"),
+            (0x8082, "C.JR x1 (JALR x0, x1, 0)"),
+            (0x852e, "C.MV x10, x11 (ADD x10, x0, x11)"),
+            (0x9002, "C.EBREAK (EBREAK)"),
+            (0x9282, "C.JALR x5 (JALR x1, x5, 0)"),
+            (0x952e, "C.ADD x10, x11 (ADD x10, x10, x11)"),
+            (0xa422, "C.FSDSP f8, 8(x2) (FSD f8, 8(x2))"),
+            (0xdf86, "C.SWSP x1, 252(x2) (SW x1, 252(x2))"),
+            (0xe02e, "C.FSWSP f11, 0(x2) (FSW f11, 0(x2))"),
+        ];
+        for (parcel, expected) in cases {
+            let decoded = decode_compressed(parcel).unwrap();
+            assert_eq!(decoded.to_string(), expected, "{:#06x}", parcel);
+            assert_eq!(decoded.parcel(), parcel);
+            assert!(!decoded.is_hint(), "{}", expected);
+        }
+    }
+
+    #[test]
+    fn compressed_rv64_instructions() {
+        let rv64 = DecoderConfig::rv64();
+        let cases = [
+            (0x7ce8, "C.LD x10, 248(x9) (LD x10, 248(x9))"),
+            (0xe780, "C.SD x8, 8(x15) (SD x8, 8(x15))"),
+            (0x357d, "C.ADDIW x10, -1 (ADDIW x10, x10, -1)"),
+            (0x9c05, "C.SUBW x8, x9 (SUBW x8, x8, x9)"),
+            (0x9eb9, "C.ADDW x13, x14 (ADDW x13, x13, x14)"),
+            (0x10fe, "C.SLLI x1, 63 (SLLI x1, x1, 63)"),
+            (0x9101, "C.SRLI x10, 32 (SRLI x10, x10, 32)"),
+            (0x70fe, "C.LDSP x1, 504(x2) (LD x1, 504(x2))"),
+            (0xe422, "C.SDSP x8, 8(x2) (SD x8, 8(x2))"),
+        ];
+        for (parcel, expected) in cases {
+            let result = rv64.decode_compressed(parcel).unwrap().to_string();
+            assert_eq!(result, expected, "{:#06x}", parcel);
+        }
+
+        // The same quadrant 0 slot holds C.FLW on RV32
+        let result = decode_compressed(0x7ce8).unwrap().to_string();
+        assert_eq!(result, "C.FLW f10, 124(x9) (FLW f10, 124(x9))");
+
+        // RV64-only encodings are rejected on RV32
+        for parcel in [0x9c05, 0x9eb9, 0x10fe, 0x9101] {
+            let result = decode_compressed(parcel);
+            assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: parcel as u32, xlen: Xlen::Rv32 }));
+        }
+
+        // C.ADDIW with rd = x0 is reserved
+        let result = rv64.decode_compressed(0x2005);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x2005, field: "rd", value: 0 }));
+    }
+
+    #[test]
+    fn compressed_hints_and_reserved() {
+        let hints = [
+            (0x0015, "C.NOP 5 (hint: ADDI x0, x0, 5)"),
+            (0x0081, "C.ADDI x1, 0 (hint: ADDI x1, x1, 0)"),
+            (0x4015, "C.LI x0, 5 (hint: ADDI x0, x0, 5)"),
+            (0x802e, "C.MV x0, x11 (hint: ADD x0, x0, x11)"),
+            (0x0082, "C.SLLI x1, 0 (hint: SLLI x1, x1, 0)"),
+        ];
+        for (parcel, expected) in hints {
+            let decoded = decode_compressed(parcel).unwrap();
+            assert!(decoded.is_hint(), "{}", expected);
+            assert_eq!(decoded.to_string(), expected);
+        }
+
+        let reserved = [
+            // The all-zero parcel is defined illegal
+            (0x0000, "nzuimm", 0),
+            (0x4002, "rd", 0),        // C.LWSP x0
+            (0x8002, "rs1", 0),       // C.JR x0
+            (0x6101, "nzimm", 0),     // C.ADDI16SP 0
+            (0x6281, "nzimm", 0),     // C.LUI x5, 0
+            (0x8000, "funct3", 0b100),
+            (0x9c41, "funct6", 0b100111),
+            // Not a compressed instruction
+            (0x0003, "op", 0b11),
+        ];
+        for (parcel, field, value) in reserved {
+            let result = decode_compressed(parcel);
+            assert_eq!(result, Err(DecodeError::ReservedFunct { word: parcel as u32, field, value }), "{:#06x}", parcel);
+        }
+    }
+
+    #[test]
+    fn compressed_expansion() {
+        let cases = [
+            // C.ADDI x1, 4
+            (0x0091, 0x00408093),
+            // C.J -2048
+            (0xb001, 0x801ff06f),
+            // C.SW x11, 124(x12)
+            (0xde6c, 0x06b62e23),
+            // C.ADDI16SP x2, -64
+            (0x7139, 0xfc010113),
+            // C.LUI x5, -1
+            (0x72fd, 0xfffff2b7),
+            // C.BNEZ x9, 254
+            (0xecfd, 0x0e049f63),
+            // C.JALR x5
+            (0x9282, 0x000280e7),
+        ];
+        for (parcel, expected) in cases {
+            let result = expand_compressed(parcel);
+            assert_eq!(result, Ok(expected), "{:#06x}", parcel);
+        }
+
+        // Every valid parcel expands to a word the 32-bit decoder reads back
+        for xlen in [Xlen::Rv32, Xlen::Rv64] {
+            let config = DecoderConfig { xlen };
+            for parcel in 0..=u16::MAX {
+                if let Ok(compressed) = config.decode_compressed(parcel) {
+                    let word = config.expand_compressed(parcel).unwrap();
+                    assert_eq!(config.decode(word), Ok(compressed.expansion()), "{:#06x}", parcel);
+                }
+            }
+        }
+
+        // Reserved encodings have no expansion
+        let result = expand_compressed(0x0000);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x0000, field: "nzuimm", value: 0 }));
+        let result = expand_compressed(0x9c05);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x9c05, xlen: Xlen::Rv32 }));
+    }
+
+    #[test]
+    fn compression_finder() {
+        let rv64 = DecoderConfig::rv64();
+        let cases = [
+            (Instruction::Addi { rd: 2, rs1: 2, imm: -16 }, 0x1141, "C.ADDI"),
+            (Instruction::Addi { rd: 2, rs1: 2, imm: -64 }, 0x7139, "C.ADDI16SP"),
+            (Instruction::Lw { rd: 8, rs1: 9, imm: 4 }, 0x40c0, "C.LW"),
+            (Instruction::Addi { rd: 10, rs1: 2, imm: 8 }, 0x0028, "C.ADDI4SPN"),
+            (Instruction::Add { rd: 5, rs1: 0, rs2: 6 }, 0x829a, "C.MV"),
+            (Instruction::Jal { rd: 0, imm: 100 }, 0xa095, "C.J"),
+            (Instruction::Beq { rs1: 9, rs2: 0, imm: -8 }, 0xdce5, "C.BEQZ"),
+            (Instruction::Ld { rd: 9, rs1: 2, imm: 16 }, 0x64c2, "C.LDSP"),
+            (Instruction::Sd { rs1: 2, rs2: 10, imm: 248 }, 0xfdaa, "C.SDSP"),
+            (Instruction::Fld { rd: 9, rs1: 10, imm: 8 }, 0x2504, "C.FLD"),
+            (Instruction::Srai { rd: 9, rs1: 9, shamt: 3 }, 0x848d, "C.SRAI"),
+            (Instruction::Addiw { rd: 5, rs1: 5, imm: -1 }, 0x32fd, "C.ADDIW"),
+            (Instruction::Lui { rd: 5, imm: -1 }, 0x72fd, "C.LUI"),
+        ];
+        for (instr, parcel, mnemonic) in cases {
+            let compressed = rv64.compress(&instr, &Extensions::all()).unwrap();
+            assert_eq!((compressed.parcel(), compressed.mnemonic()), (parcel, mnemonic), "{}", instr);
+        }
+
+        // Operands the compressed fields cannot hold
+        let extensions = Extensions::default();
+        assert!(compress(&Instruction::Lw { rd: 20, rs1: 9, imm: 4 }, &extensions).is_none());
+        assert!(compress(&Instruction::Lw { rd: 8, rs1: 9, imm: 6 }, &extensions).is_none());
+        assert!(compress(&Instruction::Lw { rd: 8, rs1: 9, imm: 128 }, &extensions).is_none());
+        assert!(compress(&Instruction::Addi { rd: 5, rs1: 6, imm: 1 }, &extensions).is_none());
+        assert!(compress(&Instruction::Beq { rs1: 9, rs2: 1, imm: 8 }, &extensions).is_none());
+        assert!(compress(&Instruction::Jal { rd: 1, imm: 2048 }, &extensions).is_none());
+
+        // HINT encodings are not offered
+        assert!(compress(&Instruction::Addi { rd: 5, rs1: 5, imm: 0 }, &extensions).is_none());
+        assert!(compress(&Instruction::Addi { rd: 0, rs1: 0, imm: 0 }, &extensions).is_some());
+
+        // XLEN and the F/D extensions select the available forms
+        let jal = Instruction::Jal { rd: 1, imm: -4 };
+        assert_eq!(compress(&jal, &extensions).map(|c| c.mnemonic()), Some("C.JAL"));
+        assert!(rv64.compress(&jal, &extensions).is_none());
+        let flw = Instruction::Flw { rd: 8, rs1: 2, imm: 12 };
+        assert!(compress(&flw, &extensions).is_none());
+        assert_eq!(compress(&flw, &Extensions { f: true, d: false }).map(|c| c.mnemonic()), Some("C.FLWSP"));
+        assert!(rv64.compress(&flw, &Extensions::all()).is_none());
+        assert!(compress(&Instruction::Ld { rd: 8, rs1: 9, imm: 8 }, &extensions).is_none());
+
+        // Every non-HINT compressed instruction is found again from its expansion
+        for xlen in [Xlen::Rv32, Xlen::Rv64] {
+            let config = DecoderConfig { xlen };
+            for parcel in 0..=u16::MAX {
+                if let Ok(decoded) = config.decode_compressed(parcel) {
+                    if decoded.is_hint() {
+                        continue;
+                    }
+                    let found = config.compress(&decoded.expansion(), &Extensions::all());
+                    assert_eq!(found.map(|c| c.expansion()), Some(decoded.expansion()), "{:#06x}", parcel);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn compression_summary_over_buffer() {
+        let mut bytes = Vec::new();
+        for word in [0xff010113u32, 0x0044a403, 0x00a58533, 0x00000013] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes.extend_from_slice(&0x8082u16.to_le_bytes());
+        bytes.extend_from_slice(&0xfc2ca623u32.to_le_bytes());
+
+        // ADDI x2, x2, -16, LW x8, 4(x9) and NOP compress, ADD x10, x11, x10 and SW x2, -52(x25) do not
+        let summary = compression_summary(&bytes, &Extensions::default()).unwrap();
+        assert_eq!(summary, CompressionSummary { instructions: 5, compressed: 1, compressible: 3 });
+        assert_eq!(summary.bytes_saved(), 6);
+
+        let result = compression_summary(&bytes[..bytes.len() - 2], &Extensions::default());
+        assert_eq!(result, Err(DecodeError::TooShort { len: 2, needed: 4 }));
+        let result = compression_summary(&[0x00, 0x00], &Extensions::default());
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x0000, field: "nzuimm", value: 0 }));
+    }
+
+    #[test]
+    fn csr_instructions() {
+        let cases = [
+            (0x301110f3, Instruction::Csrrw { rd: 1, rs1: 2, csr: 0x301 }, "CSRRW x1, misa, x2"),
+            (0x7c0022f3, Instruction::Csrrs { rd: 5, rs1: 0, csr: 0x7c0 }, "CSRRS x5, 0x7c0, x0"),
+            (0x001fb073, Instruction::Csrrc { rd: 0, rs1: 31, csr: 0x001 }, "CSRRC x0, fflags, x31"),
+            (0x180fd1f3, Instruction::Csrrwi { rd: 3, uimm: 31, csr: 0x180 }, "CSRRWI x3, satp, 31"),
+            (0xc1f0e273, Instruction::Csrrsi { rd: 4, uimm: 1, csr: 0xc1f }, "CSRRSI x4, hpmcounter31, 1"),
+            (0xb8307373, Instruction::Csrrci { rd: 6, uimm: 0, csr: 0xb83 }, "CSRRCI x6, mhpmcounter3h, 0"),
+            (0xc00023f3, Instruction::Csrrs { rd: 7, rs1: 0, csr: 0xc00 }, "CSRRS x7, cycle, x0"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        // Name database, both directions
+        assert_eq!(csr_name(0x305), Some("mtvec"));
+        assert_eq!(csr_name(0x002), Some("frm"));
+        assert_eq!(csr_name(0x003), Some("fcsr"));
+        assert_eq!(csr_name(0xc83), Some("hpmcounter3h"));
+        assert_eq!(csr_name(0x3ef), Some("pmpaddr63"));
+        assert_eq!(csr_name(0xfff), None);
+        assert_eq!(csr_number("mstatus"), Some(0x300));
+        assert_eq!(csr_number("HPMCOUNTER3"), Some(0xc03));
+        assert_eq!(csr_number("hpmcounter32"), None);
+        for csr in 0..=0xfff {
+            if let Some(name) = csr_name(csr) {
+                assert_eq!(csr_number(name), Some(csr), "{}", name);
+            }
+        }
+
+        // CSRs parse by name or number, but not as registers
+        let parsed: Instruction = "csrrw ra, 0x300, sp".parse().unwrap();
+        assert_eq!(parsed.to_string(), "CSRRW x1, mstatus, x2");
+        let result = "CSRRW x1, x2, x3".parse::<Instruction>();
+        assert_eq!(result, Err(ParseError { column: 11, kind: ParseErrorKind::ExpectedCsr }));
+        let result = "CSRRW x1, 0x1000, x3".parse::<Instruction>();
+        let err = EncodeError::ImmediateOutOfRange { imm: 0x1000, min: 0, max: 0xfff };
+        assert_eq!(result, Err(ParseError { column: 11, kind: ParseErrorKind::Encode(err) }));
+        let result = "CSRRWI x1, mstatus, 32".parse::<Instruction>();
+        let err = EncodeError::ImmediateOutOfRange { imm: 32, min: 0, max: 31 };
+        assert_eq!(result, Err(ParseError { column: 21, kind: ParseErrorKind::Encode(err) }));
+
+        // funct3 100 is not a CSR instruction
+        let result = decode(0x30014073);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x30014073, field: "funct3", value: 0b100 }));
+
+        // The observer names the CSR
+        let mut observer = VerboseObserver::new(Vec::new());
+        decode_with_observer(0x301110f3, &mut observer).unwrap();
+        let text = String::from_utf8(observer.into_inner()).unwrap();
+        assert!(text.contains("Atomic Read/Write CSR (CSRRW) instruction decoded"));
+        assert!(text.contains("CSR: misa (0x301)"));
+    }
+
+    #[test]
+    fn privileged_instructions() {
+        let cases = [
+            (0x10200073, Instruction::Sret, "SRET"),
+            (0x30200073, Instruction::Mret, "MRET"),
+            (0x70200073, Instruction::Mnret, "MNRET"),
+            (0x10500073, Instruction::Wfi, "WFI"),
+            // Virtual address in rs1, ASID in rs2
+            (0x12208073, Instruction::SfenceVma { rs1: 1, rs2: 2 }, "SFENCE.VMA x1, x2"),
+            (0x12000073, Instruction::SfenceVma { rs1: 0, rs2: 0 }, "SFENCE.VMA x0, x0"),
+            (0x16418073, Instruction::SinvalVma { rs1: 3, rs2: 4 }, "SINVAL.VMA x3, x4"),
+            (0x18000073, Instruction::SfenceWInval, "SFENCE.W.INVAL"),
+            (0x18100073, Instruction::SfenceInvalIr, "SFENCE.INVAL.IR"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        // A bare SFENCE.VMA fences every address space
+        let result = "sfence.vma".parse::<Instruction>();
+        assert_eq!(result, Ok(Instruction::SfenceVma { rs1: 0, rs2: 0 }));
+        let fields = Instruction::SfenceVma { rs1: 10, rs2: 11 }.fields();
+        assert_eq!(fields, vec![Field::Rs1(Register::X(10)), Field::Rs2(Register::X(11))]);
+
+        // Trap returns have no register operands
+        let result = decode(0x30208073);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x30208073, field: "rs1", value: 1 }));
+        let result = decode(0x120000f3);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x120000f3, field: "rd", value: 1 }));
+        let result = decode(0x18200073);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x18200073, field: "funct12", value: 0x182 }));
+    }
+
+    #[test]
+    fn debug_instructions() {
+        // Debug mode return
+        assert_eq!(decode(0x7b200073), Ok(Instruction::Dret));
+        assert_eq!(Instruction::Dret.to_string(), "DRET");
+        assert_eq!(encode(&Instruction::Dret), Ok(0x7b200073));
+        assert_eq!("dret".parse::<Instruction>(), Ok(Instruction::Dret));
+
+        let cases = [
+            (
+                0x00221005,
+                AbstractCommand::AccessRegister { aarsize: 2, aarpostincrement: false, postexec: false, transfer: true, write: false, regno: 0x1005 },
+                "Access Register: cmdtype=0, aarsize=2 (32-bit), aarpostincrement=0, postexec=0, transfer=1, write=0, regno=0x1005 (x5)",
+            ),
+            (
+                0x003f1021,
+                AbstractCommand::AccessRegister { aarsize: 3, aarpostincrement: true, postexec: true, transfer: true, write: true, regno: 0x1021 },
+                "Access Register: cmdtype=0, aarsize=3 (64-bit), aarpostincrement=1, postexec=1, transfer=1, write=1, regno=0x1021 (f1)",
+            ),
+            (
+                0x00230300,
+                AbstractCommand::AccessRegister { aarsize: 2, aarpostincrement: false, postexec: false, transfer: true, write: true, regno: 0x300 },
+                "Access Register: cmdtype=0, aarsize=2 (32-bit), aarpostincrement=0, postexec=0, transfer=1, write=1, regno=0x300 (mstatus)",
+            ),
+            (
+                0x0044c000,
+                AbstractCommand::AccessRegister { aarsize: 4, aarpostincrement: false, postexec: true, transfer: false, write: false, regno: 0xc000 },
+                "Access Register: cmdtype=0, aarsize=4 (128-bit), aarpostincrement=0, postexec=1, transfer=0, write=0, regno=0xc000 (0xc000)",
+            ),
+            (0x01000000, AbstractCommand::QuickAccess, "Quick Access: cmdtype=1"),
+            (
+                0x02a90000,
+                AbstractCommand::AccessMemory { aamvirtual: true, aamsize: 2, aampostincrement: true, write: true, target_specific: 0 },
+                "Access Memory: cmdtype=2, aamvirtual=1, aamsize=2 (32-bit), aampostincrement=1, write=1, target-specific=0",
+            ),
+            (
+                0x0200c000,
+                AbstractCommand::AccessMemory { aamvirtual: false, aamsize: 0, aampostincrement: false, write: false, target_specific: 3 },
+                "Access Memory: cmdtype=2, aamvirtual=0, aamsize=0 (8-bit), aampostincrement=0, write=0, target-specific=3",
+            ),
+        ];
+        for (word, expected, text) in cases {
+            assert_eq!(decode_abstract_command(word), Ok(expected), "{:#010x}", word);
+            assert_eq!(expected.to_string(), text);
+            let command = convert_binary_string_to_vector(&format!("{:032b}", word));
+            assert_eq!(abstract_command_decoder(command), Ok(text.to_string()));
+        }
+
+        // Unknown command types, reserved sizes and reserved control bits
+        let errors = [
+            (0x03000000, "cmdtype", 3),
+            (0x00121005, "aarsize", 1),
+            (0x00721005, "aarsize", 7),
+            (0x00a21005, "control", 0xa21005),
+            (0x01000001, "control", 1),
+            (0x02020000, "control", 0x020000),
+            (0x02200001, "control", 0x200001),
+            (0x02500000, "aamsize", 5),
+        ];
+        for (word, field, value) in errors {
+            assert_eq!(decode_abstract_command(word), Err(DecodeError::ReservedFunct { word, field, value }), "{:#010x}", word);
+        }
+
+        // The string form reports errors instead of panicking
+        let command = convert_binary_string_to_vector(&format!("{:032b}", 0x03000000));
+        let result = abstract_command_decoder(command);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x03000000, field: "cmdtype", value: 3 }));
+        let command = convert_binary_string_to_vector("0000001");
+        assert_eq!(abstract_command_decoder(command), Err(DecodeError::TooShort { len: 7, needed: 32 }));
+    }
+
+    #[test]
+    fn bit_manipulation_instructions() {
+        let cases = [
+            // Zba address generation
+            (0x203120b3, Instruction::Sh1add { rd: 1, rs1: 2, rs2: 3 }, "SH1ADD x1, x2, x3"),
+            (0x2062c233, Instruction::Sh2add { rd: 4, rs1: 5, rs2: 6 }, "SH2ADD x4, x5, x6"),
+            (0x209463b3, Instruction::Sh3add { rd: 7, rs1: 8, rs2: 9 }, "SH3ADD x7, x8, x9"),
+            // Zbb logical with negate, counts, min/max and extension
+            (0x40c5f533, Instruction::Andn { rd: 10, rs1: 11, rs2: 12 }, "ANDN x10, x11, x12"),
+            (0x40f766b3, Instruction::Orn { rd: 13, rs1: 14, rs2: 15 }, "ORN x13, x14, x15"),
+            (0x4128c833, Instruction::Xnor { rd: 16, rs1: 17, rs2: 18 }, "XNOR x16, x17, x18"),
+            (0x60011093, Instruction::Clz { rd: 1, rs1: 2 }, "CLZ x1, x2"),
+            (0x60121193, Instruction::Ctz { rd: 3, rs1: 4 }, "CTZ x3, x4"),
+            (0x60231293, Instruction::Cpop { rd: 5, rs1: 6 }, "CPOP x5, x6"),
+            (0x0a3160b3, Instruction::Max { rd: 1, rs1: 2, rs2: 3 }, "MAX x1, x2, x3"),
+            (0x0a62f233, Instruction::Maxu { rd: 4, rs1: 5, rs2: 6 }, "MAXU x4, x5, x6"),
+            (0x0a9443b3, Instruction::Min { rd: 7, rs1: 8, rs2: 9 }, "MIN x7, x8, x9"),
+            (0x0ac5d533, Instruction::Minu { rd: 10, rs1: 11, rs2: 12 }, "MINU x10, x11, x12"),
+            (0x60411093, Instruction::SextB { rd: 1, rs1: 2 }, "SEXT.B x1, x2"),
+            (0x60521193, Instruction::SextH { rd: 3, rs1: 4 }, "SEXT.H x3, x4"),
+            (0x080342b3, Instruction::ZextH { rd: 5, rs1: 6 }, "ZEXT.H x5, x6"),
+            // Zbb rotates and byte operations
+            (0x603110b3, Instruction::Rol { rd: 1, rs1: 2, rs2: 3 }, "ROL x1, x2, x3"),
+            (0x6062d233, Instruction::Ror { rd: 4, rs1: 5, rs2: 6 }, "ROR x4, x5, x6"),
+            (0x61f45393, Instruction::Rori { rd: 7, rs1: 8, shamt: 31 }, "RORI x7, x8, 31"),
+            (0x28755493, Instruction::OrcB { rd: 9, rs1: 10 }, "ORC.B x9, x10"),
+            (0x69865593, Instruction::Rev8 { rd: 11, rs1: 12 }, "REV8 x11, x12"),
+            // Zbc carry-less multiplication
+            (0x0a3110b3, Instruction::Clmul { rd: 1, rs1: 2, rs2: 3 }, "CLMUL x1, x2, x3"),
+            (0x0a62b233, Instruction::Clmulh { rd: 4, rs1: 5, rs2: 6 }, "CLMULH x4, x5, x6"),
+            (0x0a9423b3, Instruction::Clmulr { rd: 7, rs1: 8, rs2: 9 }, "CLMULR x7, x8, x9"),
+            // Zbs single-bit operations
+            (0x483110b3, Instruction::Bclr { rd: 1, rs1: 2, rs2: 3 }, "BCLR x1, x2, x3"),
+            (0x48729213, Instruction::Bclri { rd: 4, rs1: 5, shamt: 7 }, "BCLRI x4, x5, 7"),
+            (0x4883d333, Instruction::Bext { rd: 6, rs1: 7, rs2: 8 }, "BEXT x6, x7, x8"),
+            (0x49f55493, Instruction::Bexti { rd: 9, rs1: 10, shamt: 31 }, "BEXTI x9, x10, 31"),
+            (0x68d615b3, Instruction::Binv { rd: 11, rs1: 12, rs2: 13 }, "BINV x11, x12, x13"),
+            (0x68179713, Instruction::Binvi { rd: 14, rs1: 15, shamt: 1 }, "BINVI x14, x15, 1"),
+            (0x29289833, Instruction::Bset { rd: 16, rs1: 17, rs2: 18 }, "BSET x16, x17, x18"),
+            (0x280a1993, Instruction::Bseti { rd: 19, rs1: 20, shamt: 0 }, "BSETI x19, x20, 0"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        // Unused count/extend selectors and OR-combine variants are reserved
+        let result = decode(0x60311093);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x60311093, field: "funct12", value: 0x603 }));
+        let result = decode(0x28855493);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x28855493, field: "funct12", value: 0x288 }));
+    }
+
+    #[test]
+    fn bit_manipulation_rv64_instructions() {
+        let rv64 = DecoderConfig::rv64();
+        let cases = [
+            (0x083100bb, Instruction::AddUw { rd: 1, rs1: 2, rs2: 3 }, "ADD.UW x1, x2, x3"),
+            (0x2062a23b, Instruction::Sh1addUw { rd: 4, rs1: 5, rs2: 6 }, "SH1ADD.UW x4, x5, x6"),
+            (0x209443bb, Instruction::Sh2addUw { rd: 7, rs1: 8, rs2: 9 }, "SH2ADD.UW x7, x8, x9"),
+            (0x20c5e53b, Instruction::Sh3addUw { rd: 10, rs1: 11, rs2: 12 }, "SH3ADD.UW x10, x11, x12"),
+            (0x0bf7169b, Instruction::SlliUw { rd: 13, rs1: 14, shamt: 63 }, "SLLI.UW x13, x14, 63"),
+            (0x6001109b, Instruction::Clzw { rd: 1, rs1: 2 }, "CLZW x1, x2"),
+            (0x6012119b, Instruction::Ctzw { rd: 3, rs1: 4 }, "CTZW x3, x4"),
+            (0x6023129b, Instruction::Cpopw { rd: 5, rs1: 6 }, "CPOPW x5, x6"),
+            (0x609413bb, Instruction::Rolw { rd: 7, rs1: 8, rs2: 9 }, "ROLW x7, x8, x9"),
+            (0x60c5d53b, Instruction::Rorw { rd: 10, rs1: 11, rs2: 12 }, "RORW x10, x11, x12"),
+            (0x61f7569b, Instruction::Roriw { rd: 13, rs1: 14, shamt: 31 }, "RORIW x13, x14, 31"),
+            (0x080342bb, Instruction::ZextH { rd: 5, rs1: 6 }, "ZEXT.H x5, x6"),
+            (0x6b865593, Instruction::Rev8 { rd: 11, rs1: 12 }, "REV8 x11, x12"),
+            (0x63f45393, Instruction::Rori { rd: 7, rs1: 8, shamt: 63 }, "RORI x7, x8, 63"),
+            (0x2a8a1993, Instruction::Bseti { rd: 19, rs1: 20, shamt: 40 }, "BSETI x19, x20, 40"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&rv64, word, expected, text);
+        }
+
+        // ZEXT.H and REV8 are encoded differently under RV32
+        let rv32 = DecoderConfig::rv32();
+        let zext_h = Instruction::ZextH { rd: 5, rs1: 6 };
+        assert_eq!(rv32.encode(&zext_h), Ok(0x080342b3));
+        assert_eq!(rv32.decode(0x080342b3), Ok(zext_h));
+        assert_eq!(rv64.decode(0x080342b3), Ok(Instruction::Pack { rd: 5, rs1: 6, rs2: 0 }));
+        let rev8 = Instruction::Rev8 { rd: 11, rs1: 12 };
+        assert_eq!(rv32.encode(&rev8), Ok(0x69865593));
+        let result = rv32.decode(0x6b865593);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x6b865593, xlen: Xlen::Rv32 }));
+        let result = rv64.decode(0x69865593);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x69865593, field: "funct12", value: 0x698 }));
+
+        // The word forms and 6-bit shift amounts are RV64 only
+        let result = rv32.decode(0x083100bb);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x083100bb, xlen: Xlen::Rv32 }));
+        let result = rv32.decode(0x63f45393);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x63f45393, xlen: Xlen::Rv32 }));
+    }
+
+    #[test]
+    fn scalar_crypto_instructions() {
+        let cases = [
+            // Zbkb/Zbkx packing, bit reversal and crossbar permutations
+            (0x083140b3, Instruction::Pack { rd: 1, rs1: 2, rs2: 3 }, "PACK x1, x2, x3"),
+            (0x0862f233, Instruction::Packh { rd: 4, rs1: 5, rs2: 6 }, "PACKH x4, x5, x6"),
+            (0x289423b3, Instruction::Xperm4 { rd: 7, rs1: 8, rs2: 9 }, "XPERM4 x7, x8, x9"),
+            (0x28c5c533, Instruction::Xperm8 { rd: 10, rs1: 11, rs2: 12 }, "XPERM8 x10, x11, x12"),
+            (0x68775693, Instruction::Brev8 { rd: 13, rs1: 14 }, "BREV8 x13, x14"),
+            (0x08f81793, Instruction::Zip { rd: 15, rs1: 16 }, "ZIP x15, x16"),
+            (0x08f95893, Instruction::Unzip { rd: 17, rs1: 18 }, "UNZIP x17, x18"),
+            // Zkne/Zknd AES rounds, the byte select is the last operand
+            (0x223100b3, Instruction::Aes32esi { rd: 1, rs1: 2, rs2: 3, bs: 0 }, "AES32ESI x1, x2, x3, 0"),
+            (0x66628233, Instruction::Aes32esmi { rd: 4, rs1: 5, rs2: 6, bs: 1 }, "AES32ESMI x4, x5, x6, 1"),
+            (0xaa9403b3, Instruction::Aes32dsi { rd: 7, rs1: 8, rs2: 9, bs: 2 }, "AES32DSI x7, x8, x9, 2"),
+            (0xeec58533, Instruction::Aes32dsmi { rd: 10, rs1: 11, rs2: 12, bs: 3 }, "AES32DSMI x10, x11, x12, 3"),
+            // Zknh SHA2 sigma and sum functions
+            (0x10211093, Instruction::Sha256sig0 { rd: 1, rs1: 2 }, "SHA256SIG0 x1, x2"),
+            (0x10321193, Instruction::Sha256sig1 { rd: 3, rs1: 4 }, "SHA256SIG1 x3, x4"),
+            (0x10031293, Instruction::Sha256sum0 { rd: 5, rs1: 6 }, "SHA256SUM0 x5, x6"),
+            (0x10141393, Instruction::Sha256sum1 { rd: 7, rs1: 8 }, "SHA256SUM1 x7, x8"),
+            (0x5c3100b3, Instruction::Sha512sig0h { rd: 1, rs1: 2, rs2: 3 }, "SHA512SIG0H x1, x2, x3"),
+            (0x54628233, Instruction::Sha512sig0l { rd: 4, rs1: 5, rs2: 6 }, "SHA512SIG0L x4, x5, x6"),
+            (0x5e9403b3, Instruction::Sha512sig1h { rd: 7, rs1: 8, rs2: 9 }, "SHA512SIG1H x7, x8, x9"),
+            (0x56c58533, Instruction::Sha512sig1l { rd: 10, rs1: 11, rs2: 12 }, "SHA512SIG1L x10, x11, x12"),
+            (0x50f706b3, Instruction::Sha512sum0r { rd: 13, rs1: 14, rs2: 15 }, "SHA512SUM0R x13, x14, x15"),
+            (0x53288833, Instruction::Sha512sum1r { rd: 16, rs1: 17, rs2: 18 }, "SHA512SUM1R x16, x17, x18"),
+            // Zksed/Zksh SM4 and SM3
+            (0xf03100b3, Instruction::Sm4ed { rd: 1, rs1: 2, rs2: 3, bs: 3 }, "SM4ED x1, x2, x3, 3"),
+            (0xb4628233, Instruction::Sm4ks { rd: 4, rs1: 5, rs2: 6, bs: 2 }, "SM4KS x4, x5, x6, 2"),
+            (0x10841393, Instruction::Sm3p0 { rd: 7, rs1: 8 }, "SM3P0 x7, x8"),
+            (0x10951493, Instruction::Sm3p1 { rd: 9, rs1: 10 }, "SM3P1 x9, x10"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        // Under RV32 PACK with rs2 = x0 is ZEXT.H, so it decodes back as the alias
+        let word = encode(&Instruction::Pack { rd: 5, rs1: 6, rs2: 0 }).unwrap();
+        assert_eq!(word, 0x080342b3);
+        assert_eq!(decode(word), Ok(Instruction::ZextH { rd: 5, rs1: 6 }));
+
+        // The byte select is reported as its own field and holds two bits
+        let fields = Instruction::Aes32esi { rd: 1, rs1: 2, rs2: 3, bs: 0 }.fields();
+        let expected = vec![Field::Rd(Register::X(1)), Field::Rs1(Register::X(2)), Field::Rs2(Register::X(3)), Field::Bs(0)];
+        assert_eq!(fields, expected);
+        let result = encode(&Instruction::Sm4ed { rd: 1, rs1: 2, rs2: 3, bs: 4 });
+        assert_eq!(result, Err(EncodeError::ImmediateOutOfRange { imm: 4, min: 0, max: 3 }));
+        let kind = ParseErrorKind::Encode(EncodeError::ImmediateOutOfRange { imm: 4, min: 0, max: 3 });
+        assert_eq!("AES32ESI x1, x2, x3, 4".parse::<Instruction>(), Err(ParseError { column: 22, kind }));
+
+        // The AES32 and SHA2-512 register pair forms, ZIP and UNZIP are RV32 only
+        let rv64 = DecoderConfig::rv64();
+        let result = rv64.decode(0x223100b3);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x223100b3, xlen: Xlen::Rv64 }));
+        let result = rv64.decode(0x5c3100b3);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x5c3100b3, xlen: Xlen::Rv64 }));
+        let result = rv64.decode(0x08f81793);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x08f81793, xlen: Xlen::Rv64 }));
+        let result = rv64.encode(&Instruction::Zip { rd: 15, rs1: 16 });
+        assert_eq!(result, Err(EncodeError::UnsupportedXlen { xlen: Xlen::Rv64 }));
+        let result = rv64.encode(&Instruction::Aes32esi { rd: 1, rs1: 2, rs2: 3, bs: 0 });
+        assert_eq!(result, Err(EncodeError::UnsupportedXlen { xlen: Xlen::Rv64 }));
+        let kind = ParseErrorKind::Encode(EncodeError::UnsupportedXlen { xlen: Xlen::Rv64 });
+        assert_eq!(rv64.parse("SHA512SIG0H x1, x2, x3"), Err(ParseError { column: 1, kind }));
+    }
+
+    #[test]
+    fn scalar_crypto_rv64_instructions() {
+        let rv64 = DecoderConfig::rv64();
+        let cases = [
+            (0x083140bb, Instruction::Packw { rd: 1, rs1: 2, rs2: 3 }, "PACKW x1, x2, x3"),
+            (0x323100b3, Instruction::Aes64es { rd: 1, rs1: 2, rs2: 3 }, "AES64ES x1, x2, x3"),
+            (0x36628233, Instruction::Aes64esm { rd: 4, rs1: 5, rs2: 6 }, "AES64ESM x4, x5, x6"),
+            (0x3a9403b3, Instruction::Aes64ds { rd: 7, rs1: 8, rs2: 9 }, "AES64DS x7, x8, x9"),
+            (0x3ec58533, Instruction::Aes64dsm { rd: 10, rs1: 11, rs2: 12 }, "AES64DSM x10, x11, x12"),
+            (0x31a71693, Instruction::Aes64ks1i { rd: 13, rs1: 14, rnum: 10 }, "AES64KS1I x13, x14, 10"),
+            (0x7f1807b3, Instruction::Aes64ks2 { rd: 15, rs1: 16, rs2: 17 }, "AES64KS2 x15, x16, x17"),
+            (0x30099913, Instruction::Aes64im { rd: 18, rs1: 19 }, "AES64IM x18, x19"),
+            (0x10611093, Instruction::Sha512sig0 { rd: 1, rs1: 2 }, "SHA512SIG0 x1, x2"),
+            (0x10721193, Instruction::Sha512sig1 { rd: 3, rs1: 4 }, "SHA512SIG1 x3, x4"),
+            (0x10431293, Instruction::Sha512sum0 { rd: 5, rs1: 6 }, "SHA512SUM0 x5, x6"),
+            (0x10541393, Instruction::Sha512sum1 { rd: 7, rs1: 8 }, "SHA512SUM1 x7, x8"),
+            // The Zbkb and SM4 encodings are shared with RV32
+            (0x68775693, Instruction::Brev8 { rd: 13, rs1: 14 }, "BREV8 x13, x14"),
+            (0xf03100b3, Instruction::Sm4ed { rd: 1, rs1: 2, rs2: 3, bs: 3 }, "SM4ED x1, x2, x3, 3"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&rv64, word, expected, text);
+        }
+
+        // The round number is reported as its own field, values above 10 are reserved
+        let fields = Instruction::Aes64ks1i { rd: 13, rs1: 14, rnum: 10 }.fields();
+        assert_eq!(fields, vec![Field::Rd(Register::X(13)), Field::Rs1(Register::X(14)), Field::Rnum(10)]);
+        let result = rv64.decode(0x31b71693);
+        assert_eq!(result, Err(DecodeError::ReservedFunct { word: 0x31b71693, field: "rnum", value: 11 }));
+        let result = rv64.encode(&Instruction::Aes64ks1i { rd: 13, rs1: 14, rnum: 11 });
+        assert_eq!(result, Err(EncodeError::ImmediateOutOfRange { imm: 11, min: 0, max: 10 }));
+
+        // The AES64 and single register SHA2-512 forms are RV64 only
+        let result = decode(0x323100b3);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x323100b3, xlen: Xlen::Rv32 }));
+        let result = decode(0x31a71693);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x31a71693, xlen: Xlen::Rv32 }));
+        let result = decode(0x10611093);
+        assert_eq!(result, Err(DecodeError::UnsupportedXlen { word: 0x10611093, xlen: Xlen::Rv32 }));
+        let result = encode(&Instruction::Aes64es { rd: 1, rs1: 2, rs2: 3 });
+        assert_eq!(result, Err(EncodeError::UnsupportedXlen { xlen: Xlen::Rv32 }));
+        let kind = ParseErrorKind::Encode(EncodeError::UnsupportedXlen { xlen: Xlen::Rv32 });
+        assert_eq!("SHA512SIG0 x1, x2".parse::<Instruction>(), Err(ParseError { column: 1, kind }));
+    }
+
+    #[test]
+    fn vector_configuration_and_memory_instructions() {
+        let e32m2 = Vtype { sew: Sew::E32, lmul: Lmul::M2, tail_agnostic: true, mask_agnostic: true };
+        let e8mf8 = Vtype { sew: Sew::E8, lmul: Lmul::Mf8, tail_agnostic: false, mask_agnostic: false };
+        let e64m1 = Vtype { sew: Sew::E64, lmul: Lmul::M1, tail_agnostic: true, mask_agnostic: false };
+        let cases = [
+            (0x0d1170d7, Instruction::Vsetvli { rd: 1, rs1: 2, vtype: e32m2 }, "VSETVLI x1, x2, e32, m2, ta, ma"),
+            (0x005170d7, Instruction::Vsetvli { rd: 1, rs1: 2, vtype: e8mf8 }, "VSETVLI x1, x2, e8, mf8, tu, mu"),
+            (0xc582f0d7, Instruction::Vsetivli { rd: 1, uimm: 5, vtype: e64m1 }, "VSETIVLI x1, 5, e64, m1, ta, mu"),
+            (0x803170d7, Instruction::Vsetvl { rd: 1, rs1: 2, rs2: 3 }, "VSETVL x1, x2, x3"),
+            // Unit-stride, fault-only-first and segment loads, vm clear means masked by v0
+            (0x00016087, Instruction::VectorLoad { vd: 1, rs1: 2, addressing: VectorAddressing::UnitStride, eew: Sew::E32, nf: 1, vm: false }, "VLE32.V v1, (x2), v0.t"),
+            (0x4302d207, Instruction::VectorLoad { vd: 4, rs1: 5, addressing: VectorAddressing::FaultOnlyFirst, eew: Sew::E16, nf: 3, vm: true }, "VLSEG3E16FF.V v4, (x5)"),
+            (0x02b10087, Instruction::VectorLoad { vd: 1, rs1: 2, addressing: VectorAddressing::Mask, eew: Sew::E8, nf: 1, vm: true }, "VLM.V v1, (x2)"),
+            (0x02b100a7, Instruction::VectorStore { vs3: 1, rs1: 2, addressing: VectorAddressing::Mask, eew: Sew::E8, nf: 1, vm: true }, "VSM.V v1, (x2)"),
+            // Whole register accesses, nf counts registers
+            (0x2280e107, Instruction::VectorLoad { vd: 2, rs1: 1, addressing: VectorAddressing::WholeRegister, eew: Sew::E32, nf: 2, vm: true }, "VL2RE32.V v2, (x1)"),
+            (0x62808227, Instruction::VectorStore { vs3: 4, rs1: 1, addressing: VectorAddressing::WholeRegister, eew: Sew::E8, nf: 4, vm: true }, "VS4R.V v4, (x1)"),
+            // Strided and indexed accesses
+            (0x0a317087, Instruction::VectorLoad { vd: 1, rs1: 2, addressing: VectorAddressing::Strided { rs2: 3 }, eew: Sew::E64, nf: 1, vm: true }, "VLSE64.V v1, (x2), x3"),
+            (0x04310087, Instruction::VectorLoad { vd: 1, rs1: 2, addressing: VectorAddressing::IndexedUnordered { vs2: 3 }, eew: Sew::E8, nf: 1, vm: false }, "VLUXEI8.V v1, (x2), v3, v0.t"),
+            (0x2e3150a7, Instruction::VectorStore { vs3: 1, rs1: 2, addressing: VectorAddressing::IndexedOrdered { vs2: 3 }, eew: Sew::E16, nf: 2, vm: true }, "VSOXSEG2EI16.V v1, (x2), v3"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        // The policies default to undisturbed and LMUL to m1
+        let parsed = "vsetvli a0, a1, e16".parse::<Instruction>();
+        let vtype = Vtype { sew: Sew::E16, lmul: Lmul::M1, tail_agnostic: false, mask_agnostic: false };
+        assert_eq!(parsed, Ok(Instruction::Vsetvli { rd: 10, rs1: 11, vtype }));
+        let result = "VSETVLI x1, x2, m2, e32".parse::<Instruction>();
+        assert_eq!(result, Err(ParseError { column: 17, kind: ParseErrorKind::ExpectedVtype }));
+        assert_eq!(Vtype::from_bits(0x0d1), Some(e32m2));
+        assert_eq!(Vtype::from_bits(0x020), None);
+        assert_eq!(decode(0x020170d7), Err(DecodeError::ReservedFunct { word: 0x020170d7, field: "vtype", value: 0x020 }));
+
+        // The store data register and the mask bit are reported as fields
+        let fields = decode(0x2e3150a7).unwrap().fields();
+        let expected = vec![Field::Rs1(Register::X(2)), Field::Rs2(Register::V(3)), Field::Rs3(Register::V(1)), Field::Vm(true)];
+        assert_eq!(fields, expected);
+        let instr = decode(0x00016087).unwrap();
+        assert_eq!(instr.mnemonic(), "VLE");
+        assert_eq!(instr.description(), "Vector Unit-Stride Load");
+
+        // Whole register groups must be aligned, mask accesses use byte elements
+        assert_eq!(decode(0x22810087), Err(DecodeError::ReservedFunct { word: 0x22810087, field: "vd", value: 1 }));
+        assert_eq!(decode(0x02b160a7), Err(DecodeError::ReservedFunct { word: 0x02b160a7, field: "width", value: 0b110 }));
+        assert_eq!(decode(0x12016087), Err(DecodeError::ReservedFunct { word: 0x12016087, field: "mew", value: 1 }));
+        let whole = Instruction::VectorLoad { vd: 1, rs1: 2, addressing: VectorAddressing::WholeRegister, eew: Sew::E8, nf: 2, vm: true };
+        assert_eq!(encode(&whole), Err(EncodeError::ReservedEncoding));
+        let result = "VLE8.V v1, 4(x2)".parse::<Instruction>();
+        let kind = ParseErrorKind::Encode(EncodeError::ImmediateOutOfRange { imm: 4, min: 0, max: 0 });
+        assert_eq!(result, Err(ParseError { column: 12, kind }));
+
+        // Indexed accesses with 64-bit indices exist under RV32 too
+        let expected = Instruction::VectorLoad { vd: 1, rs1: 2, addressing: VectorAddressing::IndexedUnordered { vs2: 3 }, eew: Sew::E64, nf: 1, vm: false };
+        assert_round_trip(&DecoderConfig::rv32(), 0x04317087, expected, "VLUXEI64.V v1, (x2), v3, v0.t");
+        assert_round_trip(&DecoderConfig::rv64(), 0x04317087, expected, "VLUXEI64.V v1, (x2), v3, v0.t");
+    }
+
+    #[test]
+    fn vector_arithmetic_instructions() {
+        use VectorOperand::{Float, Imm, Scalar, Vector};
+        let arith = |op, vd, vs2, src, vm| Instruction::VectorArith { op, vd, vs2, src, vm };
+        let unary = |op, vd, vs2, vm| Instruction::VectorUnary { op, vd, vs2, vm };
+        let cases = [
+            // Immediates are sign-extended except for shifts, gathers and slides
+            (0x002eb0d7, arith(VectorOp::Vadd, 1, 2, Imm(-3), false), "VADD.VI v1, v2, -3, v0.t"),
+            (0x7a2eb0d7, arith(VectorOp::Vmsgtu, 1, 2, Imm(-3), true), "VMSGTU.VI v1, v2, -3"),
+            (0x822eb0d7, arith(VectorOp::Vsaddu, 1, 2, Imm(-3), true), "VSADDU.VI v1, v2, -3"),
+            (0x322fb0d7, arith(VectorOp::Vrgather, 1, 2, Imm(31), true), "VRGATHER.VI v1, v2, 31"),
+            (0x3a2fb0d7, arith(VectorOp::Vslideup, 1, 2, Imm(31), true), "VSLIDEUP.VI v1, v2, 31"),
+            (0xbe2fb0d7, arith(VectorOp::Vnclip, 1, 2, Imm(31), true), "VNCLIP.WI v1, v2, 31"),
+            // Carry forms take v0 as an operand
+            (0x462180d7, arith(VectorOp::Vmadc, 1, 2, Vector(3), true), "VMADC.VV v1, v2, v3"),
+            (0x442180d7, arith(VectorOp::Vmadc, 1, 2, Vector(3), false), "VMADC.VVM v1, v2, v3, v0"),
+            (0x4022b0d7, arith(VectorOp::Vadc, 1, 2, Imm(5), false), "VADC.VIM v1, v2, 5, v0"),
+            (0x5c21c0d7, arith(VectorOp::Vmerge, 1, 2, Scalar(3), false), "VMERGE.VXM v1, v2, x3, v0"),
+            (0x5c21d0d7, arith(VectorOp::Vfmerge, 1, 2, Float(3), false), "VFMERGE.VFM v1, v2, f3, v0"),
+            // Moves share the merge funct6 with vm set
+            (0x5e0fb0d7, arith(VectorOp::VmvV, 1, 0, Imm(-1), true), "VMV.V.I v1, -1"),
+            (0x5e0150d7, arith(VectorOp::VfmvV, 1, 0, Float(2), true), "VFMV.V.F v1, f2"),
+            (0x420160d7, arith(VectorOp::VmvS, 1, 0, Scalar(2), true), "VMV.S.X v1, x2"),
+            (0x420150d7, arith(VectorOp::VfmvS, 1, 0, Float(2), true), "VFMV.S.F v1, f2"),
+            // Mask logical, compress, reductions and widening forms
+            (0x6221a0d7, arith(VectorOp::Vmandn, 1, 2, Vector(3), true), "VMANDN.MM v1, v2, v3"),
+            (0x5e21a0d7, arith(VectorOp::Vcompress, 1, 2, Vector(3), true), "VCOMPRESS.VM v1, v2, v3"),
+            (0x062190d7, arith(VectorOp::Vfredusum, 1, 2, Vector(3), true), "VFREDUSUM.VS v1, v2, v3"),
+            (0xc62180d7, arith(VectorOp::Vwredsum, 1, 2, Vector(3), true), "VWREDSUM.VS v1, v2, v3"),
+            (0xd221d0d7, arith(VectorOp::VfwaddW, 1, 2, Float(3), true), "VFWADD.WF v1, v2, f3"),
+            // Multiply-add forms put the source before vs2
+            (0xb43160d7, arith(VectorOp::Vmacc, 1, 3, Scalar(2), false), "VMACC.VX v1, x2, v3, v0.t"),
+            (0xfa3160d7, arith(VectorOp::Vwmaccus, 1, 3, Scalar(2), true), "VWMACCUS.VX v1, x2, v3"),
+            // Unary operations, the vs1 field selects the operation
+            (0x422020d7, unary(VectorUnaryOp::VmvXS, 1, 2, true), "VMV.X.S x1, v2"),
+            (0x422010d7, unary(VectorUnaryOp::VfmvFS, 1, 2, true), "VFMV.F.S f1, v2"),
+            (0x402820d7, unary(VectorUnaryOp::VcpopM, 1, 2, false), "VCPOP.M x1, v2, v0.t"),
+            (0x4228a0d7, unary(VectorUnaryOp::VfirstM, 1, 2, true), "VFIRST.M x1, v2"),
+            (0x5008a0d7, unary(VectorUnaryOp::VidV, 1, 0, false), "VID.V v1, v0.t"),
+            (0x522820d7, unary(VectorUnaryOp::ViotaM, 1, 2, true), "VIOTA.M v1, v2"),
+            (0x482320d7, unary(VectorUnaryOp::VzextVf2, 1, 2, false), "VZEXT.VF2 v1, v2, v0.t"),
+            (0x4a2a90d7, unary(VectorUnaryOp::VfncvtRodFFW, 1, 2, true), "VFNCVT.ROD.F.F.W v1, v2"),
+            (0x4e2210d7, unary(VectorUnaryOp::Vfrsqrt7V, 1, 2, true), "VFRSQRT7.V v1, v2"),
+            (0x9e40b157, unary(VectorUnaryOp::Vmv2rV, 2, 4, true), "VMV2R.V v2, v4"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        let instr = decode(0x002eb0d7).unwrap();
+        assert_eq!(instr.mnemonic(), "VADD");
+        assert_eq!(instr.description(), "Vector Add");
+        let expected = vec![Field::Rd(Register::V(1)), Field::Imm(-3), Field::Rs2(Register::V(2)), Field::Vm(false)];
+        assert_eq!(instr.fields(), expected);
+
+        // Forms an operation does not have are reserved
+        assert_eq!(decode(0x0a2eb0d7), Err(DecodeError::ReservedFunct { word: 0x0a2eb0d7, field: "funct6", value: 0b000010 }));
+        assert_eq!(decode(0x6021a0d7), Err(DecodeError::ReservedFunct { word: 0x6021a0d7, field: "vm", value: 0 }));
+        assert_eq!(decode(0x4211a0d7), Err(DecodeError::ReservedFunct { word: 0x4211a0d7, field: "vs1", value: 0b00011 }));
+        assert_eq!(decode(0x9e30b157), Err(DecodeError::ReservedFunct { word: 0x9e30b157, field: "vs2", value: 3 }));
+        let result = encode(&arith(VectorOp::Vsub, 1, 2, Imm(3), true));
+        assert_eq!(result, Err(EncodeError::ReservedEncoding));
+        let result = encode(&arith(VectorOp::Vsll, 1, 2, Imm(-1), true));
+        assert_eq!(result, Err(EncodeError::ImmediateOutOfRange { imm: -1, min: 0, max: 31 }));
+
+        // Masking is optional, the carry operand must be v0
+        let result = "VADD.VI v1, v2, 16".parse::<Instruction>();
+        let kind = ParseErrorKind::Encode(EncodeError::ImmediateOutOfRange { imm: 16, min: -16, max: 15 });
+        assert_eq!(result, Err(ParseError { column: 17, kind }));
+        let result = "VADC.VVM v1, v2, v3, v4".parse::<Instruction>();
+        assert_eq!(result, Err(ParseError { column: 22, kind: ParseErrorKind::ExpectedMaskRegister }));
+        let result = "VADD.VV v1, x2, v3".parse::<Instruction>();
+        assert_eq!(result, Err(ParseError { column: 13, kind: ParseErrorKind::ExpectedVectorRegister }));
+    }
+
+    #[test]
+    fn vector_crypto_instructions() {
+        use VectorOperand::{Imm, Scalar, Vector};
+        let arith = |op, vd, vs2, src, vm| Instruction::VectorArith { op, vd, vs2, src, vm };
+        let unary = |op, vd, vs2, vm| Instruction::VectorUnary { op, vd, vs2, vm };
+        let cases = [
+            // Zvbb and Zvbc live in OP-V, VROR.VI holds bit 5 of the amount in funct6
+            (0x0621c0d7, arith(VectorOp::Vandn, 1, 2, Scalar(3), true), "VANDN.VX v1, v2, x3"),
+            (0x562180d7, arith(VectorOp::Vrol, 1, 2, Vector(3), true), "VROL.VV v1, v2, v3"),
+            (0x5628b0d7, arith(VectorOp::Vror, 1, 2, Imm(49), true), "VROR.VI v1, v2, 49"),
+            (0x5028b0d7, arith(VectorOp::Vror, 1, 2, Imm(17), false), "VROR.VI v1, v2, 17, v0.t"),
+            (0xd62fb0d7, arith(VectorOp::Vwsll, 1, 2, Imm(31), true), "VWSLL.VI v1, v2, 31"),
+            (0x3421e0d7, arith(VectorOp::Vclmulh, 1, 2, Scalar(3), false), "VCLMULH.VX v1, v2, x3, v0.t"),
+            (0x4a2420d7, unary(VectorUnaryOp::Vbrev8V, 1, 2, true), "VBREV8.V v1, v2"),
+            (0x482720d7, unary(VectorUnaryOp::VcpopV, 1, 2, false), "VCPOP.V v1, v2, v0.t"),
+            // Element group operations live in OP-VE and are never masked
+            (0xb221a0f7, arith(VectorOp::Vghsh, 1, 2, Vector(3), true), "VGHSH.VV v1, v2, v3"),
+            (0xba21a0f7, arith(VectorOp::Vsha2ch, 1, 2, Vector(3), true), "VSHA2CH.VV v1, v2, v3"),
+            (0x8a552277, arith(VectorOp::Vaeskf1, 4, 5, Imm(10), true), "VAESKF1.VI v4, v5, 10"),
+            (0x8623a0f7, arith(VectorOp::Vsm4k, 1, 2, Imm(7), true), "VSM4K.VI v1, v2, 7"),
+            (0xae5fa277, arith(VectorOp::Vsm3c, 4, 5, Imm(31), true), "VSM3C.VI v4, v5, 31"),
+            (0xa220a0f7, unary(VectorUnaryOp::VaesdfVv, 1, 2, true), "VAESDF.VV v1, v2"),
+            (0xa633a277, unary(VectorUnaryOp::VaeszVs, 4, 3, true), "VAESZ.VS v4, v3"),
+            (0xa6282277, unary(VectorUnaryOp::Vsm4rVs, 4, 2, true), "VSM4R.VS v4, v2"),
+            (0xa228a0f7, unary(VectorUnaryOp::VgmulVv, 1, 2, true), "VGMUL.VV v1, v2"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        // The .VS forms apply element group 0 of vs2 to every group of vd
+        let instr = decode(0xa633a277).unwrap();
+        assert_eq!(instr.mnemonic(), "VAESZ.VS");
+        assert_eq!(instr.description(), "Vector AES Round Zero, Round Key from Element Group 0");
+        assert_eq!(instr.fields(), vec![Field::Rd(Register::V(4)), Field::Rs2(Register::V(3)), Field::Vm(true)]);
+
+        assert_eq!(decode(0xa222a0f7), Err(DecodeError::ReservedFunct { word: 0xa222a0f7, field: "vs1", value: 0b00101 }));
+        assert_eq!(decode(0xb021a0f7), Err(DecodeError::ReservedFunct { word: 0xb021a0f7, field: "vm", value: 0 }));
+        assert_eq!(decode(0xb22180f7), Err(DecodeError::ReservedFunct { word: 0xb22180f7, field: "funct3", value: 0 }));
+        assert_eq!(decode(0xc221a0f7), Err(DecodeError::ReservedFunct { word: 0xc221a0f7, field: "funct6", value: 0b110000 }));
+        let result = encode(&arith(VectorOp::Vghsh, 1, 2, Vector(3), false));
+        assert_eq!(result, Err(EncodeError::ReservedEncoding));
+        let result = encode(&arith(VectorOp::Vaeskf1, 1, 2, Imm(-1), true));
+        assert_eq!(result, Err(EncodeError::ImmediateOutOfRange { imm: -1, min: 0, max: 31 }));
+        let result = "VROR.VI v1, v2, 64".parse::<Instruction>();
+        let kind = ParseErrorKind::Encode(EncodeError::ImmediateOutOfRange { imm: 64, min: 0, max: 63 });
+        assert_eq!(result, Err(ParseError { column: 17, kind }));
+    }
+
+    #[test]
+    fn half_precision_instructions() {
+        use RoundingMode::{Dyn, Rdn, Rne, Rtz, Rup};
+        let cases = [
+            (0x243100c3, Instruction::FmaddH { rd: 1, rs1: 2, rs2: 3, rs3: 4, rm: Rne }, "FMADD.H f1, f2, f3, f4, RNE"),
+            (0x243170cb, Instruction::FnmsubH { rd: 1, rs1: 2, rs2: 3, rs3: 4, rm: Dyn }, "FNMSUB.H f1, f2, f3, f4, DYN"),
+            (0x04c59553, Instruction::FaddH { rd: 10, rs1: 11, rs2: 12, rm: Rtz }, "FADD.H f10, f11, f12, RTZ"),
+            (0x1cc5f553, Instruction::FdivH { rd: 10, rs1: 11, rs2: 12, rm: Dyn }, "FDIV.H f10, f11, f12, DYN"),
+            (0x5c0332d3, Instruction::FsqrtH { rd: 5, rs1: 6, rm: Rup }, "FSQRT.H f5, f6, RUP"),
+            (0x243120d3, Instruction::FsgnjxH { rd: 1, rs1: 2, rs2: 3 }, "FSGNJX.H f1, f2, f3"),
+            (0x2c3110d3, Instruction::FmaxH { rd: 1, rs1: 2, rs2: 3 }, "FMAX.H f1, f2, f3"),
+            (0xc4011553, Instruction::FcvtWH { rd: 10, rs1: 2, rm: Rtz }, "FCVT.W.H x10, f2, RTZ"),
+            (0xd415f0d3, Instruction::FcvtHWu { rd: 1, rs1: 11, rm: Dyn }, "FCVT.H.WU f1, x11, DYN"),
+            (0xa420a553, Instruction::FeqH { rd: 10, rs1: 1, rs2: 2 }, "FEQ.H x10, f1, f2"),
+            (0xe4009553, Instruction::FclassH { rd: 10, rs1: 1 }, "FCLASS.H x10, f1"),
+            // Zfhmin, conversions between formats keep the source format in rs2
+            (0x402100d3, Instruction::FcvtSH { rd: 1, rs1: 2, rm: Rne }, "FCVT.S.H f1, f2, RNE"),
+            (0x440100d3, Instruction::FcvtHS { rd: 1, rs1: 2, rm: Rne }, "FCVT.H.S f1, f2, RNE"),
+            (0x422100d3, Instruction::FcvtDH { rd: 1, rs1: 2, rm: Rne }, "FCVT.D.H f1, f2, RNE"),
+            (0x441170d3, Instruction::FcvtHD { rd: 1, rs1: 2, rm: Dyn }, "FCVT.H.D f1, f2, DYN"),
+            (0xe40182d3, Instruction::FmvXH { rd: 5, rs1: 3 }, "FMV.X.H x5, f3"),
+            (0xf40281d3, Instruction::FmvHX { rd: 3, rs1: 5 }, "FMV.H.X f3, x5"),
+            (0xffe11087, Instruction::Flh { rd: 1, rs1: 2, imm: -2 }, "FLH f1, -2(x2)"),
+            (0x7e111f27, Instruction::Fsh { rs1: 2, rs2: 1, imm: 2046 }, "FSH f1, 2046(x2)"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        let instr = decode(0x04c59553).unwrap();
+        assert_eq!(instr.mnemonic(), "FADD.H");
+        assert_eq!(instr.description(), "Half Floating Point Addition");
+
+        // The long conversions only exist under RV64
+        let rv64 = DecoderConfig::rv64();
+        assert_eq!(rv64.decode(0xc4211553), Ok(Instruction::FcvtLH { rd: 10, rs1: 2, rm: Rtz }));
+        assert_eq!(rv64.decode(0xd435a0d3), Ok(Instruction::FcvtHLu { rd: 1, rs1: 11, rm: Rdn }));
+        assert_eq!(decode(0xc4211553), Err(DecodeError::UnsupportedXlen { word: 0xc4211553, xlen: Xlen::Rv32 }));
+
+        // FCVT.H.H is not a conversion
+        assert_eq!(decode(0x442100d3), Err(DecodeError::ReservedFunct { word: 0x442100d3, field: "fmt", value: 0b10 }));
+    }
+
+    #[test]
+    fn quad_precision_instructions() {
+        use RoundingMode::{Rne, Rtz};
+        let cases = [
+            (0x263100c3, Instruction::FmaddQ { rd: 1, rs1: 2, rs2: 3, rs3: 4, rm: Rne }, "FMADD.Q f1, f2, f3, f4, RNE"),
+            (0x06c59553, Instruction::FaddQ { rd: 10, rs1: 11, rs2: 12, rm: Rtz }, "FADD.Q f10, f11, f12, RTZ"),
+            (0x2e3110d3, Instruction::FmaxQ { rd: 1, rs1: 2, rs2: 3 }, "FMAX.Q f1, f2, f3"),
+            (0xa620a553, Instruction::FeqQ { rd: 10, rs1: 1, rs2: 2 }, "FEQ.Q x10, f1, f2"),
+            (0xe6009553, Instruction::FclassQ { rd: 10, rs1: 1 }, "FCLASS.Q x10, f1"),
+            (0x403100d3, Instruction::FcvtSQ { rd: 1, rs1: 2, rm: Rne }, "FCVT.S.Q f1, f2, RNE"),
+            (0x460100d3, Instruction::FcvtQS { rd: 1, rs1: 2, rm: Rne }, "FCVT.Q.S f1, f2, RNE"),
+            (0x461100d3, Instruction::FcvtQD { rd: 1, rs1: 2, rm: Rne }, "FCVT.Q.D f1, f2, RNE"),
+            (0x443100d3, Instruction::FcvtHQ { rd: 1, rs1: 2, rm: Rne }, "FCVT.H.Q f1, f2, RNE"),
+            (0x462100d3, Instruction::FcvtQH { rd: 1, rs1: 2, rm: Rne }, "FCVT.Q.H f1, f2, RNE"),
+            (0xff014087, Instruction::Flq { rd: 1, rs1: 2, imm: -16 }, "FLQ f1, -16(x2)"),
+            (0x00114827, Instruction::Fsq { rs1: 2, rs2: 1, imm: 16 }, "FSQ f1, 16(x2)"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        let instr = decode(0x06c59553).unwrap();
+        assert_eq!(instr.mnemonic(), "FADD.Q");
+        assert_eq!(instr.description(), "Quad Floating Point Addition");
+
+        let rv64 = DecoderConfig::rv64();
+        assert_eq!(rv64.decode(0xc6211553), Ok(Instruction::FcvtLQ { rd: 10, rs1: 2, rm: Rtz }));
+        assert_eq!(decode(0xc6211553), Err(DecodeError::UnsupportedXlen { word: 0xc6211553, xlen: Xlen::Rv32 }));
+
+        // There is no integer move for the quad format
+        let result = "FMV.X.Q x5, f3".parse::<Instruction>();
+        assert_eq!(result, Err(ParseError { column: 1, kind: ParseErrorKind::UnknownMnemonic("FMV.X.Q".to_string()) }));
+    }
+
+    #[test]
+    fn zfa_instructions() {
+        use RoundingMode::{Dyn, Rne};
+        let half = FliConstant::from_index(12).unwrap();
+        let min = FliConstant::from_index(1).unwrap();
+        let inf = FliConstant::from_index(30).unwrap();
+        let cases = [
+            (0xf01600d3, Instruction::FliS { rd: 1, constant: half }, "FLI.S f1, 0.5"),
+            (0xf2108153, Instruction::FliD { rd: 2, constant: min }, "FLI.D f2, min"),
+            (0xf61f01d3, Instruction::FliQ { rd: 3, constant: inf }, "FLI.Q f3, inf"),
+            (0x28c5a553, Instruction::FminmS { rd: 10, rs1: 11, rs2: 12 }, "FMINM.S f10, f11, f12"),
+            (0x2a3130d3, Instruction::FmaxmD { rd: 1, rs1: 2, rs2: 3 }, "FMAXM.D f1, f2, f3"),
+            (0x444100d3, Instruction::FroundH { rd: 1, rs1: 2, rm: Rne }, "FROUND.H f1, f2, RNE"),
+            (0x425170d3, Instruction::FroundnxD { rd: 1, rs1: 2, rm: Dyn }, "FROUNDNX.D f1, f2, DYN"),
+            (0xa03140d3, Instruction::FleqS { rd: 1, rs1: 2, rs2: 3 }, "FLEQ.S x1, f2, f3"),
+            (0xa63150d3, Instruction::FltqQ { rd: 1, rs1: 2, rs2: 3 }, "FLTQ.Q x1, f2, f3"),
+            (0xc2811553, Instruction::FcvtmodWD { rd: 10, rs1: 2 }, "FCVTMOD.W.D x10, f2, RTZ"),
+            (0xe2110553, Instruction::FmvhXD { rd: 10, rs1: 2 }, "FMVH.X.D x10, f2"),
+            (0xb2b50153, Instruction::FmvpDX { rd: 2, rs1: 10, rs2: 11 }, "FMVP.D.X f2, x10, x11"),
+        ];
+        for (word, expected, text) in cases {
+            assert_round_trip(&DecoderConfig::rv32(), word, expected, text);
+        }
+
+        // The constant is reported as its own field and integral ones parse from integers
+        let fields = Instruction::FliS { rd: 1, constant: half }.fields();
+        assert_eq!(fields, vec![Field::Rd(Register::F(1)), Field::Fli(half)]);
+        let two = FliConstant::from_index(20).unwrap();
+        assert_eq!("FLI.H f1, 2".parse::<Instruction>(), Ok(Instruction::FliH { rd: 1, constant: two }));
+        assert_eq!(encode(&Instruction::FliH { rd: 1, constant: two }), Ok(0xf41a00d3));
+        let result = "FLI.S f1, 0.3".parse::<Instruction>();
+        assert_eq!(result, Err(ParseError { column: 11, kind: ParseErrorKind::InvalidOperand("0.3".to_string()) }));
+        let result = "FLI.S f1, 3".parse::<Instruction>();
+        assert_eq!(result, Ok(Instruction::FliS { rd: 1, constant: FliConstant::from_index(22).unwrap() }));
+        let result = "FLI.S f1, 5".parse::<Instruction>();
+        assert_eq!(result, Err(ParseError { column: 11, kind: ParseErrorKind::ExpectedFliConstant }));
+
+        // FCVTMOD.W.D only rounds towards zero
+        let result = "FCVTMOD.W.D x10, f2, RNE".parse::<Instruction>();
+        assert_eq!(result, Err(ParseError { column: 22, kind: ParseErrorKind::ExpectedRoundingMode }));
+        assert_eq!(decode(0xc2810553), Err(DecodeError::ReservedFunct { word: 0xc2810553, field: "funct3", value: 0 }));
+
+        // The split moves of a double are RV32 only and those of a quad RV64 only
+        let rv64 = DecoderConfig::rv64();
+        assert_eq!(rv64.decode(0xe2110553), Err(DecodeError::UnsupportedXlen { word: 0xe2110553, xlen: Xlen::Rv64 }));
+        assert_eq!(rv64.decode(0xe6110553), Ok(Instruction::FmvhXQ { rd: 10, rs1: 2 }));
+        assert_eq!(decode(0xe6110553), Err(DecodeError::UnsupportedXlen { word: 0xe6110553, xlen: Xlen::Rv32 }));
+
+        // FLI takes rs2 = 1 from the moves, the moves keep rs2 zero and so do
+        // the single-source FCLASS and FSQRT
+        let errors = [
+            (0xf05080d3, "rs2", 5),       // FMV.W.X
+            (0xf010b0d3, "funct3", 3),    // FLI.S
+            (0xe03080d3, "rs2", 3),       // FMV.X.W
+            (0xe02090d3, "rs2", 2),       // FCLASS.S
+            (0xe01090d3, "rs2", 1),       // FCLASS.S
+            (0x59990d53, "rs2", 25),      // FSQRT.S
+            (0x5a990d53, "rs2", 9),       // FSQRT.D
+            (0x5c990d53, "rs2", 9),       // FSQRT.H
+            (0x5e990d53, "rs2", 9),       // FSQRT.Q
+        ];
+        for (word, field, value) in errors {
+            assert_eq!(decode(word), Err(DecodeError::ReservedFunct { word, field, value }), "{:#010x}", word);
+        }
+    }
 }